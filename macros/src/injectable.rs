@@ -1,5 +1,7 @@
 pub mod dependency;
 pub mod dummy;
 pub mod implementation;
+pub mod keyed_attr_input;
 pub mod macro_args;
 pub mod named_attr_input;
+pub mod tagged_attr_input;
@@ -1,8 +1,20 @@
 use proc_macro2::{Ident, Span};
 use syn::spanned::Spanned;
-use syn::{parse2, FnArg, GenericArgument, LitStr, PathArguments, Type};
+use syn::{
+    parse2,
+    Attribute,
+    FnArg,
+    GenericArgument,
+    LitStr,
+    PatType,
+    PathArguments,
+    Type,
+    TypePath,
+};
 
+use crate::injectable::keyed_attr_input::KeyedAttrInput;
 use crate::injectable::named_attr_input::NamedAttrInput;
+use crate::injectable::tagged_attr_input::TaggedAttrInput;
 use crate::util::error::diagnostic_error_enum;
 use crate::util::syn_path::SynPathExt;
 
@@ -15,6 +27,52 @@ pub struct Dependency
     interface: Type,
     ptr: Ident,
     name: Option<LitStr>,
+    key: Option<Type>,
+    tags: Vec<(LitStr, LitStr)>,
+    kind: DependencyKind,
+}
+
+/// The kind of a dependency of a injectable type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyKind
+{
+    /// A single binding. Found as a `Ptr<Interface>` argument.
+    Single,
+
+    /// A single binding that might not exist. Found as a `Option<Ptr<Interface>>`
+    /// argument.
+    Optional,
+
+    /// A binding that is resolved the first time it is used. Found as a
+    /// `Lazy<Interface>` argument.
+    Lazy,
+
+    /// A binding that is resolved every time it is asked for. Found as a
+    /// `Provider<Interface>` argument.
+    Provider,
+
+    /// A back-reference to a singleton. Found as a `WeakSingletonPtr<Interface>`
+    /// argument.
+    WeakSingleton,
+
+    /// All of the bindings without a name or tags. Found as a `Vec<Ptr<Interface>>`
+    /// argument.
+    All,
+
+    /// All of the bindings with a name, keyed by name. Found as a
+    /// `HashMap<String, Ptr<Interface>>` argument.
+    AllNamed,
+}
+
+impl DependencyKind
+{
+    /// Returns whether or not the dependency kind is only supported by the blocking
+    /// DI container. Dependencies of these kinds are created by the container instead of
+    /// being resolved to a pointer.
+    pub fn is_blocking_only(self) -> bool
+    {
+        matches!(self, Self::Lazy | Self::Provider | Self::WeakSingleton)
+    }
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -30,74 +88,98 @@ impl Dependency
             }),
         }?;
 
-        let dependency_type_path = match typed_ctor_method_arg.ty.as_ref() {
-            Type::Path(arg_type_path) => Ok(arg_type_path),
-            Type::Reference(ref_type_path) => match ref_type_path.elem.as_ref() {
-                Type::Path(arg_type_path) => Ok(arg_type_path),
+        let dependency_type_path =
+            Self::get_dependency_type_path(typed_ctor_method_arg.ty.as_ref())?;
+
+        let (kind, ptr_type_index) = match Self::get_type_path_ident(dependency_type_path)
+        {
+            Some(ident) if ident == "Vec" => (DependencyKind::All, Some(0)),
+            Some(ident) if ident == "HashMap" => (DependencyKind::AllNamed, Some(1)),
+            Some(ident) if ident == "Option" => (DependencyKind::Optional, Some(0)),
+            _ => (DependencyKind::Single, None),
+        };
+
+        let ptr_type_path = if let Some(ptr_type_index) = ptr_type_index {
+            let item_type = Self::get_generic_type(dependency_type_path, ptr_type_index)
+                .ok_or_else(|| DependencyError::DependencyTypeMissingGenerics {
+                    ptr_ident_span: dependency_type_path.span(),
+                })?;
+
+            match item_type {
+                Type::Path(item_type_path) => Ok(item_type_path),
                 other_type => Err(DependencyError::InvalidType {
                     type_span: other_type.span(),
                 }),
-            },
-            other_type => Err(DependencyError::InvalidType {
-                type_span: other_type.span(),
-            }),
-        }?;
+            }?
+        } else {
+            dependency_type_path
+        };
 
-        let ptr_path_segment = dependency_type_path.path.segments.last().map_or_else(
+        let ptr_ident = Self::get_type_path_ident(ptr_type_path).map_or_else(
             || {
                 Err(DependencyError::MissingType {
                     arg_span: typed_ctor_method_arg.span(),
                 })
             },
-            Ok,
+            |ident| Ok(ident.clone()),
         )?;
 
-        let ptr_ident = ptr_path_segment.ident.clone();
+        let kind = match kind {
+            DependencyKind::Single if ptr_ident == "Lazy" => DependencyKind::Lazy,
+            DependencyKind::Single if ptr_ident == "Provider" => DependencyKind::Provider,
+            DependencyKind::Single if ptr_ident == "WeakSingletonPtr" => {
+                DependencyKind::WeakSingleton
+            }
+            other_kind => other_kind,
+        };
 
-        let ptr_generic_args = match ptr_path_segment.arguments.clone() {
-            PathArguments::AngleBracketed(generic_args) => Ok(generic_args),
-            _ => Err(DependencyError::DependencyTypeMissingGenerics {
+        let interface = Self::get_generic_type(ptr_type_path, 0)
+            .ok_or_else(|| DependencyError::DependencyTypeMissingGenerics {
                 ptr_ident_span: ptr_ident.span(),
-            }),
-        }?
-        .args;
-
-        let interface =
-            if let Some(GenericArgument::Type(interface)) = ptr_generic_args.first() {
-                Ok(interface.clone())
-            } else {
-                Err(DependencyError::DependencyTypeMissingGenerics {
-                    ptr_ident_span: ptr_ident.span(),
-                })
-            }?;
+            })?
+            .clone();
 
         let arg_attrs = &typed_ctor_method_arg.attrs;
 
-        let opt_named_attr = arg_attrs.iter().find(|attr| {
-            attr.path.get_ident().map_or_else(
-                || false,
-                |attr_ident| attr_ident.to_string().as_str() == "named",
-            ) || &attr.path.to_string() == "syrette::named"
-        });
+        let name = Self::parse_name(arg_attrs).map_err(|err| {
+            DependencyError::InvalidNamedAttrInput {
+                arg_span: typed_ctor_method_arg.span(),
+                err,
+            }
+        })?;
+
+        if let (DependencyKind::All | DependencyKind::AllNamed, Some(name)) =
+            (kind, &name)
+        {
+            return Err(DependencyError::NamedCollection {
+                name_span: name.span(),
+            });
+        }
 
-        let opt_named_attr_tokens = opt_named_attr.map(|attr| &attr.tokens);
+        let key = Self::build_key(typed_ctor_method_arg, kind, name.as_ref())?;
+
+        let tags = Self::parse_tags(arg_attrs).map_err(|err| {
+            DependencyError::InvalidTaggedAttrInput {
+                arg_span: typed_ctor_method_arg.span(),
+                err,
+            }
+        })?;
 
-        let opt_named_attr_input =
-            if let Some(named_attr_tokens) = opt_named_attr_tokens {
-                Some(parse2::<NamedAttrInput>(named_attr_tokens.clone()).map_err(
-                    |err| DependencyError::InvalidNamedAttrInput {
-                        arg_span: typed_ctor_method_arg.span(),
-                        err,
-                    },
-                )?)
-            } else {
-                None
-            };
+        if let (DependencyKind::All | DependencyKind::AllNamed, Some((key, _))) =
+            (kind, tags.first())
+        {
+            return Err(DependencyError::TaggedCollection {
+                tag_span: key.span(),
+            });
+        }
 
         Ok(Self {
             interface,
             ptr: ptr_ident,
-            name: opt_named_attr_input.map(|named_attr_input| named_attr_input.name),
+            name,
+            key,
+            tags,
+            kind,
         })
     }
 
@@ -121,6 +203,149 @@ impl Dependency
     {
         &self.name
     }
+
+    /// Returns the dependency's binding key.
+    #[allow(dead_code)] // Mock function is never used
+    pub fn get_key(&self) -> &Option<Type>
+    {
+        &self.key
+    }
+
+    /// Returns the dependency's tags.
+    #[allow(dead_code)] // Mock function is never used
+    pub fn get_tags(&self) -> &Vec<(LitStr, LitStr)>
+    {
+        &self.tags
+    }
+
+    /// Returns the dependency's kind.
+    #[allow(dead_code)] // Mock function is never used
+    pub fn get_kind(&self) -> DependencyKind
+    {
+        self.kind
+    }
+}
+
+impl Dependency
+{
+    fn build_key(
+        typed_ctor_method_arg: &PatType,
+        kind: DependencyKind,
+        name: Option<&LitStr>,
+    ) -> Result<Option<Type>, DependencyError>
+    {
+        let key = Self::parse_key(&typed_ctor_method_arg.attrs).map_err(|err| {
+            DependencyError::InvalidKeyedAttrInput {
+                arg_span: typed_ctor_method_arg.span(),
+                err,
+            }
+        })?;
+
+        if let (DependencyKind::All | DependencyKind::AllNamed, Some(key)) = (kind, &key)
+        {
+            return Err(DependencyError::KeyedCollection {
+                key_span: key.span(),
+            });
+        }
+
+        if let (Some(name), Some(_)) = (name, &key) {
+            return Err(DependencyError::NamedAndKeyed {
+                name_span: name.span(),
+            });
+        }
+
+        Ok(key)
+    }
+
+    fn get_dependency_type_path(arg_type: &Type) -> Result<&TypePath, DependencyError>
+    {
+        match arg_type {
+            Type::Path(arg_type_path) => Ok(arg_type_path),
+            Type::Reference(ref_type_path) => match ref_type_path.elem.as_ref() {
+                Type::Path(arg_type_path) => Ok(arg_type_path),
+                other_type => Err(DependencyError::InvalidType {
+                    type_span: other_type.span(),
+                }),
+            },
+            other_type => Err(DependencyError::InvalidType {
+                type_span: other_type.span(),
+            }),
+        }
+    }
+
+    fn get_type_path_ident(type_path: &TypePath) -> Option<&Ident>
+    {
+        type_path
+            .path
+            .segments
+            .last()
+            .map(|path_segment| &path_segment.ident)
+    }
+
+    fn get_generic_type(type_path: &TypePath, index: usize) -> Option<&Type>
+    {
+        let generic_args = match &type_path.path.segments.last()?.arguments {
+            PathArguments::AngleBracketed(generic_args) => &generic_args.args,
+            _ => return None,
+        };
+
+        match generic_args.iter().nth(index)? {
+            GenericArgument::Type(generic_type) => Some(generic_type),
+            _ => None,
+        }
+    }
+
+    fn parse_name(attrs: &[Attribute]) -> syn::Result<Option<LitStr>>
+    {
+        let opt_named_attr = attrs.iter().find(|attr| {
+            attr.path.get_ident().map_or_else(
+                || false,
+                |attr_ident| attr_ident.to_string().as_str() == "named",
+            ) || &attr.path.to_string() == "syrette::named"
+        });
+
+        opt_named_attr
+            .map(|named_attr| {
+                parse2::<NamedAttrInput>(named_attr.tokens.clone())
+                    .map(|named_attr_input| named_attr_input.name)
+            })
+            .transpose()
+    }
+
+    fn parse_key(attrs: &[Attribute]) -> syn::Result<Option<Type>>
+    {
+        let opt_keyed_attr = attrs.iter().find(|attr| {
+            attr.path.get_ident().map_or_else(
+                || false,
+                |attr_ident| attr_ident.to_string().as_str() == "keyed",
+            ) || &attr.path.to_string() == "syrette::keyed"
+        });
+
+        opt_keyed_attr
+            .map(|keyed_attr| {
+                parse2::<KeyedAttrInput>(keyed_attr.tokens.clone())
+                    .map(|keyed_attr_input| keyed_attr_input.key)
+            })
+            .transpose()
+    }
+
+    fn parse_tags(attrs: &[Attribute]) -> syn::Result<Vec<(LitStr, LitStr)>>
+    {
+        attrs
+            .iter()
+            .filter(|attr| {
+                attr.path.get_ident().map_or_else(
+                    || false,
+                    |attr_ident| attr_ident.to_string().as_str() == "tagged",
+                ) || &attr.path.to_string() == "syrette::tagged"
+            })
+            .map(|tagged_attr| {
+                parse2::<TaggedAttrInput>(tagged_attr.tokens.clone()).map(
+                    |tagged_attr_input| (tagged_attr_input.key, tagged_attr_input.value),
+                )
+            })
+            .collect()
+    }
 }
 
 diagnostic_error_enum! {
@@ -153,12 +378,59 @@ pub enum DependencyError
         ptr_ident_span: Span
     },
 
+    #[
+        error("Dependency of all bindings of a interface can't have a name"),
+        span = name_span
+    ]
+    #[help("Remove the 'named' attribute"), span = name_span]
+    NamedCollection {
+        name_span: Span
+    },
+
     #[error("Dependency has a 'named' attribute given invalid input"), span = arg_span]
     #[source(err)]
     InvalidNamedAttrInput {
         arg_span: Span,
         err: syn::Error
     },
+
+    #[
+        error("Dependency of all bindings of a interface can't have a key"),
+        span = key_span
+    ]
+    #[help("Remove the 'keyed' attribute"), span = key_span]
+    KeyedCollection {
+        key_span: Span
+    },
+
+    #[error("Dependency has a 'keyed' attribute given invalid input"), span = arg_span]
+    #[source(err)]
+    InvalidKeyedAttrInput {
+        arg_span: Span,
+        err: syn::Error
+    },
+
+    #[error("Dependency can't have both a name and a key"), span = name_span]
+    #[help("Remove either the 'named' or the 'keyed' attribute"), span = name_span]
+    NamedAndKeyed {
+        name_span: Span
+    },
+
+    #[
+        error("Dependency of all bindings of a interface can't have tags"),
+        span = tag_span
+    ]
+    #[help("Remove the 'tagged' attributes"), span = tag_span]
+    TaggedCollection {
+        tag_span: Span
+    },
+
+    #[error("Dependency has a 'tagged' attribute given invalid input"), span = arg_span]
+    #[source(err)]
+    InvalidTaggedAttrInput {
+        arg_span: Span,
+        err: syn::Error
+    },
 }
 }
 
@@ -206,7 +478,10 @@ mod tests
                     PathSegment::from(format_ident!("Foo"))
                 ])),
                 ptr: format_ident!("TransientPtr"),
-                name: None
+                name: None,
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::Single
             }
         ));
 
@@ -231,7 +506,10 @@ mod tests
                     PathSegment::from(format_ident!("Bar"))
                 ])),
                 ptr: format_ident!("SingletonPtr"),
-                name: None
+                name: None,
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::Single
             }
         ));
     }
@@ -267,7 +545,10 @@ mod tests
                     PathSegment::from(format_ident!("Foo"))
                 ])),
                 ptr: format_ident!("TransientPtr"),
-                name: Some(LitStr::new("cool", Span::call_site()))
+                name: Some(LitStr::new("cool", Span::call_site())),
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::Single
             }
         ));
 
@@ -301,7 +582,302 @@ mod tests
                     PathSegment::from(format_ident!("Bar"))
                 ])),
                 ptr: format_ident!("FactoryPtr"),
-                name: Some(LitStr::new("awesome", Span::call_site()))
+                name: Some(LitStr::new("awesome", Span::call_site())),
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::Single
+            }
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_with_key()
+    {
+        let create_keyed_attr = |tokens| Attribute {
+            pound_token: Pound::default(),
+            style: AttrStyle::Outer,
+            bracket_token: Bracket::default(),
+            path: test_utils::create_path(&[test_utils::create_path_segment(
+                format_ident!("keyed"),
+                &[],
+            )]),
+            tokens,
+        };
+
+        let key = test_utils::create_type(test_utils::create_path(&[
+            test_utils::create_path_segment(format_ident!("Primary"), &[]),
+        ]));
+
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![create_keyed_attr(quote! { (Primary) })],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("TransientPtr"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("TransientPtr"),
+                name: None,
+                key: Some(key.clone()),
+                tags: vec![],
+                kind: DependencyKind::Single
+            }
+        ));
+
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![create_keyed_attr(quote! { (Primary) })],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("Vec"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(
+                                format_ident!("TransientPtr"),
+                                &[test_utils::create_type(test_utils::create_path(&[
+                                    test_utils::create_path_segment(
+                                        format_ident!("Foo"),
+                                        &[]
+                                    )
+                                ]))]
+                            )
+                        ]))]
+                    ),
+                ])))
+            })),
+            Err(DependencyError::KeyedCollection { .. })
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_with_tags()
+    {
+        let create_tagged_attr = |tokens| Attribute {
+            pound_token: Pound::default(),
+            style: AttrStyle::Outer,
+            bracket_token: Bracket::default(),
+            path: test_utils::create_path(&[test_utils::create_path_segment(
+                format_ident!("tagged"),
+                &[],
+            )]),
+            tokens,
+        };
+
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![
+                    create_tagged_attr(quote! { (key = "region", value = "eu") }),
+                    create_tagged_attr(quote! { (key = "tier", value = "primary") }),
+                ],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("TransientPtr"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("TransientPtr"),
+                name: None,
+                key: None,
+                tags: vec![
+                    (
+                        LitStr::new("region", Span::call_site()),
+                        LitStr::new("eu", Span::call_site())
+                    ),
+                    (
+                        LitStr::new("tier", Span::call_site()),
+                        LitStr::new("primary", Span::call_site())
+                    ),
+                ],
+                kind: DependencyKind::Single
+            }
+        ));
+
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![create_tagged_attr(quote! { (key = "region") })],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("TransientPtr"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Err(DependencyError::InvalidTaggedAttrInput { .. })
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_of_all()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("Vec"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(
+                                format_ident!("TransientPtr"),
+                                &[test_utils::create_type(test_utils::create_path(&[
+                                    test_utils::create_path_segment(
+                                        format_ident!("Foo"),
+                                        &[]
+                                    )
+                                ]))]
+                            )
+                        ]))]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("TransientPtr"),
+                name: None,
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::All
+            }
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_of_optional()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("Option"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(
+                                format_ident!("SingletonPtr"),
+                                &[test_utils::create_type(test_utils::create_path(&[
+                                    test_utils::create_path_segment(
+                                        format_ident!("Foo"),
+                                        &[]
+                                    )
+                                ]))]
+                            )
+                        ]))]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("SingletonPtr"),
+                name: None,
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::Optional
+            }
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_of_lazy()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("Lazy"),
+                        &[test_utils::create_type(test_utils::create_path(&[
+                            test_utils::create_path_segment(format_ident!("Foo"), &[])
+                        ]))]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("Lazy"),
+                name: None,
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::Lazy
+            }
+        ));
+    }
+
+    #[test]
+    fn can_build_dependency_of_all_named()
+    {
+        assert!(matches!(
+            Dependency::build(&FnArg::Typed(PatType {
+                attrs: vec![],
+                pat: Box::new(Pat::Verbatim(TokenStream::default())),
+                colon_token: Colon::default(),
+                ty: Box::new(test_utils::create_type(test_utils::create_path(&[
+                    test_utils::create_path_segment(
+                        format_ident!("HashMap"),
+                        &[
+                            test_utils::create_type(test_utils::create_path(&[
+                                test_utils::create_path_segment(
+                                    format_ident!("String"),
+                                    &[]
+                                )
+                            ])),
+                            test_utils::create_type(test_utils::create_path(&[
+                                test_utils::create_path_segment(
+                                    format_ident!("TransientPtr"),
+                                    &[test_utils::create_type(test_utils::create_path(
+                                        &[test_utils::create_path_segment(
+                                            format_ident!("Foo"),
+                                            &[]
+                                        )]
+                                    ))]
+                                )
+                            ]))
+                        ]
+                    ),
+                ])))
+            })),
+            Ok(dependency) if dependency == Dependency {
+                interface: test_utils::create_type(test_utils::create_path(&[
+                    PathSegment::from(format_ident!("Foo"))
+                ])),
+                ptr: format_ident!("TransientPtr"),
+                name: None,
+                key: None,
+                tags: vec![],
+                kind: DependencyKind::AllNamed
             }
         ));
     }
@@ -16,7 +16,7 @@ use syn::{
     Type,
 };
 
-use crate::injectable::dependency::DependencyError;
+use crate::injectable::dependency::{DependencyError, DependencyKind};
 use crate::util::error::diagnostic_error_enum;
 use crate::util::item_impl::find_impl_method_by_name_mut;
 use crate::util::string::camelcase_to_snakecase;
@@ -86,7 +86,11 @@ impl InjectableImpl
         })
     }
 
-    pub fn validate(&self, is_async: bool) -> Result<(), InjectableImplError>
+    pub fn validate(
+        &self,
+        is_async: bool,
+        is_threadsafe: bool,
+    ) -> Result<(), InjectableImplError>
     {
         if matches!(self.constructor_method.sig.output, ReturnType::Default) {
             return Err(InjectableImplError::InvalidConstructorMethodReturnType {
@@ -145,6 +149,39 @@ impl InjectableImpl
                 generics_span: self.constructor_method.sig.generics.span(),
             });
         }
+
+        if is_async {
+            let opt_blocking_only_dependency = self
+                .dependencies
+                .iter()
+                .find(|dependency| dependency.get_kind().is_blocking_only());
+
+            if let Some(blocking_only_dependency) = opt_blocking_only_dependency {
+                let ptr = blocking_only_dependency.get_ptr();
+
+                return Err(InjectableImplError::AsyncUnsupportedDependency {
+                    ptr: ptr.clone(),
+                    ptr_span: ptr.span(),
+                });
+            }
+        }
+
+        if is_threadsafe {
+            let opt_blocking_only_dependency = self
+                .dependencies
+                .iter()
+                .find(|dependency| dependency.get_kind().is_blocking_only());
+
+            if let Some(blocking_only_dependency) = opt_blocking_only_dependency {
+                let ptr = blocking_only_dependency.get_ptr();
+
+                return Err(InjectableImplError::ThreadsafeUnsupportedDependency {
+                    ptr: ptr.clone(),
+                    ptr_span: ptr.span(),
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -154,8 +191,12 @@ impl InjectableImpl
     }
 
     #[cfg(not(tarpaulin_include))]
-    pub fn expand(&self, no_doc_hidden: bool, is_async: bool)
-        -> proc_macro2::TokenStream
+    pub fn expand(
+        &self,
+        no_doc_hidden: bool,
+        is_async: bool,
+        is_threadsafe: bool,
+    ) -> proc_macro2::TokenStream
     {
         let di_container_var = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dependency_history_var = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -169,7 +210,10 @@ impl InjectableImpl
         let maybe_prevent_circular_deps = if cfg!(feature = "prevent-circular") {
             Self::expand_prevent_circular_deps(&dependency_history_var)
         } else {
-            quote! {}
+            // The history is also needed to evaluate contextual binding constraints
+            quote! {
+                #dependency_history_var.push::<Self>();
+            }
         };
 
         let get_dep_method_calls = Self::create_get_dep_method_calls(
@@ -187,8 +231,22 @@ impl InjectableImpl
                 &maybe_prevent_circular_deps,
                 &get_dep_method_calls,
             )
+        } else if is_threadsafe {
+            self.expand_blocking_impl(
+                &quote! {
+                    syrette::interfaces::threadsafe_injectable::ThreadsafeInjectable
+                },
+                &quote! { syrette::di_container::threadsafe::ThreadsafeDIContainer },
+                &maybe_doc_hidden,
+                &di_container_var,
+                &dependency_history_var,
+                &maybe_prevent_circular_deps,
+                &get_dep_method_calls,
+            )
         } else {
             self.expand_blocking_impl(
+                &quote! { syrette::interfaces::injectable::Injectable },
+                &quote! { syrette::di_container::blocking::DIContainer },
                 &maybe_doc_hidden,
                 &di_container_var,
                 &dependency_history_var,
@@ -248,6 +306,8 @@ impl InjectableImpl
             quote! {}
         };
 
+        let dependency_infos = Self::create_dependency_infos(&self.dependencies);
+
         quote! {
             #maybe_doc_hidden
             impl #generics syrette::interfaces::async_injectable::AsyncInjectable<
@@ -289,13 +349,21 @@ impl InjectableImpl
                         )#maybe_await_constructor))
                     })
                 }
+
+                fn dependencies() -> Vec<syrette::dependency::DependencyInfo>
+                {
+                    vec![#(#dependency_infos),*]
+                }
             }
         }
     }
 
     #[cfg(not(tarpaulin_include))]
+    #[allow(clippy::too_many_arguments)]
     fn expand_blocking_impl(
         &self,
+        injectable_trait: &proc_macro2::TokenStream,
+        di_container_type: &proc_macro2::TokenStream,
         maybe_doc_hidden: &proc_macro2::TokenStream,
         di_container_var: &Ident,
         dependency_history_var: &Ident,
@@ -307,14 +375,14 @@ impl InjectableImpl
         let self_type = &self.original_impl.self_ty;
         let constructor = &self.constructor_method.sig.ident;
 
+        let dependency_infos = Self::create_dependency_infos(&self.dependencies);
+
         quote! {
             #maybe_doc_hidden
-            impl #generics syrette::interfaces::injectable::Injectable<
-                ::syrette::di_container::blocking::DIContainer
-            > for #self_type
+            impl #generics #injectable_trait<#di_container_type> for #self_type
             {
                 fn resolve(
-                    #di_container_var: &syrette::di_container::blocking::DIContainer,
+                    #di_container_var: &#di_container_type,
                     mut #dependency_history_var: syrette::dependency_history::DependencyHistory
                 ) -> Result<
                     syrette::ptr::TransientPtr<Self>,
@@ -332,6 +400,11 @@ impl InjectableImpl
                         #(#get_dep_method_calls),*
                     )));
                 }
+
+                fn dependencies() -> Vec<syrette::dependency::DependencyInfo>
+                {
+                    vec![#(#dependency_infos),*]
+                }
             }
         }
     }
@@ -356,6 +429,38 @@ impl InjectableImpl
             .collect()
     }
 
+    fn create_dependency_infos(dependencies: &[Dependency])
+        -> Vec<proc_macro2::TokenStream>
+    {
+        dependencies
+            .iter()
+            .map(|dependency| {
+                let interface = dependency.get_interface();
+
+                let kind = format_ident!(
+                    "{}",
+                    match dependency.get_kind() {
+                        DependencyKind::Single => "Single",
+                        DependencyKind::Optional => "Optional",
+                        DependencyKind::All => "All",
+                        DependencyKind::AllNamed => "AllNamed",
+                        DependencyKind::Lazy | DependencyKind::Provider => "Deferred",
+                        DependencyKind::WeakSingleton => "WeakSingleton",
+                    }
+                );
+
+                let binding_options = Self::create_binding_options(dependency);
+
+                quote! {
+                    syrette::dependency::DependencyInfo::new::<#interface>(
+                        syrette::dependency::DependencyKind::#kind,
+                        #binding_options
+                    )
+                }
+            })
+            .collect()
+    }
+
     fn create_binding_options(dependency: &Dependency) -> Expr
     {
         let binding_options_new = Expr::Call(ExprCall::new(
@@ -365,14 +470,34 @@ impl InjectableImpl
             [],
         ));
 
-        match dependency.get_name() {
-            Some(name) => Expr::MethodCall(ExprMethodCall::new(
+        let binding_options = match (dependency.get_name(), dependency.get_key()) {
+            (Some(name), _) => Expr::MethodCall(ExprMethodCall::new(
                 binding_options_new,
                 format_ident!("name"),
                 [Expr::Lit(ExprLit::new(name.clone()))],
             )),
-            None => binding_options_new,
-        }
+            (None, Some(key)) => Expr::MethodCall(
+                ExprMethodCall::new(binding_options_new, format_ident!("keyed"), [])
+                    .with_turbofish(MethodTurbofish::new([GenericMethodArgument::Type(
+                        key.clone(),
+                    )])),
+            ),
+            (None, None) => binding_options_new,
+        };
+
+        dependency
+            .get_tags()
+            .iter()
+            .fold(binding_options, |binding_options, (key, value)| {
+                Expr::MethodCall(ExprMethodCall::new(
+                    binding_options,
+                    format_ident!("tag"),
+                    [
+                        Expr::Lit(ExprLit::new(key.clone())),
+                        Expr::Lit(ExprLit::new(value.clone())),
+                    ],
+                ))
+            })
     }
 
     fn create_single_get_dep_method_call(
@@ -384,18 +509,41 @@ impl InjectableImpl
     {
         let dep_interface = dependency.get_interface();
 
-        let method_call = ExprMethodCall::new(
-            Expr::Path(ExprPath::new(di_container_var.clone().into())),
-            format_ident!("get_bound"),
-            [
-                Expr::MethodCall(ExprMethodCall::new(
-                    Expr::Path(ExprPath::new(dependency_history_var.clone().into())),
-                    format_ident!("clone"),
-                    [],
-                )),
-                Self::create_binding_options(dependency),
-            ],
-        )
+        let dependency_history_clone =
+            Self::create_dependency_history_clone(dependency_history_var);
+
+        let method_call = match dependency.get_kind() {
+            DependencyKind::Single | DependencyKind::Optional => ExprMethodCall::new(
+                Expr::Path(ExprPath::new(di_container_var.clone().into())),
+                format_ident!("get_bound"),
+                [
+                    dependency_history_clone,
+                    Self::create_binding_options(dependency),
+                ],
+            ),
+            DependencyKind::All => ExprMethodCall::new(
+                Expr::Path(ExprPath::new(di_container_var.clone().into())),
+                format_ident!("get_all_bound"),
+                [dependency_history_clone],
+            ),
+            DependencyKind::AllNamed => ExprMethodCall::new(
+                Expr::Path(ExprPath::new(di_container_var.clone().into())),
+                format_ident!("get_all_named_bound"),
+                [dependency_history_clone],
+            ),
+            DependencyKind::WeakSingleton => ExprMethodCall::new(
+                Expr::Path(ExprPath::new(di_container_var.clone().into())),
+                format_ident!("get_weak_singleton_bound"),
+                [Self::create_binding_options(dependency)],
+            ),
+            DependencyKind::Lazy | DependencyKind::Provider => {
+                Self::create_deferred_method_call(
+                    dependency,
+                    di_container_var,
+                    dependency_history_clone,
+                )
+            }
+        }
         .with_turbofish(MethodTurbofish::new([GenericMethodArgument::Type(
             dep_interface.clone(),
         )]));
@@ -419,13 +567,52 @@ impl InjectableImpl
 
         let dep_interface_str = dep_interface.to_token_stream().to_string();
 
+        if dependency.get_kind().is_blocking_only() {
+            return quote! {
+                #do_method_call.map_err(|err| #resolve_failed_error {
+                    reason: Box::new(err),
+                    affected: self_type_name
+                })?
+            };
+        }
+
+        if dependency.get_kind() == DependencyKind::Optional {
+            return Self::create_optional_get_dep(
+                &do_method_call,
+                &to_ptr,
+                &resolve_failed_error,
+                &dep_interface_str,
+                is_async,
+            );
+        }
+
+        let prepare_dependency = match dependency.get_kind() {
+            DependencyKind::Single
+            | DependencyKind::Optional
+            | DependencyKind::Lazy
+            | DependencyKind::Provider
+            | DependencyKind::WeakSingleton => quote! { .#to_ptr() },
+            DependencyKind::All => quote! {
+                .into_iter()
+                .map(|dependency| dependency.#to_ptr())
+                .collect::<Result<Vec<_>, _>>()
+            },
+            DependencyKind::AllNamed => quote! {
+                .into_iter()
+                .map(|(name, dependency)| {
+                    dependency.#to_ptr().map(|dependency| (name, dependency))
+                })
+                .collect::<Result<::std::collections::HashMap<_, _>, _>>()
+            },
+        };
+
         quote! {
             #do_method_call
                 .map_err(|err| #resolve_failed_error {
                     reason: Box::new(err),
                     affected: self_type_name
                 })?
-                .#to_ptr()
+                #prepare_dependency
                 .map_err(|err| InjectableError:: PrepareDependencyFailed {
                     reason: err,
                     dependency_name: #dep_interface_str
@@ -433,6 +620,77 @@ impl InjectableImpl
         }
     }
 
+    fn create_dependency_history_clone(dependency_history_var: &Ident) -> Expr
+    {
+        Expr::MethodCall(ExprMethodCall::new(
+            Expr::Path(ExprPath::new(dependency_history_var.clone().into())),
+            format_ident!("clone"),
+            [],
+        ))
+    }
+
+    fn create_deferred_method_call(
+        dependency: &Dependency,
+        di_container_var: &Ident,
+        dependency_history_clone: Expr,
+    ) -> ExprMethodCall
+    {
+        let method = if dependency.get_kind() == DependencyKind::Lazy {
+            format_ident!("get_lazy")
+        } else {
+            format_ident!("get_provider")
+        };
+
+        ExprMethodCall::new(
+            Expr::Path(ExprPath::new(di_container_var.clone().into())),
+            method,
+            [
+                dependency_history_clone,
+                Self::create_binding_options(dependency),
+            ],
+        )
+    }
+
+    fn create_optional_get_dep(
+        do_method_call: &proc_macro2::TokenStream,
+        to_ptr: &Ident,
+        resolve_failed_error: &proc_macro2::TokenStream,
+        dep_interface_str: &str,
+        is_async: bool,
+    ) -> proc_macro2::TokenStream
+    {
+        let binding_not_found_error = if is_async {
+            quote! {
+                syrette::errors::async_di_container::AsyncDIContainerError
+                    ::BindingNotFound
+            }
+        } else {
+            quote! {
+                syrette::errors::di_container::DIContainerError::BindingNotFound
+            }
+        };
+
+        quote! {
+            match #do_method_call {
+                Ok(dependency) => Some(
+                    dependency
+                        .#to_ptr()
+                        .map_err(|err| InjectableError::PrepareDependencyFailed {
+                            reason: err,
+                            dependency_name: #dep_interface_str
+                        })?
+                ),
+                Err(#binding_not_found_error { .. }) => None,
+                Err(err) => {
+                    return Err(#resolve_failed_error {
+                        reason: Box::new(err),
+                        affected: self_type_name
+                    });
+                }
+            }
+        }
+    }
+
     fn build_dependencies(
         ctor_method: &ImplItemMethod,
     ) -> Result<Vec<Dependency>, DependencyError>
@@ -465,11 +723,17 @@ impl InjectableImpl
                 .iter()
                 .enumerate()
                 .filter_map(|(index, attr)| {
-                    if &attr.path.to_string() == "syrette::named" {
+                    if matches!(
+                        attr.path.to_string().as_str(),
+                        "syrette::named" | "syrette::keyed" | "syrette::tagged"
+                    ) {
                         return Some(index);
                     }
 
-                    if attr.path.get_ident()?.to_string().as_str() == "named" {
+                    if matches!(
+                        attr.path.get_ident()?.to_string().as_str(),
+                        "named" | "keyed" | "tagged"
+                    ) {
                         return Some(index);
                     }
 
@@ -477,7 +741,8 @@ impl InjectableImpl
                 })
                 .collect();
 
-            for attr_index in attrs_to_remove {
+            // Removed in reverse so that the indices of the remaining ones stay valid
+            for attr_index in attrs_to_remove.into_iter().rev() {
                 typed_arg.attrs.remove(attr_index);
             }
         }
@@ -553,6 +818,24 @@ pub enum InjectableImplError
         generics_span: Span
     },
 
+    #[
+        error("'{ptr}' dependencies are not supported by async injectables"),
+        span = ptr_span
+    ]
+    AsyncUnsupportedDependency {
+        ptr: Ident,
+        ptr_span: Span
+    },
+
+    #[
+        error("'{ptr}' dependencies are not supported by threadsafe injectables"),
+        span = ptr_span
+    ]
+    ThreadsafeUnsupportedDependency {
+        ptr: Ident,
+        ptr_span: Span
+    },
+
     #[error("Has a invalid dependency"), span = implementation_span]
     #[source(err)]
     ContainsAInvalidDependency {
@@ -609,6 +892,59 @@ mod tests
         }
     }
 
+    /// Returns a mock of a dependency of `interface` in a `ptr` that is of the specified
+    /// kind & has the specified name, key & tags.
+    fn create_mock_dependency(
+        interface: &str,
+        ptr: &str,
+        kind: DependencyKind,
+        name: Option<&str>,
+        key: Option<&str>,
+        tags: &[(&str, &str)],
+    ) -> MockDependency
+    {
+        let mut mock_dependency = MockDependency::new();
+
+        mock_dependency
+            .expect_get_interface()
+            .return_const(create_type(create_path(&[create_path_segment(
+                format_ident!("{}", interface),
+                &[],
+            )])));
+
+        mock_dependency
+            .expect_get_ptr()
+            .return_const(format_ident!("{}", ptr));
+
+        mock_dependency.expect_get_kind().return_const(kind);
+
+        mock_dependency
+            .expect_get_name()
+            .return_const(name.map(|name| LitStr::new(name, Span::call_site())));
+
+        mock_dependency
+            .expect_get_key()
+            .return_const(key.map(|key| {
+                create_type(create_path(&[create_path_segment(
+                    format_ident!("{}", key),
+                    &[],
+                )]))
+            }));
+
+        mock_dependency.expect_get_tags().return_const(
+            tags.iter()
+                .map(|(key, value)| {
+                    (
+                        LitStr::new(key, Span::call_site()),
+                        LitStr::new(value, Span::call_site()),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        mock_dependency
+    }
+
     #[test]
     fn can_build_dependencies()
     {
@@ -833,20 +1169,14 @@ mod tests
     #[test]
     fn can_create_single_get_dep_method_call()
     {
-        let mut mock_dependency = MockDependency::new();
-
-        mock_dependency
-            .expect_get_interface()
-            .return_const(create_type(create_path(&[create_path_segment(
-                format_ident!("Foo"),
-                &[],
-            )])));
-
-        mock_dependency.expect_get_name().return_const(None);
-
-        mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::Single,
+            None,
+            None,
+            &[],
+        );
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -883,22 +1213,14 @@ mod tests
     #[test]
     fn can_create_single_get_dep_method_call_with_name()
     {
-        let mut mock_dependency = MockDependency::new();
-
-        mock_dependency
-            .expect_get_interface()
-            .return_const(create_type(create_path(&[create_path_segment(
-                format_ident!("Foo"),
-                &[],
-            )])));
-
-        mock_dependency
-            .expect_get_name()
-            .return_const(Some(LitStr::new("special", Span::call_site())));
-
-        mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::Single,
+            Some("special"),
+            None,
+            &[],
+        );
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -933,22 +1255,60 @@ mod tests
     }
 
     #[test]
-    fn can_create_single_get_dep_method_call_async()
+    fn can_create_single_get_dep_method_call_with_key()
     {
-        let mut mock_dependency = MockDependency::new();
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::Single,
+            None,
+            Some("Primary"),
+            &[],
+        );
 
-        mock_dependency
-            .expect_get_interface()
-            .return_const(create_type(create_path(&[create_path_segment(
-                format_ident!("Foo"),
-                &[],
-            )])));
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+        let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
 
-        mock_dependency.expect_get_name().return_const(None);
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
 
-        mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                #di_container_var_ident
+                    .get_bound::<Foo>(
+                        #dep_history_var_ident.clone(),
+                        syrette::di_container::BindingOptions::new().keyed::<Primary>()
+                    )
+                    .map_err(|err| InjectableError::ResolveFailed {
+                        reason: Box::new(err),
+                        affected: self_type_name
+                    })?
+                    .transient()
+                    .map_err(|err| InjectableError::PrepareDependencyFailed {
+                        reason: err,
+                        dependency_name: "Foo"
+                    })?
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_async()
+    {
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::Single,
+            None,
+            None,
+            &[],
+        );
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -986,22 +1346,14 @@ mod tests
     #[test]
     fn can_create_single_get_dep_method_call_async_with_name()
     {
-        let mut mock_dependency = MockDependency::new();
-
-        mock_dependency
-            .expect_get_interface()
-            .return_const(create_type(create_path(&[create_path_segment(
-                format_ident!("Foo"),
-                &[],
-            )])));
-
-        mock_dependency
-            .expect_get_name()
-            .return_const(Some(LitStr::new("foobar", Span::call_site())));
-
-        mock_dependency
-            .expect_get_ptr()
-            .return_const(format_ident!("TransientPtr"));
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::Single,
+            Some("foobar"),
+            None,
+            &[],
+        );
 
         let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
         let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
@@ -1035,4 +1387,272 @@ mod tests
             .unwrap()
         );
     }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_for_all()
+    {
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "SingletonPtr",
+            DependencyKind::All,
+            None,
+            None,
+            &[],
+        );
+
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+        let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
+
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
+
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                #di_container_var_ident
+                    .get_all_bound::<Foo>(#dep_history_var_ident.clone())
+                    .map_err(|err| InjectableError::ResolveFailed {
+                        reason: Box::new(err),
+                        affected: self_type_name
+                    })?
+                    .into_iter()
+                    .map(|dependency| dependency.singleton())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| InjectableError::PrepareDependencyFailed {
+                        reason: err,
+                        dependency_name: "Foo"
+                    })?
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_for_all_named()
+    {
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::AllNamed,
+            None,
+            None,
+            &[],
+        );
+
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+        let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
+
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
+
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                #di_container_var_ident
+                    .get_all_named_bound::<Foo>(#dep_history_var_ident.clone())
+                    .map_err(|err| InjectableError::ResolveFailed {
+                        reason: Box::new(err),
+                        affected: self_type_name
+                    })?
+                    .into_iter()
+                    .map(|(name, dependency)| {
+                        dependency.transient().map(|dependency| (name, dependency))
+                    })
+                    .collect::<Result<::std::collections::HashMap<_, _>, _>>()
+                    .map_err(|err| InjectableError::PrepareDependencyFailed {
+                        reason: err,
+                        dependency_name: "Foo"
+                    })?
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_for_optional()
+    {
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::Optional,
+            None,
+            None,
+            &[],
+        );
+
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+        let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
+
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
+
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                match #di_container_var_ident.get_bound::<Foo>(
+                    #dep_history_var_ident.clone(),
+                    syrette::di_container::BindingOptions::new()
+                ) {
+                    Ok(dependency) => Some(
+                        dependency
+                            .transient()
+                            .map_err(|err| InjectableError::PrepareDependencyFailed {
+                                reason: err,
+                                dependency_name: "Foo"
+                            })?
+                    ),
+                    Err(syrette::errors::di_container::DIContainerError::BindingNotFound {
+                        ..
+                    }) => None,
+                    Err(err) => {
+                        return Err(InjectableError::ResolveFailed {
+                            reason: Box::new(err),
+                            affected: self_type_name
+                        });
+                    }
+                }
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_for_lazy()
+    {
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "Lazy",
+            DependencyKind::Lazy,
+            Some("bar"),
+            None,
+            &[("region", "eu")],
+        );
+
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+        let dep_history_var_ident = format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME);
+
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
+
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                #di_container_var_ident
+                    .get_lazy::<Foo>(
+                        #dep_history_var_ident.clone(),
+                        syrette::di_container::BindingOptions::new()
+                            .name("bar")
+                            .tag("region", "eu")
+                    )
+                    .map_err(|err| InjectableError::ResolveFailed {
+                        reason: Box::new(err),
+                        affected: self_type_name
+                    })?
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_single_get_dep_method_call_for_weak_singleton()
+    {
+        let mock_dependency = create_mock_dependency(
+            "Foo",
+            "WeakSingletonPtr",
+            DependencyKind::WeakSingleton,
+            None,
+            None,
+            &[],
+        );
+
+        let di_container_var_ident = format_ident!("{}", DI_CONTAINER_VAR_NAME);
+
+        let output = InjectableImpl::create_single_get_dep_method_call(
+            &mock_dependency,
+            false,
+            &format_ident!("{}", DI_CONTAINER_VAR_NAME),
+            &format_ident!("{}", DEPENDENCY_HISTORY_VAR_NAME),
+        );
+
+        assert_eq!(
+            parse2::<Expr>(output).unwrap(),
+            parse2::<Expr>(quote! {
+                #di_container_var_ident
+                    .get_weak_singleton_bound::<Foo>(
+                        syrette::di_container::BindingOptions::new()
+                    )
+                    .map_err(|err| InjectableError::ResolveFailed {
+                        reason: Box::new(err),
+                        affected: self_type_name
+                    })?
+            })
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn can_create_dependency_infos()
+    {
+        let mock_single_dependency = create_mock_dependency(
+            "Foo",
+            "TransientPtr",
+            DependencyKind::Single,
+            Some("bar"),
+            None,
+            &[],
+        );
+
+        let mock_lazy_dependency = create_mock_dependency(
+            "Bar",
+            "TransientPtr",
+            DependencyKind::Lazy,
+            None,
+            None,
+            &[],
+        );
+
+        let output = InjectableImpl::create_dependency_infos(&[
+            mock_single_dependency,
+            mock_lazy_dependency,
+        ]);
+
+        assert_eq!(
+            output
+                .into_iter()
+                .map(|dependency_info| parse2::<Expr>(dependency_info).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                parse2::<Expr>(quote! {
+                    syrette::dependency::DependencyInfo::new::<Foo>(
+                        syrette::dependency::DependencyKind::Single,
+                        syrette::di_container::BindingOptions::new().name("bar")
+                    )
+                })
+                .unwrap(),
+                parse2::<Expr>(quote! {
+                    syrette::dependency::DependencyInfo::new::<Bar>(
+                        syrette::dependency::DependencyKind::Deferred,
+                        syrette::di_container::BindingOptions::new()
+                    )
+                })
+                .unwrap()
+            ]
+        );
+    }
 }
@@ -0,0 +1,36 @@
+use quote::ToTokens;
+use syn::parse::Parse;
+use syn::token::Paren;
+use syn::{parenthesized, Type};
+
+pub struct KeyedAttrInput
+{
+    pub paren: Paren,
+    pub key: Type,
+}
+
+impl Parse for KeyedAttrInput
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self>
+    {
+        let content;
+
+        let paren = parenthesized!(content in input);
+
+        Ok(Self {
+            paren,
+            key: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for KeyedAttrInput
+{
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream)
+    {
+        self.paren
+            .surround(&mut self.key.to_token_stream(), |stream| {
+                stream.to_tokens(tokens);
+            });
+    }
+}
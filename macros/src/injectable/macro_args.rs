@@ -10,6 +10,7 @@ use crate::util::iterator_ext::IteratorExt;
 pub const INJECTABLE_MACRO_FLAGS: &[&str] = &[
     "no_doc_hidden",
     "async",
+    "threadsafe",
     "no_declare_concrete_interface",
     "constructor",
 ];
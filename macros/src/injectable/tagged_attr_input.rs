@@ -0,0 +1,92 @@
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::token::Paren;
+use syn::{parenthesized, Ident, LitStr, Token};
+
+pub struct TaggedAttrInput
+{
+    pub paren: Paren,
+    pub key: LitStr,
+    pub value: LitStr,
+}
+
+impl TaggedAttrInput
+{
+    fn parse_field(input: ParseStream, field_name: &str) -> syn::Result<LitStr>
+    {
+        let field_ident = input.parse::<Ident>()?;
+
+        if field_ident != field_name {
+            return Err(syn::Error::new(
+                field_ident.span(),
+                format!("Expected '{field_name}'"),
+            ));
+        }
+
+        input.parse::<Token![=]>()?;
+
+        input.parse()
+    }
+}
+
+impl Parse for TaggedAttrInput
+{
+    fn parse(input: ParseStream) -> syn::Result<Self>
+    {
+        let content;
+
+        let paren = parenthesized!(content in input);
+
+        let key = Self::parse_field(&content, "key")?;
+
+        content.parse::<Token![,]>()?;
+
+        let value = Self::parse_field(&content, "value")?;
+
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Self { paren, key, value })
+    }
+}
+
+impl ToTokens for TaggedAttrInput
+{
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream)
+    {
+        let key = &self.key;
+        let value = &self.value;
+
+        self.paren.surround(
+            &mut quote! { key = #key, value = #value },
+            |stream| {
+                stream.to_tokens(tokens);
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use syn::parse2;
+
+    use super::*;
+
+    #[test]
+    fn can_parse()
+    {
+        let tagged_attr_input =
+            parse2::<TaggedAttrInput>(quote! { (key = "region", value = "eu",) })
+                .unwrap();
+
+        assert_eq!(tagged_attr_input.key.value(), "region");
+        assert_eq!(tagged_attr_input.value.value(), "eu");
+
+        assert!(parse2::<TaggedAttrInput>(quote! { (value = "eu", key = "region") })
+            .is_err());
+
+        assert!(parse2::<TaggedAttrInput>(quote! { (key = "region") }).is_err());
+    }
+}
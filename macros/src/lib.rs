@@ -73,6 +73,14 @@ const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 ///
 /// This flag must be set to `true` for the type to be usable with [`AsyncDIContainer`].
 ///
+/// #### `threadsafe`
+/// **Value:** boolean literal<br>
+/// **Default:** `false`<br>
+/// Generate an implementation of [`ThreadsafeInjectable`] instead of [`Injectable`].
+///
+/// This flag must be set to `true` for the type to be usable with
+/// [`ThreadsafeDIContainer`]. Can't be combined with the `async` flag.
+///
 /// #### `constructor`
 /// **Value:** identifier<br>
 /// **Default:** `new`<br>
@@ -123,6 +131,74 @@ const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// }
 /// # }
 /// ```
+/// <br>
+///
+/// A dependency wrapped in a [`Option`] is resolved to [`None`] when the interface has
+/// no binding. Any other failure to resolve it is still returned as a error.
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::injectable;
+/// #
+/// # trait IMetricsSink {}
+/// #
+/// # struct Service { metrics_sink: Option<TransientPtr<dyn IMetricsSink>> }
+/// #
+/// #[injectable]
+/// impl Service
+/// {
+///     pub fn new(metrics_sink: Option<TransientPtr<dyn IMetricsSink>>) -> Self
+///     {
+///         Self { metrics_sink }
+///     }
+/// }
+/// ```
+/// <br>
+///
+/// A dependency wrapped in a [`Vec`] is resolved to every binding of the interface
+/// without a name or tags, in the order they were bound.
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::injectable;
+/// #
+/// # trait IPlugin {}
+/// #
+/// # struct PluginManager { plugins: Vec<TransientPtr<dyn IPlugin>> }
+/// #
+/// #[injectable]
+/// impl PluginManager
+/// {
+///     pub fn new(plugins: Vec<TransientPtr<dyn IPlugin>>) -> Self
+///     {
+///         Self { plugins }
+///     }
+/// }
+/// ```
+/// <br>
+///
+/// A dependency wrapped in a [`HashMap`] with [`String`] keys is resolved to every
+/// named binding of the interface, keyed by name.
+/// ```
+/// # use std::collections::HashMap;
+/// #
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::injectable;
+/// #
+/// # trait ICommandHandler {}
+/// #
+/// # struct CommandDispatcher
+/// # {
+/// #     handlers: HashMap<String, TransientPtr<dyn ICommandHandler>>,
+/// # }
+/// #
+/// #[injectable]
+/// impl CommandDispatcher
+/// {
+///     pub fn new(handlers: HashMap<String, TransientPtr<dyn ICommandHandler>>) -> Self
+///     {
+///         Self { handlers }
+///     }
+/// }
+/// ```
 ///
 /// # Attributes
 /// Attributes specific to impls with this attribute macro.
@@ -167,12 +243,87 @@ const PACKAGE_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// # impl IKnight for Knight {}
 /// ```
 ///
+/// ### Keyed
+/// Used inside of the constructor method before a dependency argument. Declares the
+/// binding key of the dependency. Should be given a type implementing `BindingKey`,
+/// usually a unit struct, inside parenthesis. Can't be combined with the `named`
+/// attribute.
+///
+/// The [`macro@keyed`] ghost attribute macro can be used for intellisense and
+/// autocompletion for this attribute.
+///
+/// For example:
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::di_container::BindingKey;
+/// # use syrette::injectable;
+/// #
+/// # trait IDatabase {}
+/// #
+/// # struct Replica;
+/// #
+/// # impl BindingKey for Replica {}
+/// #
+/// # struct ReportService
+/// # {
+/// #     database: TransientPtr<dyn IDatabase>,
+/// # }
+/// #
+/// #[injectable]
+/// impl ReportService
+/// {
+///     pub fn new(
+///         #[keyed(Replica)] database: TransientPtr<dyn IDatabase>,
+///     ) -> Self
+///     {
+///         Self { database }
+///     }
+/// }
+/// ```
+///
+/// ### Tagged
+/// Used inside of the constructor method before a dependency argument. Declares a tag of
+/// the dependency. Should be given the key and value of the tag inside parenthesis. Can
+/// be used multiple times on the same argument.
+///
+/// The [`macro@tagged`] ghost attribute macro can be used for intellisense and
+/// autocompletion for this attribute.
+///
+/// For example:
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::injectable;
+/// #
+/// # trait IDatabase {}
+/// #
+/// # struct ReportService
+/// # {
+/// #     database: TransientPtr<dyn IDatabase>,
+/// # }
+/// #
+/// #[injectable]
+/// impl ReportService
+/// {
+///     pub fn new(
+///         #[tagged(key = "region", value = "eu")]
+///         #[tagged(key = "access", value = "read_only")]
+///         database: TransientPtr<dyn IDatabase>,
+///     ) -> Self
+///     {
+///         Self { database }
+///     }
+/// }
+/// ```
+///
 /// [`DIContainer`]: ../syrette/di_container/blocking/struct.DIContainer.html
 /// [`AsyncDIContainer`]: ../syrette/di_container/asynchronous/struct.AsyncDIContainer.html
 /// [`Injectable`]: ../syrette/interfaces/injectable/trait.Injectable.html
 /// [`AsyncInjectable`]: ../syrette/interfaces/async_injectable/trait.AsyncInjectable.html
+/// [`ThreadsafeDIContainer`]: ../syrette/di_container/threadsafe/struct.ThreadsafeDIContainer.html
+/// [`ThreadsafeInjectable`]: ../syrette/interfaces/threadsafe_injectable/trait.ThreadsafeInjectable.html
 /// [`di_container_bind`]: ../syrette/macro.di_container_bind.html
 /// [`async`]: https://doc.rust-lang.org/std/keyword.async.html
+/// [`HashMap`]: std::collections::HashMap
 #[cfg(not(tarpaulin_include))]
 #[proc_macro_error]
 #[proc_macro_attribute]
@@ -254,11 +405,33 @@ pub fn injectable(args_stream: TokenStream, input_stream: TokenStream) -> TokenS
         );
     }
 
+    let is_threadsafe_flag = args
+        .flags
+        .iter()
+        .find(|flag| flag.name() == "threadsafe")
+        .cloned()
+        .unwrap_or_else(|| MacroFlag::new_off("threadsafe"));
+
+    let is_threadsafe = is_threadsafe_flag.get_bool().unwrap_or_abort();
+
+    if is_async && is_threadsafe {
+        use proc_macro_error::abort;
+
+        abort!(
+            is_threadsafe_flag.name().span(),
+            "The 'threadsafe' flag cannot be combined with the 'async' flag";
+            help = "Async injectables are already thread-safe"
+        );
+    }
+
     let injectable_impl = InjectableImpl::new(item_impl, &constructor).unwrap_or_abort();
 
-    injectable_impl.validate(is_async).unwrap_or_abort();
+    injectable_impl
+        .validate(is_async, is_threadsafe)
+        .unwrap_or_abort();
 
-    let expanded_injectable_impl = injectable_impl.expand(no_doc_hidden, is_async);
+    let expanded_injectable_impl =
+        injectable_impl.expand(no_doc_hidden, is_async, is_threadsafe);
 
     let self_type = injectable_impl.self_type();
 
@@ -271,7 +444,7 @@ pub fn injectable(args_stream: TokenStream, input_stream: TokenStream) -> TokenS
     });
 
     let maybe_decl_interface = if let Some(interface) = opt_interface {
-        let threadsafe_sharable_flag = if is_async {
+        let threadsafe_sharable_flag = if is_async || is_threadsafe {
             quote! { , threadsafe_sharable = true }
         } else {
             quote! {}
@@ -399,6 +572,84 @@ pub fn named(_: TokenStream, _: TokenStream) -> TokenStream
     TokenStream::new()
 }
 
+/// Used to declare the binding key of a dependency in the constructor of a impl block
+/// decorated with [`macro@injectable`].
+///
+/// **This macro attribute doesn't actually do anything**. It only exists for the
+/// convenience of having intellisense, autocompletion and documentation.
+///
+/// # Examples
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::di_container::BindingKey;
+/// # use syrette::injectable;
+/// #
+/// # trait IDatabase {}
+/// #
+/// # struct Primary;
+/// #
+/// # impl BindingKey for Primary {}
+/// #
+/// # struct UserRepository
+/// # {
+/// #   database: TransientPtr<dyn IDatabase>,
+/// # }
+/// #
+/// #[injectable]
+/// impl UserRepository
+/// {
+///     pub fn new(
+///         #[syrette::keyed(Primary)] database: TransientPtr<dyn IDatabase>,
+///     ) -> Self
+///     {
+///         Self { database }
+///     }
+/// }
+/// ```
+#[cfg(not(tarpaulin_include))]
+#[proc_macro_attribute]
+pub fn keyed(_: TokenStream, _: TokenStream) -> TokenStream
+{
+    TokenStream::new()
+}
+
+/// Used to declare a tag of a dependency in the constructor of a impl block decorated
+/// with [`macro@injectable`].
+///
+/// **This macro attribute doesn't actually do anything**. It only exists for the
+/// convenience of having intellisense, autocompletion and documentation.
+///
+/// # Examples
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::injectable;
+/// #
+/// # trait IDatabase {}
+/// #
+/// # struct ReportService
+/// # {
+/// #   database: TransientPtr<dyn IDatabase>,
+/// # }
+/// #
+/// #[injectable]
+/// impl ReportService
+/// {
+///     pub fn new(
+///         #[syrette::tagged(key = "region", value = "eu")]
+///         database: TransientPtr<dyn IDatabase>,
+///     ) -> Self
+///     {
+///         Self { database }
+///     }
+/// }
+/// ```
+#[cfg(not(tarpaulin_include))]
+#[proc_macro_attribute]
+pub fn tagged(_: TokenStream, _: TokenStream) -> TokenStream
+{
+    TokenStream::new()
+}
+
 #[cfg(syrette_macros_logging)]
 fn init_logging()
 {
@@ -3,7 +3,6 @@ use std::fmt::Debug;
 
 use crate::ptr::TransientPtr;
 
-#[cfg(feature = "async")]
 pub mod threadsafe;
 
 /// Interface for any castable function.
@@ -76,7 +76,7 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "async"))]
 mod tests
 {
     use super::*;
@@ -0,0 +1,306 @@
+//! Handles to dependencies that are resolved after injection.
+//!
+//! Both handles resolve through the [`DIContainer`] that created them, which must be
+//! shared using [`DIContainer::into_shared`] by the time they are used. They can be
+//! created before that, for example when injected into a singleton.
+//!
+//! Binding conditions see the types that a handle was injected into when it resolves.
+//!
+//! [`DIContainer`]: crate::di_container::blocking::DIContainer
+//! [`DIContainer::into_shared`]: crate::di_container::blocking::DIContainer::into_shared
+use std::fmt::Debug;
+
+use once_cell::unsync::OnceCell;
+
+use crate::errors::di_container::DIContainerError;
+use crate::ptr::SomePtr;
+
+type ResolveFn<Interface> = dyn Fn() -> Result<SomePtr<Interface>, DIContainerError>;
+
+/// Handle to a dependency that is resolved the first time it is used.
+///
+/// The resolved instance is kept for the lifetime of the handle.
+///
+/// # Examples
+/// ```
+/// # use syrette::deferred::Lazy;
+/// # use syrette::{DIContainer, injectable};
+/// #
+/// # trait IDatabase
+/// # {
+/// #     fn query(&self) -> &'static str;
+/// # }
+/// #
+/// # struct Database {}
+/// #
+/// # #[injectable(IDatabase)]
+/// # impl Database
+/// # {
+/// #     fn new() -> Self
+/// #     {
+/// #         Self {}
+/// #     }
+/// # }
+/// #
+/// # impl IDatabase for Database
+/// # {
+/// #     fn query(&self) -> &'static str
+/// #     {
+/// #         "result"
+/// #     }
+/// # }
+/// #
+/// struct ReportService
+/// {
+///     database: Lazy<dyn IDatabase>,
+/// }
+///
+/// #[injectable]
+/// impl ReportService
+/// {
+///     fn new(database: Lazy<dyn IDatabase>) -> Self
+///     {
+///         Self { database }
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut di_container = DIContainer::new();
+///
+/// di_container.bind::<dyn IDatabase>().to::<Database>()?;
+/// di_container.bind::<ReportService>().to::<ReportService>()?;
+///
+/// let di_container = di_container.into_shared();
+///
+/// let report_service = di_container.get::<ReportService>()?.transient()?;
+///
+/// // The database is resolved here
+/// assert_eq!(report_service.database.get()?.query(), "result");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Lazy<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    resolve: Box<ResolveFn<Interface>>,
+    instance: OnceCell<SomePtr<Interface>>,
+}
+
+impl<Interface> Lazy<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    pub(crate) fn new(
+        resolve: impl Fn() -> Result<SomePtr<Interface>, DIContainerError> + 'static,
+    ) -> Self
+    {
+        Self {
+            resolve: Box::new(resolve),
+            instance: OnceCell::new(),
+        }
+    }
+
+    /// Returns the dependency, resolving it if it hasn't been resolved yet.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - The [`DIContainer`] that created this handle isn't shared yet
+    /// - The [`DIContainer`] that created this handle has been dropped
+    /// - Resolving the dependency fails
+    ///
+    /// [`DIContainer`]: crate::di_container::blocking::DIContainer
+    pub fn get(&self) -> Result<&Interface, DIContainerError>
+    {
+        let instance = self.instance.get_or_try_init(|| (self.resolve)())?;
+
+        Ok(some_ptr_as_ref(instance))
+    }
+}
+
+impl<Interface> Debug for Lazy<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        formatter
+            .debug_struct("Lazy")
+            .field("is_resolved", &self.instance.get().is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Handle that resolves the dependency every time it is asked for it.
+///
+/// A transient binding gives a new instance on every call to [`Provider::get`].
+///
+/// # Examples
+/// ```
+/// # use syrette::deferred::Provider;
+/// # use syrette::{DIContainer, injectable};
+/// #
+/// # trait IRequest {}
+/// #
+/// # struct Request {}
+/// #
+/// # #[injectable(IRequest)]
+/// # impl Request
+/// # {
+/// #     fn new() -> Self
+/// #     {
+/// #         Self {}
+/// #     }
+/// # }
+/// #
+/// # impl IRequest for Request {}
+/// #
+/// struct Client
+/// {
+///     request_provider: Provider<dyn IRequest>,
+/// }
+///
+/// #[injectable]
+/// impl Client
+/// {
+///     fn new(request_provider: Provider<dyn IRequest>) -> Self
+///     {
+///         Self { request_provider }
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut di_container = DIContainer::new();
+///
+/// di_container.bind::<dyn IRequest>().to::<Request>()?;
+/// di_container.bind::<Client>().to::<Client>()?;
+///
+/// let di_container = di_container.into_shared();
+///
+/// let client = di_container.get::<Client>()?.transient()?;
+///
+/// let first_request = client.request_provider.get()?.transient()?;
+/// let second_request = client.request_provider.get()?.transient()?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct Provider<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    resolve: Box<ResolveFn<Interface>>,
+}
+
+impl<Interface> Provider<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    pub(crate) fn new(
+        resolve: impl Fn() -> Result<SomePtr<Interface>, DIContainerError> + 'static,
+    ) -> Self
+    {
+        Self {
+            resolve: Box::new(resolve),
+        }
+    }
+
+    /// Resolves the dependency.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - The [`DIContainer`] that created this handle isn't shared yet
+    /// - The [`DIContainer`] that created this handle has been dropped
+    /// - Resolving the dependency fails
+    ///
+    /// [`DIContainer`]: crate::di_container::blocking::DIContainer
+    pub fn get(&self) -> Result<SomePtr<Interface>, DIContainerError>
+    {
+        (self.resolve)()
+    }
+}
+
+impl<Interface> Debug for Provider<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        formatter.debug_struct("Provider").finish_non_exhaustive()
+    }
+}
+
+fn some_ptr_as_ref<Interface>(some_ptr: &SomePtr<Interface>) -> &Interface
+where
+    Interface: 'static + ?Sized,
+{
+    match some_ptr {
+        SomePtr::Transient(ptr) => ptr,
+        SomePtr::Singleton(ptr) => ptr,
+        #[cfg(feature = "factory")]
+        SomePtr::Factory(ptr) => ptr,
+        SomePtr::ThreadsafeSingleton(ptr) => ptr,
+        #[cfg(feature = "factory")]
+        SomePtr::ThreadsafeFactory(ptr) => ptr,
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::ptr::TransientPtr;
+    use crate::test_utils::subjects;
+
+    #[test]
+    fn lazy_resolves_once()
+    {
+        let resolve_count = Rc::new(Cell::new(0));
+
+        let lazy = Lazy::<dyn subjects::INumber>::new({
+            let resolve_count = resolve_count.clone();
+
+            move || {
+                resolve_count.set(resolve_count.get() + 1);
+
+                Ok(SomePtr::Transient(TransientPtr::new(subjects::Number {
+                    num: 42,
+                })))
+            }
+        });
+
+        assert_eq!(resolve_count.get(), 0);
+
+        assert_eq!(lazy.get().unwrap().get(), 42);
+        assert_eq!(lazy.get().unwrap().get(), 42);
+
+        assert_eq!(resolve_count.get(), 1);
+    }
+
+    #[test]
+    fn provider_resolves_every_time()
+    {
+        let resolve_count = Rc::new(Cell::new(0));
+
+        let provider = Provider::<dyn subjects::INumber>::new({
+            let resolve_count = resolve_count.clone();
+
+            move || {
+                resolve_count.set(resolve_count.get() + 1);
+
+                Ok(SomePtr::Transient(TransientPtr::new(subjects::Number {
+                    num: resolve_count.get(),
+                })))
+            }
+        });
+
+        assert_eq!(provider.get().unwrap().transient().unwrap().get(), 1);
+        assert_eq!(provider.get().unwrap().transient().unwrap().get(), 2);
+    }
+}
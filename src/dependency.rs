@@ -0,0 +1,122 @@
+//! Static information about the dependencies of injectables.
+use std::any::{type_name, TypeId};
+
+use crate::di_container::BindingOptions;
+
+/// Information about a dependency of a injectable.
+///
+/// Returned from the `dependencies` function of [`Injectable`] & [`AsyncInjectable`]
+/// implementations generated by the [`injectable`] macro, making it possible to
+/// inspect what a injectable depends on without constructing it.
+///
+/// [`Injectable`]: crate::interfaces::injectable::Injectable
+/// [`AsyncInjectable`]: crate::interfaces::async_injectable::AsyncInjectable
+/// [`injectable`]: crate::injectable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyInfo
+{
+    interface_id: TypeId,
+    interface_name: &'static str,
+    binding_options: BindingOptions<'static>,
+    kind: DependencyKind,
+}
+
+impl DependencyInfo
+{
+    /// Returns a new `DependencyInfo` for a dependency on `Interface`.
+    #[must_use]
+    pub fn new<Interface>(
+        kind: DependencyKind,
+        binding_options: BindingOptions<'static>,
+    ) -> Self
+    where
+        Interface: 'static + ?Sized,
+    {
+        Self {
+            interface_id: TypeId::of::<Interface>(),
+            interface_name: type_name::<Interface>(),
+            binding_options,
+            kind,
+        }
+    }
+
+    /// Returns the type ID of the interface that is depended on.
+    #[must_use]
+    pub fn interface_id(&self) -> TypeId
+    {
+        self.interface_id
+    }
+
+    /// Returns the type name of the interface that is depended on.
+    #[must_use]
+    pub fn interface_name(&self) -> &'static str
+    {
+        self.interface_name
+    }
+
+    /// Returns the name of the binding that is depended on, if any.
+    #[must_use]
+    pub fn name(&self) -> Option<&str>
+    {
+        self.binding_options.name.as_deref()
+    }
+
+    /// Returns the options of the binding that is depended on.
+    #[must_use]
+    pub fn binding_options(&self) -> &BindingOptions<'static>
+    {
+        &self.binding_options
+    }
+
+    /// Returns how the dependency is resolved.
+    #[must_use]
+    pub fn kind(&self) -> DependencyKind
+    {
+        self.kind
+    }
+}
+
+/// How a dependency of a injectable is resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum DependencyKind
+{
+    /// Resolved from a single binding which must exist.
+    Single,
+
+    /// Resolved from a single binding if it exists. Is a `Option`.
+    Optional,
+
+    /// Resolved from all bindings without a name or tags. Is a `Vec`.
+    All,
+
+    /// Resolved from all bindings with a name but no tags. Is a `HashMap`.
+    AllNamed,
+
+    /// Resolved from a single binding only when asked for. Is a [`Lazy`] or a
+    /// [`Provider`].
+    ///
+    /// [`Lazy`]: crate::deferred::Lazy
+    /// [`Provider`]: crate::deferred::Provider
+    Deferred,
+
+    /// A weak pointer to the singleton of a single binding. Is a
+    /// [`WeakSingletonPtr`].
+    ///
+    /// [`WeakSingletonPtr`]: crate::ptr::WeakSingletonPtr
+    WeakSingleton,
+}
+
+impl DependencyKind
+{
+    /// Returns whether or not the dependency is constructed together with the
+    /// injectable that depends on it.
+    #[must_use]
+    pub fn is_eager(self) -> bool
+    {
+        matches!(
+            self,
+            Self::Single | Self::Optional | Self::All | Self::AllNamed
+        )
+    }
+}
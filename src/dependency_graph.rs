@@ -0,0 +1,515 @@
+//! Graph of the bindings of a DI container and the dependencies between them.
+use std::fmt::Write;
+
+use crate::dependency::DependencyKind;
+use crate::dependency_history::DependencyHistory;
+use crate::di_container::binding_lookup::BindingLookup;
+use crate::di_container::binding_storage::StoredBinding;
+use crate::di_container::BindingScope;
+use crate::provider::ProvidedInjectable;
+
+/// Graph of the bindings of a DI container and the constructor dependencies between
+/// them.
+///
+/// The nodes & edges are in the order that the bindings were registered in, making
+/// the rendered output stable between runs.
+///
+/// # Examples
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::{injectable, DIContainer};
+/// #
+/// # trait IEngine {}
+/// #
+/// # struct Engine {}
+/// #
+/// # #[injectable(IEngine)]
+/// # impl Engine
+/// # {
+/// #     fn new() -> Self
+/// #     {
+/// #         Self {}
+/// #     }
+/// # }
+/// #
+/// # impl IEngine for Engine {}
+/// #
+/// # struct Car
+/// # {
+/// #     engine: TransientPtr<dyn IEngine>,
+/// # }
+/// #
+/// # #[injectable]
+/// # impl Car
+/// # {
+/// #     fn new(engine: TransientPtr<dyn IEngine>) -> Self
+/// #     {
+/// #         Self { engine }
+/// #     }
+/// # }
+/// #
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut di_container = DIContainer::new();
+///
+/// di_container.bind::<dyn IEngine>().to::<Engine>()?;
+/// di_container.bind::<Car>().to::<Car>()?;
+///
+/// let dependency_graph = di_container.dependency_graph();
+///
+/// assert_eq!(dependency_graph.nodes().len(), 2);
+/// assert_eq!(dependency_graph.edges().len(), 1);
+///
+/// println!("{}", dependency_graph.to_dot());
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph
+{
+    nodes: Vec<DependencyGraphNode>,
+    edges: Vec<DependencyGraphEdge>,
+}
+
+impl DependencyGraph
+{
+    /// Returns the nodes of the graph. There is one node for every binding.
+    #[must_use]
+    pub fn nodes(&self) -> &[DependencyGraphNode]
+    {
+        &self.nodes
+    }
+
+    /// Returns the edges of the graph. There is one edge for every binding that a
+    /// dependency of a injectable is resolved from.
+    #[must_use]
+    pub fn edges(&self) -> &[DependencyGraphEdge]
+    {
+        &self.edges
+    }
+
+    /// Renders the graph in the Graphviz DOT language.
+    #[must_use]
+    pub fn to_dot(&self) -> String
+    {
+        let mut dot = String::from("digraph dependencies {\n    node [shape=box];\n");
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut label = escape_dot(node.interface);
+
+            if let Some(name) = &node.name {
+                let _ = write!(label, "\\nnamed \\\"{}\\\"", escape_dot(name));
+            }
+
+            if let Some(key) = &node.key {
+                let _ = write!(label, "\\nkeyed {}", escape_dot(key));
+            }
+
+            if let Some(implementation) = node.implementation {
+                if implementation != node.interface {
+                    let _ = write!(label, "\\n{}", escape_dot(implementation));
+                }
+            }
+
+            let _ = writeln!(dot, "    n{index} [label=\"{label}\\n({})\"];", node.scope);
+        }
+
+        for edge in &self.edges {
+            let _ = write!(dot, "    n{} -> n{}", edge.from, edge.to);
+
+            match edge.kind {
+                DependencyKind::Single => {}
+                DependencyKind::Deferred | DependencyKind::WeakSingleton => {
+                    let _ = write!(dot, " [label=\"{}\", style=dashed]", edge.kind);
+                }
+                kind => {
+                    let _ = write!(dot, " [label=\"{kind}\"]");
+                }
+            }
+
+            dot.push_str(";\n");
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Renders the graph as JSON.
+    ///
+    /// The JSON is a object with a `nodes` array & a `edges` array. Edges refer to nodes
+    /// by their index in the `nodes` array.
+    #[must_use]
+    pub fn to_json(&self) -> String
+    {
+        let mut json = String::from("{\n  \"nodes\": [");
+
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index != 0 {
+                json.push(',');
+            }
+
+            let _ = write!(
+                json,
+                concat!(
+                    "\n    {{",
+                    "\n      \"interface\": {},",
+                    "\n      \"implementation\": {},",
+                    "\n      \"name\": {},",
+                    "\n      \"key\": {},",
+                    "\n      \"scope\": {}",
+                    "\n    }}"
+                ),
+                json_string(node.interface),
+                node.implementation
+                    .map_or_else(|| "null".to_string(), json_string),
+                node.name
+                    .as_deref()
+                    .map_or_else(|| "null".to_string(), json_string),
+                node.key
+                    .as_deref()
+                    .map_or_else(|| "null".to_string(), json_string),
+                json_string(&node.scope.to_string())
+            );
+        }
+
+        if !self.nodes.is_empty() {
+            json.push_str("\n  ");
+        }
+
+        json.push_str("],\n  \"edges\": [");
+
+        for (index, edge) in self.edges.iter().enumerate() {
+            if index != 0 {
+                json.push(',');
+            }
+
+            let _ = write!(
+                json,
+                concat!(
+                    "\n    {{",
+                    "\n      \"from\": {},",
+                    "\n      \"to\": {},",
+                    "\n      \"kind\": {}",
+                    "\n    }}"
+                ),
+                edge.from,
+                edge.to,
+                json_string(&edge.kind.to_string())
+            );
+        }
+
+        if !self.edges.is_empty() {
+            json.push_str("\n  ");
+        }
+
+        json.push_str("]\n}\n");
+
+        json
+    }
+
+    /// Builds the graph of the bindings found by a [`BindingLookup`].
+    ///
+    /// The dependencies of a injectable are looked up as if the injectable was resolved
+    /// directly, meaning that bindings only available further down in a chain of
+    /// dependencies are not included.
+    pub(crate) fn new<Provider>(
+        lookup: &BindingLookup<'_, Provider>,
+        get_injectable: fn(&Provider) -> Option<ProvidedInjectable>,
+        get_scope: fn(&Provider) -> BindingScope,
+    ) -> Self
+    where
+        Provider: 'static + ?Sized,
+    {
+        let bindings = lookup.iter().collect::<Vec<_>>();
+
+        let nodes = bindings
+            .iter()
+            .map(|binding| DependencyGraphNode {
+                interface: binding.interface.name,
+                implementation: get_injectable(binding.provider)
+                    .map(|injectable| injectable.type_name),
+                name: binding.options.name.as_deref().map(ToString::to_string),
+                key: binding.options.key.as_ref().map(ToString::to_string),
+                scope: get_scope(binding.provider),
+            })
+            .collect();
+
+        let find_node = |dependency_binding: StoredBinding<'_, Provider>| {
+            bindings.iter().position(|binding| {
+                address_of(binding.provider) == address_of(dependency_binding.provider)
+            })
+        };
+
+        let mut edges = Vec::new();
+
+        for (index, binding) in bindings.iter().enumerate() {
+            let injectable = match get_injectable(binding.provider) {
+                Some(injectable) => injectable,
+                None => continue,
+            };
+
+            let mut dependency_history = DependencyHistory::new();
+
+            dependency_history.set_next_binding_name(binding.options.name.clone());
+            dependency_history.push_type_name(injectable.type_name);
+
+            for dependency in &injectable.dependencies {
+                let dependency_bindings = if matches!(
+                    dependency.kind(),
+                    DependencyKind::All | DependencyKind::AllNamed
+                ) {
+                    lookup.find_all(dependency, &dependency_history)
+                } else {
+                    lookup.find(dependency, &dependency_history)
+                };
+
+                edges.extend(dependency_bindings.into_iter().filter_map(find_node).map(
+                    |to| DependencyGraphEdge {
+                        from: index,
+                        to,
+                        kind: dependency.kind(),
+                    },
+                ));
+            }
+        }
+
+        Self { nodes, edges }
+    }
+}
+
+/// A binding in a [`DependencyGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraphNode
+{
+    interface: &'static str,
+    implementation: Option<&'static str>,
+    name: Option<String>,
+    key: Option<String>,
+    scope: BindingScope,
+}
+
+impl DependencyGraphNode
+{
+    /// Returns the type name of the interface of the binding.
+    #[must_use]
+    pub fn interface(&self) -> &'static str
+    {
+        self.interface
+    }
+
+    /// Returns the type name of the implementation of the binding. Is `None` for
+    /// bindings to factories & dynamic values.
+    #[must_use]
+    pub fn implementation(&self) -> Option<&'static str>
+    {
+        self.implementation
+    }
+
+    /// Returns the name of the binding.
+    #[must_use]
+    pub fn name(&self) -> Option<&str>
+    {
+        self.name.as_deref()
+    }
+
+    /// Returns the binding key of the binding, formatted with it's [`Debug`]
+    /// implementation.
+    #[must_use]
+    pub fn key(&self) -> Option<&str>
+    {
+        self.key.as_deref()
+    }
+
+    /// Returns the scope of the binding.
+    #[must_use]
+    pub fn scope(&self) -> BindingScope
+    {
+        self.scope
+    }
+}
+
+/// A constructor dependency in a [`DependencyGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DependencyGraphEdge
+{
+    from: usize,
+    to: usize,
+    kind: DependencyKind,
+}
+
+impl DependencyGraphEdge
+{
+    /// Returns the index of the node of the binding with the dependency.
+    #[must_use]
+    pub fn from(&self) -> usize
+    {
+        self.from
+    }
+
+    /// Returns the index of the node of the binding that the dependency is resolved
+    /// from.
+    #[must_use]
+    pub fn to(&self) -> usize
+    {
+        self.to
+    }
+
+    /// Returns how the dependency is resolved.
+    #[must_use]
+    pub fn kind(&self) -> DependencyKind
+    {
+        self.kind
+    }
+}
+
+fn address_of<Provider>(provider: &Provider) -> *const ()
+where
+    Provider: ?Sized,
+{
+    (provider as *const Provider).cast()
+}
+
+fn escape_dot(text: &str) -> String
+{
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn json_string(text: &str) -> String
+{
+    let mut json_string = String::with_capacity(text.len() + 2);
+
+    json_string.push('"');
+
+    for character in text.chars() {
+        match character {
+            '"' => json_string.push_str("\\\""),
+            '\\' => json_string.push_str("\\\\"),
+            '\n' => json_string.push_str("\\n"),
+            character if character.is_control() => {
+                let _ = write!(json_string, "\\u{:04x}", u32::from(character));
+            }
+            character => json_string.push(character),
+        }
+    }
+
+    json_string.push('"');
+
+    json_string
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn create_graph() -> DependencyGraph
+    {
+        DependencyGraph {
+            nodes: vec![
+                DependencyGraphNode {
+                    interface: "dyn IWeapon",
+                    implementation: Some("Katana"),
+                    name: Some("sharp".to_string()),
+                    key: None,
+                    scope: BindingScope::Singleton,
+                },
+                DependencyGraphNode {
+                    interface: "Ninja",
+                    implementation: Some("Ninja"),
+                    name: None,
+                    key: Some("Shadow".to_string()),
+                    scope: BindingScope::Transient,
+                },
+            ],
+            edges: vec![
+                DependencyGraphEdge {
+                    from: 1,
+                    to: 0,
+                    kind: DependencyKind::Single,
+                },
+                DependencyGraphEdge {
+                    from: 1,
+                    to: 0,
+                    kind: DependencyKind::Deferred,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn can_render_dot()
+    {
+        assert_eq!(
+            create_graph().to_dot(),
+            concat!(
+                "digraph dependencies {\n",
+                "    node [shape=box];\n",
+                "    n0 [label=\"dyn IWeapon\\nnamed \\\"sharp\\\"\\nKatana",
+                "\\n(singleton)\"];\n",
+                "    n1 [label=\"Ninja\\nkeyed Shadow\\n(transient)\"];\n",
+                "    n1 -> n0;\n",
+                "    n1 -> n0 [label=\"deferred\", style=dashed];\n",
+                "}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn can_render_json()
+    {
+        assert_eq!(
+            create_graph().to_json(),
+            r#"{
+  "nodes": [
+    {
+      "interface": "dyn IWeapon",
+      "implementation": "Katana",
+      "name": "sharp",
+      "key": null,
+      "scope": "singleton"
+    },
+    {
+      "interface": "Ninja",
+      "implementation": "Ninja",
+      "name": null,
+      "key": "Shadow",
+      "scope": "transient"
+    }
+  ],
+  "edges": [
+    {
+      "from": 1,
+      "to": 0,
+      "kind": "single"
+    },
+    {
+      "from": 1,
+      "to": 0,
+      "kind": "deferred"
+    }
+  ]
+}
+"#
+        );
+    }
+
+    #[test]
+    fn can_render_empty_json()
+    {
+        assert_eq!(
+            DependencyGraph {
+                nodes: vec![],
+                edges: vec![]
+            }
+            .to_json(),
+            "{\n  \"nodes\": [],\n  \"edges\": []\n}\n"
+        );
+    }
+
+    #[test]
+    fn json_string_escapes()
+    {
+        assert_eq!(json_string("a \"b\"\\\n\t"), r#""a \"b\"\\\n\u0009""#);
+    }
+}
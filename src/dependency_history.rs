@@ -1,9 +1,15 @@
 //! Dependency history.
 
 use std::any::type_name;
+use std::borrow::Cow;
 use std::collections::HashSet;
 use std::fmt::{Debug, Display};
 
+use crate::di_container::resolution_context::{BindingCondition, ResolutionContext};
+use crate::di_container::BindingOptions;
+#[cfg(feature = "async")]
+use crate::errors::injectable::InjectableError;
+
 const BOLD_MODE: &str = "\x1b[1m";
 const RESET_BOLD_MODE: &str = "\x1b[22m";
 
@@ -11,7 +17,8 @@ const RESET_BOLD_MODE: &str = "\x1b[22m";
 ///
 /// Used by the implementations of [`Injectable`] and [`AsyncInjectable`] generated by
 /// the [`injectable`] macro to prevent circular dependencies when the `prevent-circular`
-/// crate feature is enabled.
+/// crate feature is enabled. It is also what contextual binding constraints are
+/// evaluated against.
 ///
 /// [`Injectable`]: crate::interfaces::injectable::Injectable
 /// [`AsyncInjectable`]: crate::interfaces::async_injectable::AsyncInjectable
@@ -20,6 +27,12 @@ const RESET_BOLD_MODE: &str = "\x1b[22m";
 pub struct DependencyHistory
 {
     inner: Vec<&'static str>,
+    binding_names: Vec<Option<Cow<'static, str>>>,
+    next_binding_name: Option<Cow<'static, str>>,
+
+    /// The number of dependencies at the start of the history that had already been
+    /// resolved when the rest of the history was. They aren't circular dependencies.
+    resolved_len: usize,
 }
 
 impl DependencyHistory
@@ -28,7 +41,81 @@ impl DependencyHistory
     #[must_use]
     pub const fn new() -> Self
     {
-        Self { inner: Vec::new() }
+        Self {
+            inner: Vec::new(),
+            binding_names: Vec::new(),
+            next_binding_name: None,
+            resolved_len: 0,
+        }
+    }
+
+    /// Turns the history into one for resolving a dependency after the dependencies in
+    /// the history have been resolved, like a deferred dependency is. The dependencies
+    /// are still seen by binding conditions, but depending on them again isn't a
+    /// circular dependency.
+    pub(crate) fn into_resolved(mut self) -> Self
+    {
+        self.resolved_len = self.inner.len();
+
+        self
+    }
+
+    /// Sets the name of the binding that the next dependency pushed to the history is
+    /// resolved from.
+    pub(crate) fn set_next_binding_name(&mut self, name: Option<Cow<'static, str>>)
+    {
+        self.next_binding_name = name;
+    }
+
+    /// Adds a dependency with the specified type name to the history.
+    pub(crate) fn push_type_name(&mut self, type_name: &'static str)
+    {
+        self.inner.push(type_name);
+        self.binding_names.push(self.next_binding_name.take());
+    }
+
+    /// Returns the type names of the dependencies in the history.
+    pub(crate) fn as_slice(&self) -> &[&'static str]
+    {
+        &self.inner
+    }
+
+    /// Returns the type name of the latest dependency in the history.
+    pub(crate) fn last(&self) -> Option<&'static str>
+    {
+        self.inner.last().copied()
+    }
+
+    /// Returns the name of the binding the latest dependency in the history was
+    /// resolved from.
+    pub(crate) fn last_binding_name(&self) -> Option<&str>
+    {
+        self.binding_names.last().and_then(Option::as_deref)
+    }
+
+    /// Returns whether or not the condition of a binding is satisfied by the history
+    /// when requested with the given binding options.
+    pub(crate) fn satisfies(
+        &self,
+        condition: &BindingCondition,
+        request: &BindingOptions,
+    ) -> bool
+    {
+        condition(&ResolutionContext::new(self, request))
+    }
+
+    /// Returns the error for `Dependency` being a circular dependency of the
+    /// dependencies in the history.
+    #[cfg(all(feature = "async", not(test)))]
+    pub(crate) fn into_circular_error<Dependency: 'static + ?Sized>(
+        mut self,
+    ) -> InjectableError
+    {
+        self.push::<Dependency>();
+
+        InjectableError::DetectedCircular {
+            dependency_history: self,
+        }
     }
 }
 
@@ -38,14 +125,58 @@ impl DependencyHistory
     /// Adds a dependency to the history.
     pub fn push<Dependency: 'static + ?Sized>(&mut self)
     {
-        self.inner.push(type_name::<Dependency>());
+        self.push_type_name(type_name::<Dependency>());
     }
 
     /// Returns whether or not a dependency is present in the history.
     #[allow(clippy::must_use_candidate)]
     pub fn contains<Dependency: 'static + ?Sized>(&self) -> bool
     {
-        self.inner.contains(&type_name::<Dependency>())
+        self.inner[self.resolved_len..].contains(&type_name::<Dependency>())
+    }
+}
+
+#[cfg(test)]
+impl MockDependencyHistory
+{
+    #[allow(clippy::unused_self)]
+    pub(crate) fn set_next_binding_name(&mut self, _name: Option<Cow<'static, str>>) {}
+
+    pub(crate) fn into_resolved(self) -> Self
+    {
+        self
+    }
+
+    /// Evaluates the condition against an empty history.
+    #[allow(clippy::unused_self)]
+    pub(crate) fn satisfies(
+        &self,
+        condition: &BindingCondition,
+        request: &BindingOptions,
+    ) -> bool
+    {
+        condition(&ResolutionContext::new(&DependencyHistory::new(), request))
+    }
+
+    /// Returns the error with an empty history.
+    #[cfg(feature = "async")]
+    #[allow(clippy::unused_self, clippy::extra_unused_type_parameters)]
+    pub(crate) fn into_circular_error<Dependency: 'static + ?Sized>(
+        self,
+    ) -> InjectableError
+    {
+        InjectableError::DetectedCircular {
+            dependency_history: DependencyHistory::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Clone for MockDependencyHistory
+{
+    fn clone(&self) -> Self
+    {
+        Self::new()
     }
 }
 
@@ -97,6 +228,8 @@ impl Display for DependencyHistory
 #[cfg(test)]
 mod tests
 {
+    use std::sync::Arc;
+
     use super::*;
     use crate::test_utils::subjects;
 
@@ -126,6 +259,44 @@ mod tests
         assert!(!dependency_history.contains::<dyn subjects::INumber>());
     }
 
+    #[test]
+    fn resolved_dependencies_are_not_contained()
+    {
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.push::<dyn subjects::IUserManager>();
+
+        let mut resolved_history = dependency_history.into_resolved();
+
+        assert!(!resolved_history.contains::<dyn subjects::IUserManager>());
+        assert_eq!(
+            resolved_history.last(),
+            Some(type_name::<dyn subjects::IUserManager>())
+        );
+
+        resolved_history.push::<dyn subjects::IUserManager>();
+
+        assert!(resolved_history.contains::<dyn subjects::IUserManager>());
+    }
+
+    #[test]
+    fn satisfies_works()
+    {
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.set_next_binding_name(Some("foo".into()));
+        dependency_history.push::<dyn subjects::IUserManager>();
+
+        let condition: BindingCondition =
+            Arc::new(|context| context.parent_name() == Some("foo"));
+
+        assert!(dependency_history.satisfies(&condition, &BindingOptions::new()));
+
+        dependency_history.push::<dyn subjects::INumber>();
+
+        assert!(!dependency_history.satisfies(&condition, &BindingOptions::new()));
+    }
+
     #[test]
     fn display_works()
     {
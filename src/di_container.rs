@@ -1,10 +1,17 @@
 //! Dependency injection container types.
 
+use std::any::{type_name, TypeId};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 #[cfg(feature = "async")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
 pub mod asynchronous;
 
 pub mod blocking;
+pub mod resolution_context;
+pub mod threadsafe;
 
 /// DI container binding options.
 ///
@@ -13,11 +20,18 @@ pub mod blocking;
 /// # use syrette::di_container::BindingOptions;
 /// #
 /// BindingOptions::new().name("foo");
+///
+/// BindingOptions::new().name(String::from("bar"));
+///
+/// BindingOptions::new().tag("region", "eu").tag("access", "read_only");
 /// ```
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct BindingOptions<'a>
 {
-    name: Option<&'a str>,
+    pub(crate) name: Option<Cow<'a, str>>,
+    pub(crate) key: Option<BindingKeyId>,
+    pub(crate) tags: Vec<(&'a str, &'a str)>,
+    additional_id: Option<usize>,
 }
 
 impl<'a> BindingOptions<'a>
@@ -26,18 +40,299 @@ impl<'a> BindingOptions<'a>
     #[must_use]
     pub const fn new() -> Self
     {
-        Self { name: None }
+        Self {
+            name: None,
+            key: None,
+            tags: Vec::new(),
+            additional_id: None,
+        }
+    }
+
+    /// Returns `Self` with the specified name set. The name can be either borrowed or
+    /// owned.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<Cow<'a, str>>) -> Self
+    {
+        self.name = Some(name.into());
+
+        self
+    }
+
+    /// Returns `Self` with the specified binding key set.
+    #[must_use]
+    pub fn keyed<Key>(mut self) -> Self
+    where
+        Key: BindingKey,
+    {
+        self.key = Some(BindingKeyId::of::<Key>());
+
+        self
     }
 
-    /// Returns `Self` with the specified name set.
+    /// Returns `Self` with the specified tag set. A tag with the same key that is
+    /// already set is replaced.
     #[must_use]
-    pub const fn name(mut self, name: &'a str) -> Self
+    pub fn tag(mut self, key: &'a str, value: &'a str) -> Self
     {
-        self.name = Some(name);
+        // The tags are kept sorted so that the order they are set in doesn't matter
+        match self
+            .tags
+            .binary_search_by(|(tag_key, _)| (*tag_key).cmp(key))
+        {
+            Ok(index) => self.tags[index] = (key, value),
+            Err(index) => self.tags.insert(index, (key, value)),
+        }
 
         self
     }
+
+    /// Returns `Self` for a binding added with `to_additional`. Each call returns
+    /// options distinct from all others so that such bindings never replace each other.
+    pub(crate) fn additional(mut self) -> Self
+    {
+        static NEXT_ADDITIONAL_ID: AtomicUsize = AtomicUsize::new(0);
+
+        self.additional_id = Some(NEXT_ADDITIONAL_ID.fetch_add(1, Ordering::Relaxed));
+
+        self
+    }
+
+    /// Returns whether or not a binding with these options can be used to resolve a
+    /// request with the specified options. The binding must have the same name & key
+    /// and, if the request has tags, at least the tags of the request. A request without
+    /// tags only matches bindings without tags.
+    pub(crate) fn matches(&self, request: &BindingOptions) -> bool
+    {
+        if self.name != request.name || self.key != request.key {
+            return false;
+        }
+
+        if request.tags.is_empty() {
+            return self.tags.is_empty();
+        }
+
+        request
+            .tags
+            .iter()
+            .all(|request_tag| self.tags.contains(request_tag))
+    }
+
+    /// Returns whether or not the options are for a binding that is neither named,
+    /// keyed nor tagged.
+    pub(crate) fn is_unqualified(&self) -> bool
+    {
+        self.name.is_none() && self.key.is_none() && self.tags.is_empty()
+    }
+}
+
+/// Key of a binding. Used instead of a string name so that a typo in a key is caught at
+/// compile time.
+///
+/// A key is a type, usually a unit struct. Keys are told apart by their type, so a key
+/// never matches a name or another key.
+///
+/// # Examples
+/// ```
+/// # use syrette::di_container::{BindingKey, BindingOptions};
+/// #
+/// struct Primary;
+///
+/// impl BindingKey for Primary {}
+///
+/// struct Replica;
+///
+/// impl BindingKey for Replica {}
+///
+/// assert_ne!(
+///     BindingOptions::new().keyed::<Primary>(),
+///     BindingOptions::new().keyed::<Replica>()
+/// );
+///
+/// assert_ne!(
+///     BindingOptions::new().keyed::<Primary>(),
+///     BindingOptions::new().name("Primary")
+/// );
+/// ```
+pub trait BindingKey: 'static {}
+
+/// Identifies the type of a [`BindingKey`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct BindingKeyId
+{
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+impl BindingKeyId
+{
+    fn of<Key>() -> Self
+    where
+        Key: BindingKey,
+    {
+        Self {
+            type_id: TypeId::of::<Key>(),
+            type_name: type_name::<Key>(),
+        }
+    }
+}
+
+impl Display for BindingKeyId
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        formatter.write_str(self.type_name)
+    }
+}
+
+/// How the instances of a binding are created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum BindingScope
+{
+    /// A new instance is created every time.
+    Transient,
+
+    /// The same instance is always used.
+    Singleton,
+
+    /// The same instance is always used. It is created when it's first needed.
+    LazySingleton,
+
+    /// One instance is used per scope.
+    Scoped,
+
+    /// A factory is provided.
+    #[cfg(feature = "factory")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+    Factory,
+
+    /// A value is created by a function every time.
+    DynamicValue,
+
+    /// A async factory is provided.
+    #[cfg(all(feature = "async", feature = "factory"))]
+    #[cfg_attr(doc_cfg, doc(cfg(all(feature = "async", feature = "factory"))))]
+    AsyncFactory,
+
+    /// A value is created by a async function every time.
+    #[cfg(feature = "async")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
+    AsyncDynamicValue,
+}
+
+impl BindingScope
+{
+    /// Returns whether or not the instances of a binding in the scope are shared
+    /// between requests.
+    pub(crate) fn is_shared(self) -> bool
+    {
+        matches!(self, Self::Singleton | Self::LazySingleton | Self::Scoped)
+    }
+}
+
+/// Information about a binding of a DI container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingInfo
+{
+    interface: &'static str,
+    options: BindingOptions<'static>,
+    implementation: Option<&'static str>,
+    scope: BindingScope,
+    is_singleton_built: bool,
+}
+
+impl BindingInfo
+{
+    /// Returns the type name of the interface of the binding.
+    #[must_use]
+    pub fn interface(&self) -> &'static str
+    {
+        self.interface
+    }
+
+    /// Returns the name of the binding.
+    #[must_use]
+    pub fn name(&self) -> Option<&str>
+    {
+        self.options.name.as_deref()
+    }
+
+    /// Returns the options of the binding.
+    #[must_use]
+    pub fn options(&self) -> &BindingOptions<'static>
+    {
+        &self.options
+    }
+
+    /// Returns the type name of the implementation of the binding. Is `None` for
+    /// bindings to factories & dynamic values.
+    #[must_use]
+    pub fn implementation(&self) -> Option<&'static str>
+    {
+        self.implementation
+    }
+
+    /// Returns the scope of the binding.
+    #[must_use]
+    pub fn scope(&self) -> BindingScope
+    {
+        self.scope
+    }
+
+    /// Returns whether or not the binding is to a singleton that has been built.
+    #[must_use]
+    pub fn is_singleton_built(&self) -> bool
+    {
+        self.is_singleton_built
+    }
 }
 
 // Private.
+pub(crate) mod binding_lookup;
 pub(crate) mod binding_storage;
+pub(crate) mod validation;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn tags_are_order_independent()
+    {
+        assert_eq!(
+            BindingOptions::new().tag("region", "eu").tag("tier", "primary"),
+            BindingOptions::new().tag("tier", "primary").tag("region", "eu")
+        );
+
+        assert_eq!(
+            BindingOptions::new().tag("region", "us").tag("region", "eu"),
+            BindingOptions::new().tag("region", "eu")
+        );
+    }
+
+    #[test]
+    fn matches_works()
+    {
+        let binding_options = BindingOptions::new()
+            .tag("region", "eu")
+            .tag("tier", "primary");
+
+        assert!(binding_options.matches(&BindingOptions::new().tag("region", "eu")));
+
+        assert!(binding_options.matches(
+            &BindingOptions::new()
+                .tag("tier", "primary")
+                .tag("region", "eu")
+        ));
+
+        assert!(!binding_options.matches(&BindingOptions::new().tag("region", "us")));
+        assert!(!binding_options.matches(&BindingOptions::new()));
+
+        assert!(!binding_options.matches(
+            &BindingOptions::new().name("foo").tag("region", "eu")
+        ));
+
+        assert!(BindingOptions::new().matches(&BindingOptions::new()));
+    }
+}
@@ -50,29 +50,51 @@
 //! }
 //! ```
 use std::any::type_name;
+use std::collections::HashMap;
+use std::iter::successors;
+use std::sync::Arc;
 
 use crate::castable_function::threadsafe::ThreadsafeCastableFunction;
+use crate::dependency::{DependencyInfo, DependencyKind};
+use crate::dependency_graph::DependencyGraph;
 use crate::di_container::asynchronous::binding::builder::AsyncBindingBuilder;
+use crate::di_container::binding_lookup::BindingLookup;
 use crate::di_container::binding_storage::DIContainerBindingStorage;
-use crate::di_container::BindingOptions;
+use crate::di_container::resolution_context::BindingCondition;
+use crate::di_container::validation::Validator;
+use crate::di_container::asynchronous::frozen::AsyncDIContainerBuilder;
+use crate::di_container::{BindingInfo, BindingKey, BindingOptions, BindingScope};
 use crate::errors::async_di_container::AsyncDIContainerError;
+use crate::errors::build::BuildError;
+use crate::errors::lifecycle::{LifecycleError, ServiceFailure};
+use crate::errors::validation::ValidationError;
+use crate::explanation::{Explainer, Explanation};
 use crate::future::BoxFuture;
 use crate::private::cast::arc::CastArc;
 use crate::private::cast::boxed::CastBox;
 use crate::private::cast::error::CastError;
 use crate::provider::r#async::{AsyncProvidable, IAsyncProvider, ProvidableFunctionKind};
-use crate::ptr::{SomePtr, TransientPtr};
+use crate::provider::HeldSingleton;
+use crate::ptr::{SomePtr, ThreadsafeSingletonPtr, TransientPtr};
 use crate::util::use_double;
 
 use_double!(crate::dependency_history::DependencyHistory);
 
 pub mod binding;
+pub mod frozen;
+
+/// The options & provider of a binding.
+type FoundBinding<'di_container> = (
+    &'di_container BindingOptions<'static>,
+    Box<dyn IAsyncProvider<AsyncDIContainer>>,
+);
 
 /// Async dependency injection container.
 #[derive(Default)]
 pub struct AsyncDIContainer
 {
     binding_storage: DIContainerBindingStorage<dyn IAsyncProvider<Self>>,
+    parent: Option<Arc<AsyncDIContainer>>,
 }
 
 impl AsyncDIContainer
@@ -83,8 +105,500 @@ impl AsyncDIContainer
     {
         Self {
             binding_storage: DIContainerBindingStorage::new(),
+            parent: None,
         }
     }
+
+    /// Returns a new [`AsyncDIContainerBuilder`] for building a
+    /// [`FrozenAsyncDIContainer`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct Clock {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Clock
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container_builder = AsyncDIContainer::builder();
+    ///
+    /// di_container_builder.bind::<Clock>().to::<Clock>()?;
+    ///
+    /// let di_container = di_container_builder.build().await?;
+    ///
+    /// let clock = di_container.get::<Clock>().await?.transient()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`FrozenAsyncDIContainer`]: crate::di_container::asynchronous::frozen::FrozenAsyncDIContainer
+    #[must_use]
+    pub fn builder() -> AsyncDIContainerBuilder
+    {
+        AsyncDIContainerBuilder::new()
+    }
+
+    /// Returns a new child `AsyncDIContainer` of this `AsyncDIContainer`.
+    ///
+    /// Bindings are first looked up in the child. If the child doesn't have a matching
+    /// binding, the lookup falls back to the parent and then to the parent's ancestors.
+    /// Bindings created in the child are never visible to the parent and singletons
+    /// bound in the parent are shared with the child.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::Arc;
+    /// #
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait ILogger: Send + Sync {}
+    /// #
+    /// # struct StdoutLogger {}
+    /// #
+    /// # #[injectable(ILogger, async = true)]
+    /// # impl StdoutLogger
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl ILogger for StdoutLogger {}
+    /// #
+    /// # struct FileLogger {}
+    /// #
+    /// # #[injectable(ILogger, async = true)]
+    /// # impl FileLogger
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl ILogger for FileLogger {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.bind::<dyn ILogger>().to::<StdoutLogger>()?;
+    ///
+    /// let di_container = Arc::new(di_container);
+    ///
+    /// let mut child_di_container = di_container.create_child();
+    ///
+    /// child_di_container.bind::<dyn ILogger>().to::<FileLogger>()?;
+    ///
+    /// // Resolved using the binding in the child
+    /// let logger = child_di_container
+    ///     .get::<dyn ILogger>()
+    ///     .await?
+    ///     .transient()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn create_child(self: &Arc<Self>) -> Self
+    {
+        Self {
+            binding_storage: DIContainerBindingStorage::new(),
+            parent: Some(self.clone()),
+        }
+    }
+
+    /// Validates that every binding of this `AsyncDIContainer` and of it's ancestors can
+    /// be resolved, without constructing anything.
+    ///
+    /// # Errors
+    /// Will return `Err` containing every:
+    /// - Dependency without a binding or with more than one matching binding
+    /// - Chain of injectables depending on each other
+    /// - Binding with a implementation that can't be cast to the interface
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// # use syrette::errors::validation::ValidationProblem;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IEngine: Send + Sync {}
+    /// #
+    /// # struct Car
+    /// # {
+    /// #     engine: TransientPtr<dyn IEngine>,
+    /// # }
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Car
+    /// # {
+    /// #     fn new(engine: TransientPtr<dyn IEngine>) -> Self
+    /// #     {
+    /// #         Self { engine }
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.bind::<Car>().to::<Car>()?;
+    ///
+    /// let err = di_container.validate().unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     err.problems(),
+    ///     [ValidationProblem::MissingBinding { .. }]
+    /// ));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError>
+    {
+        Validator::new(self.binding_lookup(), IAsyncProvider::injectable).validate()
+    }
+
+    /// Returns information about the bindings of the DI container, in the order they
+    /// were added. The bindings of ancestors are not included.
+    pub fn bindings(&self) -> impl Iterator<Item = BindingInfo> + '_
+    {
+        self.binding_storage.iter().map(|binding| BindingInfo {
+            interface: binding.interface.name,
+            options: binding.options.clone(),
+            implementation: binding
+                .provider
+                .injectable()
+                .map(|injectable| injectable.type_name),
+            scope: binding.provider.scope(),
+            is_singleton_built: binding.provider.is_singleton_built(),
+        })
+    }
+
+    /// Returns a graph of the bindings of the DI container & of it's ancestors and of
+    /// the constructor dependencies between them.
+    ///
+    /// See [`DependencyGraph`] for a example.
+    #[must_use]
+    pub fn dependency_graph(&self) -> DependencyGraph
+    {
+        DependencyGraph::new(
+            &self.binding_lookup(),
+            IAsyncProvider::injectable,
+            IAsyncProvider::scope,
+        )
+    }
+
+    /// Returns a explanation of how the DI container would resolve `Interface` and
+    /// every transitive dependency of it, without constructing anything.
+    ///
+    /// See [`Explanation`] for a example.
+    #[must_use]
+    pub fn explain<Interface>(&self) -> Explanation
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.explain_bound::<Interface>(BindingOptions::new())
+    }
+
+    /// Returns a explanation of how the DI container would resolve `Interface` with
+    /// name `name` and every transitive dependency of it, without constructing
+    /// anything.
+    #[must_use]
+    pub fn explain_named<Interface>(&self, name: &str) -> Explanation
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.explain_bound::<Interface>(BindingOptions::new().name(name.to_string()))
+    }
+
+    /// Returns the type bound with `Interface` and the specified binding key.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with the key exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::BindingKey;
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct DeviceManager {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl DeviceManager
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// struct Usb;
+    ///
+    /// impl BindingKey for Usb {}
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<DeviceManager>()
+    ///     .to::<DeviceManager>()?
+    ///     .in_transient_scope()
+    ///     .when_keyed::<Usb>()?;
+    ///
+    /// let device_manager = di_container
+    ///     .get_keyed::<DeviceManager, Usb>()
+    ///     .await?
+    ///     .transient();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_keyed<Interface, Key>(
+        &self,
+    ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+        Key: BindingKey,
+    {
+        self.get_bound_by_ref::<Interface>(
+            DependencyHistory::new(),
+            &BindingOptions::new().keyed::<Key>(),
+        )
+        .await
+    }
+
+    /// Starts the startable singletons of the `AsyncDIContainer` in the order they were
+    /// constructed in, meaning that every singleton is started after the singletons it
+    /// depends on. Lazy singletons that haven't been built yet are built first.
+    ///
+    /// Starting stops at the first service that fails, since the services depending on
+    /// it would likely fail as well.
+    ///
+    /// Only the bindings of this `AsyncDIContainer` are started, not those of it's
+    /// parent.
+    ///
+    /// # Errors
+    /// Will return `Err` if a service fails to be resolved or started. The error names
+    /// the service.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use async_trait::async_trait;
+    /// # use syrette::interfaces::async_lifecycle::{AsyncStartable, AsyncStoppable};
+    /// # use syrette::{injectable, AsyncDIContainer};
+    /// #
+    /// # struct MessageQueue {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl MessageQueue
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// #[async_trait]
+    /// impl AsyncStartable for MessageQueue
+    /// {
+    ///     async fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    ///     {
+    ///         println!("Connecting to message queue");
+    ///
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// #[async_trait]
+    /// impl AsyncStoppable for MessageQueue
+    /// {
+    ///     async fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    ///     {
+    ///         println!("Disconnecting from message queue");
+    ///
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<MessageQueue>()
+    ///     .to::<MessageQueue>()?
+    ///     .startable()
+    ///     .stoppable()
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// di_container.start_all().await?;
+    ///
+    /// di_container.stop_all().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn start_all(&self) -> Result<(), LifecycleError>
+    {
+        for binding in self.binding_storage.iter() {
+            if !binding.provider.is_startable() || binding.provider.is_singleton_built() {
+                continue;
+            }
+
+            if let Err(reason) = binding
+                .provider
+                .provide(self, DependencyHistory::new())
+                .await
+            {
+                return Err(LifecycleError::new(vec![ServiceFailure::ResolveFailed {
+                    reason,
+                    service: binding
+                        .provider
+                        .injectable()
+                        .map_or(binding.interface.name, |injectable| {
+                            injectable.type_name
+                        }),
+                }]));
+            }
+        }
+
+        for (provider, held_singleton) in self.held_singletons() {
+            if let Some(Err(reason)) = provider.start().await {
+                return Err(LifecycleError::new(vec![ServiceFailure::StartFailed {
+                    reason,
+                    service: held_singleton.type_name,
+                }]));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stops the stoppable singletons of the `AsyncDIContainer` in the reverse order of
+    /// them being constructed, meaning that every singleton is stopped before the
+    /// singletons it depends on. Lazy singletons that haven't been built are not
+    /// stopped.
+    ///
+    /// Stopping continues past services that fail.
+    ///
+    /// Only the bindings of this `AsyncDIContainer` are stopped, not those of it's
+    /// parent.
+    ///
+    /// See [`start_all`](Self::start_all) for a example.
+    ///
+    /// # Errors
+    /// Will return `Err` if one or more services fail to stop. The error names every
+    /// service that failed.
+    pub async fn stop_all(&self) -> Result<(), LifecycleError>
+    {
+        let mut failures = Vec::new();
+
+        for (provider, held_singleton) in self.held_singletons().into_iter().rev() {
+            if let Some(Err(reason)) = provider.stop().await {
+                failures.push(ServiceFailure::StopFailed {
+                    reason,
+                    service: held_singleton.type_name,
+                });
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(LifecycleError::new(failures));
+        }
+
+        Ok(())
+    }
+
+    fn explain_bound<Interface>(&self, binding_options: BindingOptions<'static>)
+        -> Explanation
+    where
+        Interface: 'static + ?Sized,
+    {
+        let explainer = Explainer::new(
+            self.binding_lookup(),
+            IAsyncProvider::injectable,
+            IAsyncProvider::scope,
+        );
+
+        explainer.explain(&DependencyInfo::new::<Interface>(
+            DependencyKind::Single,
+            binding_options,
+        ))
+    }
+
+    fn binding_lookup(&self) -> BindingLookup<'_, dyn IAsyncProvider<Self>>
+    {
+        BindingLookup::new(
+            successors(Some(self), |di_container| di_container.parent.as_deref())
+                .map(|di_container| &di_container.binding_storage)
+                .collect(),
+        )
+    }
+
+    /// Resolves the singletons of the bindings in a lazy singleton scope that haven't
+    /// been resolved yet.
+    pub(crate) async fn resolve_lazy_singletons(&self) -> Result<(), BuildError>
+    {
+        for binding in self.binding_storage.iter() {
+            if binding.provider.scope() != BindingScope::LazySingleton
+                || binding.provider.is_singleton_built()
+            {
+                continue;
+            }
+
+            binding
+                .provider
+                .provide(self, DependencyHistory::new())
+                .await
+                .map_err(|reason| BuildError::LazySingletonResolveFailed {
+                    reason,
+                    service: binding
+                        .provider
+                        .injectable()
+                        .map_or(binding.interface.name, |injectable| {
+                            injectable.type_name
+                        }),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the providers that hold built singletons, in the order that the
+    /// singletons were constructed in.
+    fn held_singletons(&self) -> Vec<(&dyn IAsyncProvider<Self>, HeldSingleton)>
+    {
+        let mut held_singletons = self
+            .binding_storage
+            .iter()
+            .filter_map(|binding| {
+                Some((binding.provider, binding.provider.held_singleton()?))
+            })
+            .collect::<Vec<_>>();
+
+        held_singletons
+            .sort_by_key(|(_, held_singleton)| held_singleton.construction_order);
+
+        held_singletons
+    }
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -111,28 +625,238 @@ impl AsyncDIContainer
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut di_container = AsyncDIContainer::new();
     ///
-    /// di_container.bind::<DiskWriter>().to::<DiskWriter>()?;
+    /// di_container.bind::<DiskWriter>().to::<DiskWriter>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn bind<Interface>(&mut self) -> AsyncBindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        #[cfg(test)]
+        panic!("Bind function is unusable when testing");
+
+        #[cfg(not(test))]
+        AsyncBindingBuilder::new(self, DependencyHistory::new)
+    }
+
+    /// Returns a new [`AsyncBindingBuilder`] for the given interface that replaces the
+    /// existing binding of the interface without a name instead of failing.
+    ///
+    /// The binding without a name is only replaced if the new binding doesn't end up
+    /// with a name, tags or a condition. Otherwise it's kept and the new binding
+    /// replaces the binding with the same name & tags, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait IMailer: Send + Sync {}
+    /// #
+    /// # struct SmtpMailer {}
+    /// #
+    /// # #[injectable(IMailer, async = true)]
+    /// # impl SmtpMailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IMailer for SmtpMailer {}
+    /// #
+    /// # struct FakeMailer {}
+    /// #
+    /// # #[injectable(IMailer, async = true)]
+    /// # impl FakeMailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IMailer for FakeMailer {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.bind::<dyn IMailer>().to::<SmtpMailer>()?;
+    ///
+    /// di_container.rebind::<dyn IMailer>().to::<FakeMailer>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn rebind<Interface>(&mut self) -> AsyncBindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.bind::<Interface>().rebinding()
+    }
+
+    /// Removes the binding of `Interface` that doesn't have a name.
+    ///
+    /// # Errors
+    /// Will return `Err` if no such binding exists in this container.
+    pub fn unbind<Interface>(&mut self) -> Result<(), AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.remove_binding::<Interface>(BindingOptions::new())
+            .ok_or_else(|| AsyncDIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: None,
+                key: None,
+                searched_levels: 1,
+            })?;
+
+        Ok(())
+    }
+
+    /// Removes the binding of `Interface` with the specified name.
+    ///
+    /// # Errors
+    /// Will return `Err` if no such binding exists in this container.
+    pub fn unbind_named<Interface>(
+        &mut self,
+        name: &str,
+    ) -> Result<(), AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.remove_binding::<Interface>(BindingOptions::new().name(name.to_string()))
+            .ok_or_else(|| AsyncDIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: Some(name.to_string()),
+                key: None,
+                searched_levels: 1,
+            })?;
+
+        Ok(())
+    }
+
+    /// Removes all of the bindings of this container.
+    ///
+    /// The bindings of the ancestors of this container are left untouched.
+    pub fn unbind_all(&mut self)
+    {
+        self.binding_storage = DIContainerBindingStorage::new();
+    }
+
+    /// Returns the type bound with `Interface`.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct DeviceManager {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl DeviceManager
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container.bind::<DeviceManager>().to::<DeviceManager>()?;
+    ///
+    /// let device_manager = di_container.get::<DeviceManager>().await?.transient();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get<Interface>(
+        &self,
+    ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_bound::<Interface>(DependencyHistory::new(), BindingOptions::new())
+            .await
+    }
+
+    /// Returns the type bound with `Interface` and the specified name.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with name `name` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct DeviceManager {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl DeviceManager
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<DeviceManager>()
+    ///     .to::<DeviceManager>()?
+    ///     .in_transient_scope()
+    ///     .when_named("usb");
+    ///
+    /// let device_manager = di_container
+    ///     .get_named::<DeviceManager>("usb")
+    ///     .await?
+    ///     .transient();
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    #[allow(clippy::missing_panics_doc)]
-    pub fn bind<Interface>(&mut self) -> AsyncBindingBuilder<'_, Interface>
+    pub async fn get_named<Interface>(
+        &self,
+        name: &str,
+    ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
     where
         Interface: 'static + ?Sized + Send + Sync,
     {
-        #[cfg(test)]
-        panic!("Bind function is unusable when testing");
-
-        #[cfg(not(test))]
-        AsyncBindingBuilder::new(self, DependencyHistory::new)
+        self.get_bound_by_ref::<Interface>(
+            DependencyHistory::new(),
+            &BindingOptions::new().name(name),
+        )
+        .await
     }
 
-    /// Returns the type bound with `Interface`.
+    /// Returns the type bound with `Interface` and the specified name or, if no binding
+    /// has the name, the type bound with `Interface` without a name.
+    ///
+    /// Unlike this method, [`get_named`](Self::get_named) never falls back to the binding
+    /// without a name.
     ///
     /// # Errors
     /// Will return `Err` if:
-    /// - No binding for `Interface` exists
+    /// - No binding for `Interface` with name `name` nor without a name exists
     /// - Resolving the binding for `Interface` fails
     /// - Casting the binding for `Interface` fails
     ///
@@ -140,10 +864,10 @@ impl AsyncDIContainer
     /// ```
     /// # use syrette::{AsyncDIContainer, injectable};
     /// #
-    /// # struct DeviceManager {}
+    /// # struct PaymentGateway {}
     /// #
     /// # #[injectable(async = true)]
-    /// # impl DeviceManager
+    /// # impl PaymentGateway
     /// # {
     /// #     fn new() -> Self
     /// #     {
@@ -155,28 +879,46 @@ impl AsyncDIContainer
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut di_container = AsyncDIContainer::new();
     ///
-    /// di_container.bind::<DeviceManager>().to::<DeviceManager>()?;
+    /// di_container
+    ///     .bind::<PaymentGateway>()
+    ///     .to::<PaymentGateway>()?;
     ///
-    /// let device_manager = di_container.get::<DeviceManager>().await?.transient();
+    /// let payment_gateway = di_container
+    ///     .get_named_or_default::<PaymentGateway>("eu")
+    ///     .await?
+    ///     .transient();
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get<Interface>(
+    pub async fn get_named_or_default<Interface>(
         &self,
+        name: &str,
     ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
     where
         Interface: 'static + ?Sized + Send + Sync,
     {
-        self.get_bound::<Interface>(DependencyHistory::new(), BindingOptions::new())
-            .await
+        match self.get_named::<Interface>(name).await {
+            Err(err @ AsyncDIContainerError::BindingNotFound { .. }) => {
+                match self.get::<Interface>().await {
+                    // The error of the named request tells what was requested
+                    Err(AsyncDIContainerError::BindingNotFound { .. }) => Err(err),
+                    result => result,
+                }
+            }
+            result => result,
+        }
     }
 
-    /// Returns the type bound with `Interface` and the specified name.
+    /// Returns the type bound with `Interface` where the binding has the specified tags.
+    ///
+    /// A binding with more tags than the specified ones also matches, as long as no other
+    /// binding does.
     ///
     /// # Errors
     /// Will return `Err` if:
-    /// - No binding for `Interface` with name `name` exists
+    /// - No binding for `Interface` with the tags exists
+    /// - More than one binding for `Interface` with the tags exists
     /// - Resolving the binding for `Interface` fails
     /// - Casting the binding for `Interface` fails
     ///
@@ -184,10 +926,12 @@ impl AsyncDIContainer
     /// ```
     /// # use syrette::{AsyncDIContainer, injectable};
     /// #
-    /// # struct DeviceManager {}
+    /// # trait IDatabase: Send + Sync {}
     /// #
-    /// # #[injectable(async = true)]
-    /// # impl DeviceManager
+    /// # struct EuReplica {}
+    /// #
+    /// # #[injectable(IDatabase, async = true)]
+    /// # impl EuReplica
     /// # {
     /// #     fn new() -> Self
     /// #     {
@@ -195,36 +939,42 @@ impl AsyncDIContainer
     /// #     }
     /// # }
     /// #
+    /// # impl IDatabase for EuReplica {}
+    /// #
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut di_container = AsyncDIContainer::new();
     ///
     /// di_container
-    ///     .bind::<DeviceManager>()
-    ///     .to::<DeviceManager>()?
+    ///     .bind::<dyn IDatabase>()
+    ///     .to::<EuReplica>()?
     ///     .in_transient_scope()
-    ///     .when_named("usb");
+    ///     .when_tagged("region", "eu")?
+    ///     .when_tagged("access", "read_only")?;
     ///
-    /// let device_manager = di_container
-    ///     .get_named::<DeviceManager>("usb")
+    /// let database = di_container
+    ///     .get_tagged::<dyn IDatabase>(&[("region", "eu"), ("access", "read_only")])
     ///     .await?
-    ///     .transient();
+    ///     .transient()?;
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_named<Interface>(
+    pub async fn get_tagged<Interface>(
         &self,
-        name: &'static str,
+        tags: &[(&'static str, &'static str)],
     ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
     where
         Interface: 'static + ?Sized + Send + Sync,
     {
-        self.get_bound::<Interface>(
-            DependencyHistory::new(),
-            BindingOptions::new().name(name),
-        )
-        .await
+        let binding_options = tags
+            .iter()
+            .fold(BindingOptions::new(), |binding_options, (key, value)| {
+                binding_options.tag(key, value)
+            });
+
+        self.get_bound::<Interface>(DependencyHistory::new(), binding_options)
+            .await
     }
 
     /// Returns the type bound with `Interface` where the binding has the specified
@@ -232,9 +982,13 @@ impl AsyncDIContainer
     ///
     /// `dependency_history` is passed to the bound type when it is being resolved.
     ///
+    /// The binding is searched for in this container first and then in each of its
+    /// ancestors.
+    ///
     /// # Errors
     /// Will return `Err` if:
-    /// - No binding for `Interface` exists
+    /// - No binding for `Interface` exists in this container or any of its ancestors.
+    ///   The returned error reports how many levels of the hierarchy were searched
     /// - Resolving the binding for `Interface` fails
     /// - Casting the binding for `Interface` fails
     ///
@@ -269,11 +1023,208 @@ impl AsyncDIContainer
     where
         Interface: 'static + ?Sized + Send + Sync,
     {
-        let binding_providable = self
-            .get_binding_providable::<Interface>(binding_options, dependency_history)
+        self.get_bound_by_ref::<Interface>(dependency_history, &binding_options)
+            .await
+    }
+
+    /// Returns all of the types bound with `Interface` without a name or tags.
+    ///
+    /// The bindings of the ancestors of this container come first, followed by the
+    /// bindings of this container. The bindings of each container are in the order
+    /// they were bound in.
+    ///
+    /// If both this container and a ancestor of it has a binding bound with
+    /// [`to`], the binding of this container is used. Bindings bound with
+    /// [`to_additional`] are never left out.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait IPlugin: Send + Sync {}
+    /// #
+    /// # struct AudioPlugin {}
+    /// #
+    /// # #[injectable(IPlugin, async = true)]
+    /// # impl AudioPlugin
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IPlugin for AudioPlugin {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IPlugin>()
+    ///     .to_additional::<AudioPlugin>()
+    ///     .in_singleton_scope()
+    ///     .await?;
+    ///
+    /// let plugins = di_container.get_all::<dyn IPlugin>().await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to`]: AsyncBindingBuilder::to
+    /// [`to_additional`]: AsyncBindingBuilder::to_additional
+    pub async fn get_all<Interface>(
+        &self,
+    ) -> Result<Vec<SomePtr<Interface>>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_all_bound::<Interface>(DependencyHistory::new())
+            .await
+    }
+
+    /// Returns all of the types bound with `Interface` without a name or tags.
+    ///
+    /// `dependency_history` is passed to each of the bound types when they are being
+    /// resolved.
+    ///
+    /// The bindings are ordered the same way as with [`get_all`].
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    ///
+    /// [`get_all`]: Self::get_all
+    pub async fn get_all_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+    ) -> Result<Vec<SomePtr<Interface>>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let binding_providables = self
+            .get_all_binding_providables::<Interface>(
+                BindingOptions::is_unqualified,
+                &dependency_history,
+            )
+            .await?;
+
+        let mut bound = Vec::with_capacity(binding_providables.len());
+
+        for (_, binding_providable) in binding_providables {
+            bound.push(self.handle_binding_providable(binding_providable).await?);
+        }
+
+        Ok(bound.into_iter().map(SomePtr::from).collect())
+    }
+
+    /// Returns all of the types bound with `Interface` and a name but no tags, keyed by
+    /// name.
+    ///
+    /// If both this container and a ancestor of it has a binding with the same name,
+    /// the binding of this container is used.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait ICommandHandler: Send + Sync {}
+    /// #
+    /// # struct QuitHandler {}
+    /// #
+    /// # #[injectable(ICommandHandler, async = true)]
+    /// # impl QuitHandler
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl ICommandHandler for QuitHandler {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn ICommandHandler>()
+    ///     .to::<QuitHandler>()?
+    ///     .in_transient_scope()
+    ///     .when_named("quit")?;
+    ///
+    /// let handlers = di_container
+    ///     .get_all_named::<dyn ICommandHandler>()
+    ///     .await?;
+    ///
+    /// assert!(handlers.contains_key("quit"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_named<Interface>(
+        &self,
+    ) -> Result<HashMap<String, SomePtr<Interface>>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_all_named_bound::<Interface>(DependencyHistory::new())
+            .await
+    }
+
+    /// Returns all of the types bound with `Interface` and a name but no tags, keyed by
+    /// name.
+    ///
+    /// `dependency_history` is passed to each of the bound types when they are being
+    /// resolved.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    pub async fn get_all_named_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+    ) -> Result<HashMap<String, SomePtr<Interface>>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let binding_providables = self
+            .get_all_binding_providables::<Interface>(
+                |binding_options| {
+                    binding_options.name.is_some() && binding_options.tags.is_empty()
+                },
+                &dependency_history,
+            )
             .await?;
 
-        self.handle_binding_providable(binding_providable).await
+        let mut bound = Vec::with_capacity(binding_providables.len());
+
+        for (binding_options, binding_providable) in binding_providables {
+            if let Some(name) = binding_options.name {
+                bound.push((
+                    name.to_string(),
+                    self.handle_binding_providable(binding_providable).await?,
+                ));
+            }
+        }
+
+        Ok(bound
+            .into_iter()
+            .map(|(name, ptr)| (name, SomePtr::from(ptr)))
+            .collect())
     }
 
     fn has_binding<Interface>(&self, binding_options: BindingOptions<'static>) -> bool
@@ -303,6 +1254,17 @@ impl AsyncDIContainer
     {
         self.binding_storage.remove::<Interface>(binding_options)
     }
+
+    fn set_binding_condition<Interface>(
+        &mut self,
+        binding_options: BindingOptions<'static>,
+        condition: BindingCondition,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        self.binding_storage
+            .set_condition::<Interface>(binding_options, condition);
+    }
 }
 
 impl AsyncDIContainer
@@ -310,21 +1272,23 @@ impl AsyncDIContainer
     async fn handle_binding_providable<Interface>(
         &self,
         binding_providable: AsyncProvidable<Self>,
-    ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
+    ) -> Result<ThreadsafeSomePtr<Interface>, AsyncDIContainerError>
     where
         Interface: 'static + ?Sized + Send + Sync,
     {
         match binding_providable {
-            AsyncProvidable::Transient(transient_binding) => Ok(SomePtr::Transient(
-                transient_binding.cast::<Interface>().map_err(|_| {
-                    AsyncDIContainerError::CastFailed {
-                        interface: type_name::<Interface>(),
-                        binding_kind: "transient",
-                    }
-                })?,
-            )),
+            AsyncProvidable::Transient(transient_binding) => {
+                Ok(ThreadsafeSomePtr::Transient(
+                    transient_binding.cast::<Interface>().map_err(|_| {
+                        AsyncDIContainerError::CastFailed {
+                            interface: type_name::<Interface>(),
+                            binding_kind: "transient",
+                        }
+                    })?,
+                ))
+            }
             AsyncProvidable::Singleton(singleton_binding) => {
-                Ok(SomePtr::ThreadsafeSingleton(
+                Ok(ThreadsafeSomePtr::Singleton(
                     singleton_binding
                         .cast::<Interface>()
                         .map_err(|err| match err {
@@ -349,7 +1313,11 @@ impl AsyncDIContainer
                 ))
             }
             #[cfg(feature = "factory")]
-            AsyncProvidable::Function(func_bound, ProvidableFunctionKind::UserCalled) => {
+            AsyncProvidable::Function(
+                func_bound,
+                ProvidableFunctionKind::UserCalled
+                | ProvidableFunctionKind::AsyncUserCalled,
+            ) => {
                 let factory = func_bound
                     .as_any()
                     .downcast_ref::<ThreadsafeCastableFunction<Interface, Self>>()
@@ -358,7 +1326,7 @@ impl AsyncDIContainer
                         binding_kind: "factory",
                     })?;
 
-                Ok(SomePtr::ThreadsafeFactory(factory.call(self).into()))
+                Ok(ThreadsafeSomePtr::Factory(factory.call(self).into()))
             }
             AsyncProvidable::Function(func_bound, ProvidableFunctionKind::Instant) => {
                 type Func<Interface> = ThreadsafeCastableFunction<
@@ -374,7 +1342,7 @@ impl AsyncDIContainer
                         binding_kind: "dynamic value func",
                     })?;
 
-                Ok(SomePtr::Transient(dynamic_val_func.call(self)()))
+                Ok(ThreadsafeSomePtr::Transient(dynamic_val_func.call(self)()))
             }
             AsyncProvidable::Function(
                 func_bound,
@@ -393,42 +1361,228 @@ impl AsyncDIContainer
                         binding_kind: "async dynamic value function",
                     })?;
 
-                Ok(SomePtr::Transient(
-                    async_dynamic_value_func.call(self)().await,
-                ))
-            }
+                Ok(ThreadsafeSomePtr::Transient(
+                    async_dynamic_value_func.call(self)().await,
+                ))
+            }
+        }
+    }
+
+    /// Same as [`Self::get_bound`] but with borrowed binding options, so that a binding
+    /// can be looked up by a name that isn't `'static` without copying it.
+    async fn get_bound_by_ref<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: &BindingOptions<'_>,
+    ) -> Result<SomePtr<Interface>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let binding_providable = self
+            .get_binding_providable::<Interface>(binding_options, dependency_history)
+            .await?;
+
+        self.handle_binding_providable(binding_providable)
+            .await
+            .map(SomePtr::from)
+    }
+
+    async fn get_binding_providable<Interface>(
+        &self,
+        binding_options: &BindingOptions<'_>,
+        mut dependency_history: DependencyHistory,
+    ) -> Result<AsyncProvidable<Self>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let mut searched_levels = 0;
+
+        let provider = successors(Some(self), |di_container| {
+            di_container.parent.as_deref()
+        })
+        .find_map(|di_container| {
+            searched_levels += 1;
+
+            di_container
+                .find_binding_provider::<Interface>(
+                    binding_options,
+                    &dependency_history,
+                )
+                .map(|provider| provider.map(|provider| (di_container, provider)))
+                .transpose()
+        })
+        .transpose()?;
+
+        let (owner, (bound_options, provider)) = provider.map_or_else(
+            || {
+                Err(AsyncDIContainerError::BindingNotFound {
+                    interface: type_name::<Interface>(),
+                    name: binding_options.name.as_deref().map(ToString::to_string),
+                    key: binding_options.key.as_ref().map(ToString::to_string),
+                    searched_levels,
+                })
+            },
+            Ok,
+        )?;
+
+        dependency_history.set_next_binding_name(bound_options.name.clone());
+
+        let resolver = self.resolver_of(owner, provider.as_ref());
+
+        provider
+            .provide(resolver, dependency_history)
+            .await
+            .map_err(|err| AsyncDIContainerError::BindingResolveFailed {
+                reason: err,
+                interface: type_name::<Interface>(),
+            })
+    }
+
+    /// Returns the provider of the binding with a satisfied condition or, if there is no
+    /// such binding, the provider of the binding without a condition.
+    fn find_binding_provider<'this, Interface>(
+        &'this self,
+        binding_options: &BindingOptions<'this>,
+        dependency_history: &DependencyHistory,
+    ) -> Result<Option<FoundBinding<'this>>, AsyncDIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let binding = self
+            .binding_storage
+            .find_single::<Interface>(binding_options, |condition| {
+                dependency_history.satisfies(condition, binding_options)
+            })
+            .map_err(|matching| AsyncDIContainerError::AmbiguousBinding {
+                interface: type_name::<Interface>(),
+                name: binding_options.name.as_deref().map(ToString::to_string),
+                key: binding_options.key.as_ref().map(ToString::to_string),
+                matching,
+            })?;
+
+        Ok(binding.map(|(options, provider)| (options, provider.do_clone())))
+    }
+
+    /// Returns the DI container to resolve the dependencies of `provider` with, where
+    /// `provider` is the provider of a binding of `owner`.
+    ///
+    /// A lazy singleton is shared by every descendant of the DI container that owns
+    /// it's binding, so it's resolved by that DI container instead of by the
+    /// descendant it happens to be requested through first.
+    fn resolver_of<'this>(
+        &'this self,
+        owner: &'this Self,
+        provider: &dyn IAsyncProvider<Self>,
+    ) -> &'this Self
+    {
+        if provider.scope() == BindingScope::LazySingleton {
+            owner
+        } else {
+            self
+        }
+    }
+
+    async fn get_all_binding_providables<Interface>(
+        &self,
+        filter: impl Fn(&BindingOptions<'static>) -> bool + Send,
+        dependency_history: &DependencyHistory,
+    ) -> Result<
+        Vec<(BindingOptions<'static>, AsyncProvidable<Self>)>,
+        AsyncDIContainerError,
+    >
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let mut di_containers = successors(Some(self), |di_container| {
+            di_container.parent.as_deref()
+        })
+        .collect::<Vec<_>>();
+
+        // Ancestors come first
+        di_containers.reverse();
+
+        let providers = di_containers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, di_container)| {
+                let descendants = &di_containers[index + 1..];
+
+                di_container
+                    .binding_storage
+                    .get_all_satisfied::<Interface>(|condition, binding_options| {
+                        dependency_history.satisfies(condition, binding_options)
+                    })
+                    .into_iter()
+                    .filter(move |(binding_options, _)| {
+                        // The binding is shadowed by a binding of a descendant
+                        // container with the same options
+                        !descendants.iter().any(|descendant| {
+                            descendant
+                                .binding_storage
+                                .has::<Interface>((*binding_options).clone())
+                        })
+                    })
+                    .map(move |(binding_options, provider)| {
+                        (*di_container, binding_options, provider)
+                    })
+            })
+            .filter(|(_, binding_options, _)| filter(binding_options))
+            .map(|(owner, binding_options, provider)| {
+                (owner, binding_options.clone(), provider.do_clone())
+            })
+            .collect::<Vec<_>>();
+
+        let mut binding_providables = Vec::with_capacity(providers.len());
+
+        for (owner, binding_options, provider) in providers {
+            let mut dependency_history = dependency_history.clone();
+
+            dependency_history.set_next_binding_name(binding_options.name.clone());
+
+            let resolver = self.resolver_of(owner, provider.as_ref());
+
+            let binding_providable = provider
+                .provide(resolver, dependency_history)
+                .await
+                .map_err(|err| AsyncDIContainerError::BindingResolveFailed {
+                    reason: err,
+                    interface: type_name::<Interface>(),
+                })?;
+
+            binding_providables.push((binding_options, binding_providable));
         }
+
+        Ok(binding_providables)
     }
+}
 
-    async fn get_binding_providable<Interface>(
-        &self,
-        binding_options: BindingOptions<'static>,
-        dependency_history: DependencyHistory,
-    ) -> Result<AsyncProvidable<Self>, AsyncDIContainerError>
-    where
-        Interface: 'static + ?Sized + Send + Sync,
-    {
-        let provider = self
-            .binding_storage
-            .get::<Interface>(binding_options.clone())
-            .map_or_else(
-                || {
-                    Err(AsyncDIContainerError::BindingNotFound {
-                        interface: type_name::<Interface>(),
-                        name: binding_options.name,
-                    })
-                },
-                Ok,
-            )?
-            .clone();
+/// The variants of [`SomePtr`] that the [`AsyncDIContainer`] resolves bindings to.
+///
+/// Unlike [`SomePtr`], this is [`Send`] so that it can be held across await points.
+enum ThreadsafeSomePtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    Transient(TransientPtr<Interface>),
+    Singleton(ThreadsafeSingletonPtr<Interface>),
+    #[cfg(feature = "factory")]
+    Factory(crate::ptr::ThreadsafeFactoryPtr<Interface>),
+}
 
-        provider
-            .provide(self, dependency_history)
-            .await
-            .map_err(|err| AsyncDIContainerError::BindingResolveFailed {
-                reason: err,
-                interface: type_name::<Interface>(),
-            })
+impl<Interface> From<ThreadsafeSomePtr<Interface>> for SomePtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    fn from(threadsafe_some_ptr: ThreadsafeSomePtr<Interface>) -> Self
+    {
+        match threadsafe_some_ptr {
+            ThreadsafeSomePtr::Transient(transient) => Self::Transient(transient),
+            ThreadsafeSomePtr::Singleton(singleton) => {
+                Self::ThreadsafeSingleton(singleton)
+            }
+            #[cfg(feature = "factory")]
+            ThreadsafeSomePtr::Factory(factory) => Self::ThreadsafeFactory(factory),
+        }
     }
 }
 
@@ -436,6 +1590,7 @@ impl AsyncDIContainer
 mod tests
 {
     use super::*;
+    use crate::dependency_history::MockDependencyHistory;
     use crate::ptr::{ThreadsafeSingletonPtr, TransientPtr};
     use crate::test_utils::mocks::async_provider::MockAsyncProvider;
     use crate::test_utils::subjects_async;
@@ -450,6 +1605,10 @@ mod tests
         mock_provider.expect_do_clone().returning(|| {
             let mut inner_mock_provider = MockAsyncProvider::new();
 
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
             inner_mock_provider.expect_provide().returning(|_, _| {
                 Ok(AsyncProvidable::Transient(TransientPtr::new(
                     subjects_async::UserManager::new(),
@@ -474,6 +1633,67 @@ mod tests
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn can_get_with_satisfied_condition()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        mock_provider.expect_do_clone().never();
+
+        di_container.set_binding::<dyn subjects_async::INumber>(
+            BindingOptions::new(),
+            Box::new(mock_provider),
+        );
+
+        for (num, is_satisfied) in [(1, false), (2, true)] {
+            let mut mock_provider = MockAsyncProvider::new();
+
+            mock_provider.expect_do_clone().returning(move || {
+                let mut inner_mock_provider = MockAsyncProvider::new();
+
+                inner_mock_provider
+                    .expect_scope()
+                    .return_const(BindingScope::Transient);
+
+                inner_mock_provider.expect_provide().returning(move |_, _| {
+                    Ok(AsyncProvidable::Transient(TransientPtr::new(
+                        subjects_async::Number { num },
+                    )))
+                });
+
+                Box::new(inner_mock_provider)
+            });
+
+            let binding_options = BindingOptions::new().additional();
+
+            di_container.set_binding::<dyn subjects_async::INumber>(
+                binding_options.clone(),
+                Box::new(mock_provider),
+            );
+
+            di_container.set_binding_condition::<dyn subjects_async::INumber>(
+                binding_options,
+                Arc::new(move |_context| is_satisfied),
+            );
+        }
+
+        let dependency_history_mock = MockDependencyHistory::new();
+
+        let number = di_container
+            .get_bound::<dyn subjects_async::INumber>(
+                dependency_history_mock,
+                BindingOptions::new(),
+            )
+            .await
+            .unwrap()
+            .transient()
+            .unwrap();
+
+        assert_eq!(number.get(), 2);
+    }
+
     #[tokio::test]
     async fn can_get_named()
     {
@@ -484,6 +1704,10 @@ mod tests
         mock_provider.expect_do_clone().returning(|| {
             let mut inner_mock_provider = MockAsyncProvider::new();
 
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
             inner_mock_provider.expect_provide().returning(|_, _| {
                 Ok(AsyncProvidable::Transient(TransientPtr::new(
                     subjects_async::UserManager::new(),
@@ -508,6 +1732,59 @@ mod tests
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn get_named_or_default_falls_back_to_binding_without_name()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        assert!(matches!(
+            di_container
+                .get_named_or_default::<dyn subjects_async::IUserManager>("special")
+                .await,
+            Err(AsyncDIContainerError::BindingNotFound { name: Some(name), .. })
+                if name == "special"
+        ));
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        mock_provider.expect_do_clone().returning(|| {
+            let mut inner_mock_provider = MockAsyncProvider::new();
+
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
+            inner_mock_provider.expect_provide().returning(|_, _| {
+                Ok(AsyncProvidable::Transient(TransientPtr::new(
+                    subjects_async::UserManager::new(),
+                )))
+            });
+
+            Box::new(inner_mock_provider)
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects_async::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        assert!(matches!(
+            di_container
+                .get_named::<dyn subjects_async::IUserManager>("special")
+                .await,
+            Err(AsyncDIContainerError::BindingNotFound { .. })
+        ));
+
+        di_container
+            .get_named_or_default::<dyn subjects_async::IUserManager>("special")
+            .await
+            .unwrap()
+            .transient()
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn can_get_singleton()
     {
@@ -522,6 +1799,10 @@ mod tests
         mock_provider.expect_do_clone().returning(move || {
             let mut inner_mock_provider = MockAsyncProvider::new();
 
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
             let singleton_clone = singleton.clone();
 
             inner_mock_provider.expect_provide().returning(move |_, _| {
@@ -571,6 +1852,10 @@ mod tests
         mock_provider.expect_do_clone().returning(move || {
             let mut inner_mock_provider = MockAsyncProvider::new();
 
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
             let singleton_clone = singleton.clone();
 
             inner_mock_provider.expect_provide().returning(move |_, _| {
@@ -661,6 +1946,10 @@ mod tests
         mock_provider.expect_do_clone().returning(|| {
             let mut inner_mock_provider = MockAsyncProvider::new();
 
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
             let factory_func = &|_: &AsyncDIContainer| {
                 Box::new(|users| {
                     TransientPtr::new(UserManager::new(users))
@@ -745,6 +2034,10 @@ mod tests
         mock_provider.expect_do_clone().returning(|| {
             let mut inner_mock_provider = MockAsyncProvider::new();
 
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
             let factory_func = &|_: &AsyncDIContainer| {
                 Box::new(|users| {
                     TransientPtr::new(UserManager::new(users))
@@ -775,6 +2068,191 @@ mod tests
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn can_get_from_parent()
+    {
+        let mut parent_di_container = AsyncDIContainer::new();
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        let mut singleton = ThreadsafeSingletonPtr::new(subjects_async::Number::new());
+
+        ThreadsafeSingletonPtr::get_mut(&mut singleton).unwrap().num = 4096;
+
+        mock_provider.expect_do_clone().returning(move || {
+            let mut inner_mock_provider = MockAsyncProvider::new();
+
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
+            let singleton_clone = singleton.clone();
+
+            inner_mock_provider.expect_provide().returning(move |_, _| {
+                Ok(AsyncProvidable::Singleton(singleton_clone.clone()))
+            });
+
+            Box::new(inner_mock_provider)
+        });
+
+        parent_di_container
+            .binding_storage
+            .set::<dyn subjects_async::INumber>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        let parent_di_container = Arc::new(parent_di_container);
+
+        let child_di_container = parent_di_container.create_child();
+
+        let parent_number = parent_di_container
+            .get::<dyn subjects_async::INumber>()
+            .await
+            .unwrap()
+            .threadsafe_singleton()
+            .unwrap();
+
+        let child_number = child_di_container
+            .get::<dyn subjects_async::INumber>()
+            .await
+            .unwrap()
+            .threadsafe_singleton()
+            .unwrap();
+
+        assert_eq!(child_number.get(), 4096);
+
+        assert!(Arc::ptr_eq(&parent_number, &child_number));
+    }
+
+    #[tokio::test]
+    async fn can_get_all()
+    {
+        fn create_number_provider(num: i32) -> MockAsyncProvider<AsyncDIContainer>
+        {
+            let mut mock_provider = MockAsyncProvider::new();
+
+            mock_provider.expect_do_clone().returning(move || {
+                let mut inner_mock_provider = MockAsyncProvider::new();
+
+                inner_mock_provider
+                    .expect_scope()
+                    .return_const(BindingScope::Transient);
+
+                inner_mock_provider.expect_provide().returning(move |_, _| {
+                    Ok(AsyncProvidable::Transient(TransientPtr::new(
+                        subjects_async::Number { num },
+                    )))
+                });
+
+                Box::new(inner_mock_provider)
+            });
+
+            mock_provider
+        }
+
+        let mut parent_di_container = AsyncDIContainer::new();
+
+        parent_di_container
+            .binding_storage
+            .set::<dyn subjects_async::INumber>(
+                BindingOptions::new().additional(),
+                Box::new(create_number_provider(1)),
+            );
+
+        let mut di_container = Arc::new(parent_di_container).create_child();
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects_async::INumber>(
+                BindingOptions::new(),
+                Box::new(create_number_provider(2)),
+            );
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects_async::INumber>(
+                BindingOptions::new().name("four"),
+                Box::new(create_number_provider(4)),
+            );
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects_async::INumber>(
+                BindingOptions::new().additional(),
+                Box::new(create_number_provider(3)),
+            );
+
+        let numbers = di_container
+            .get_all::<dyn subjects_async::INumber>()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|number| number.transient().unwrap().get())
+            .collect::<Vec<_>>();
+
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn child_bindings_are_not_visible_to_parent()
+    {
+        let parent_di_container = Arc::new(AsyncDIContainer::new());
+
+        let mut child_di_container = parent_di_container.create_child();
+
+        let mut mock_provider = MockAsyncProvider::new();
+
+        mock_provider.expect_do_clone().returning(|| {
+            let mut inner_mock_provider = MockAsyncProvider::new();
+
+            inner_mock_provider
+                .expect_scope()
+                .return_const(BindingScope::Transient);
+
+            inner_mock_provider.expect_provide().returning(|_, _| {
+                Ok(AsyncProvidable::Transient(TransientPtr::new(
+                    subjects_async::UserManager::new(),
+                )))
+            });
+
+            Box::new(inner_mock_provider)
+        });
+
+        child_di_container
+            .binding_storage
+            .set::<dyn subjects_async::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        assert!(child_di_container
+            .get::<dyn subjects_async::IUserManager>()
+            .await
+            .is_ok());
+
+        assert!(matches!(
+            parent_di_container
+                .get::<dyn subjects_async::IUserManager>()
+                .await,
+            Err(AsyncDIContainerError::BindingNotFound {
+                searched_levels: 1,
+                ..
+            })
+        ));
+
+        assert!(matches!(
+            Arc::new(child_di_container)
+                .create_child()
+                .get::<dyn subjects_async::INumber>()
+                .await,
+            Err(AsyncDIContainerError::BindingNotFound {
+                searched_levels: 3,
+                ..
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn has_binding_works()
     {
@@ -834,4 +2312,62 @@ mod tests
                 .has::<subjects_async::UserManager>(BindingOptions::new())
         );
     }
+
+    #[test]
+    fn unbind_works()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .binding_storage
+            .set::<subjects_async::UserManager>(
+                BindingOptions::new(),
+                Box::new(MockAsyncProvider::new()),
+            );
+
+        di_container
+            .binding_storage
+            .set::<subjects_async::UserManager>(
+                BindingOptions::new().name("admin"),
+                Box::new(MockAsyncProvider::new()),
+            );
+
+        assert!(di_container.unbind::<subjects_async::UserManager>().is_ok());
+
+        assert!(matches!(
+            di_container.unbind::<subjects_async::UserManager>(),
+            Err(AsyncDIContainerError::BindingNotFound { name: None, .. })
+        ));
+
+        assert!(di_container
+            .unbind_named::<subjects_async::UserManager>("admin")
+            .is_ok());
+
+        assert!(matches!(
+            di_container.unbind_named::<subjects_async::UserManager>("admin"),
+            Err(AsyncDIContainerError::BindingNotFound {
+                name: Some(name),
+                ..
+            }) if name == "admin"
+        ));
+    }
+
+    #[test]
+    fn unbind_all_works()
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .binding_storage
+            .set::<subjects_async::UserManager>(
+                BindingOptions::new(),
+                Box::new(MockAsyncProvider::new()),
+            );
+
+        di_container.unbind_all();
+
+        assert!(!di_container
+            .binding_storage
+            .has::<subjects_async::UserManager>(BindingOptions::new()));
+    }
 }
@@ -5,7 +5,10 @@ use std::sync::Arc;
 
 use crate::castable_function::threadsafe::ThreadsafeCastableFunction;
 use crate::di_container::asynchronous::binding::scope_configurator::AsyncBindingScopeConfigurator;
-use crate::di_container::asynchronous::binding::when_configurator::AsyncBindingWhenConfigurator;
+use crate::di_container::asynchronous::binding::when_configurator::{
+    AsyncBindingWhenConfigurator,
+    ReplacedBinding,
+};
 use crate::di_container::BindingOptions;
 use crate::errors::async_di_container::AsyncBindingBuilderError;
 use crate::future::BoxFuture;
@@ -30,6 +33,7 @@ where
 {
     di_container: &'di_container mut AsyncDIContainer,
     dependency_history_factory: fn() -> DependencyHistory,
+    is_rebinding: bool,
 
     interface_phantom: PhantomData<Interface>,
 }
@@ -46,10 +50,20 @@ where
         Self {
             di_container,
             dependency_history_factory,
+            is_rebinding: false,
             interface_phantom: PhantomData,
         }
     }
 
+    /// Makes the binding builder replace the existing binding of `Interface` without
+    /// a name instead of failing.
+    pub(crate) fn rebinding(mut self) -> Self
+    {
+        self.is_rebinding = true;
+
+        self
+    }
+
     /// Creates a binding of type `Interface` to type `Implementation` inside of the
     /// associated [`AsyncDIContainer`].
     ///
@@ -92,7 +106,7 @@ where
     /// # }
     /// ```
     pub fn to<Implementation>(
-        self,
+        mut self,
     ) -> Result<
         AsyncBindingScopeConfigurator<'di_container, Interface, Implementation>,
         AsyncBindingBuilderError,
@@ -100,26 +114,98 @@ where
     where
         Implementation: AsyncInjectable<AsyncDIContainer>,
     {
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >(
-            )));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let mut binding_scope_configurator = AsyncBindingScopeConfigurator::new(
             self.di_container,
             self.dependency_history_factory,
-        );
+            BindingOptions::new(),
+        )
+        .with_replaced_binding(replaced_binding);
 
         binding_scope_configurator.set_in_transient_scope();
 
         Ok(binding_scope_configurator)
     }
 
+    /// Adds a binding of type `Interface` to type `Implementation` inside of the
+    /// associated [`AsyncDIContainer`], next to any other bindings of the interface.
+    ///
+    /// Unlike [`to`], this never fails because of an existing binding. All of the
+    /// bindings of the interface are resolved with [`AsyncDIContainer::get_all`], in
+    /// the order they were added. Each of them keeps its own scope.
+    ///
+    /// The scope of the binding is transient. But that can be changed by using the
+    /// returned [`AsyncBindingScopeConfigurator`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::injectable;
+    /// # use syrette::AsyncDIContainer;
+    /// #
+    /// # trait IPlugin: Send + Sync {}
+    /// #
+    /// # struct AudioPlugin {}
+    /// #
+    /// # #[injectable(IPlugin, async = true)]
+    /// # impl AudioPlugin {
+    /// #   fn new() -> Self
+    /// #   {
+    /// #       Self {}
+    /// #   }
+    /// # }
+    /// #
+    /// # impl IPlugin for AudioPlugin {}
+    /// #
+    /// # struct VideoPlugin {}
+    /// #
+    /// # #[injectable(IPlugin, async = true)]
+    /// # impl VideoPlugin {
+    /// #   fn new() -> Self
+    /// #   {
+    /// #       Self {}
+    /// #   }
+    /// # }
+    /// #
+    /// # impl IPlugin for VideoPlugin {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = AsyncDIContainer::new();
+    /// #
+    /// di_container.bind::<dyn IPlugin>().to_additional::<AudioPlugin>();
+    /// di_container.bind::<dyn IPlugin>().to_additional::<VideoPlugin>();
+    ///
+    /// let plugins = di_container.get_all::<dyn IPlugin>().await?;
+    ///
+    /// assert_eq!(plugins.len(), 2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to`]: Self::to
+    #[allow(clippy::must_use_candidate)]
+    pub fn to_additional<Implementation>(
+        self,
+    ) -> AsyncBindingScopeConfigurator<'di_container, Interface, Implementation>
+    where
+        Implementation: AsyncInjectable<AsyncDIContainer>,
+    {
+        let mut binding_scope_configurator = AsyncBindingScopeConfigurator::new(
+            self.di_container,
+            self.dependency_history_factory,
+            BindingOptions::new().additional(),
+        );
+
+        binding_scope_configurator.set_in_transient_scope();
+
+        binding_scope_configurator
+    }
+
     /// Creates a binding of factory type `Interface` to a factory inside of the
     /// associated [`AsyncDIContainer`].
     ///
@@ -165,7 +251,7 @@ where
     #[cfg(feature = "factory")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
     pub fn to_factory<Args, Return, FactoryFunc>(
-        self,
+        mut self,
         factory_func: &'static FactoryFunc,
     ) -> Result<
         AsyncBindingWhenConfigurator<'di_container, Interface>,
@@ -182,15 +268,7 @@ where
         use crate::castable_function::threadsafe::ThreadsafeCastableFunction;
         use crate::provider::r#async::ProvidableFunctionKind;
 
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >(
-            )));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let factory_impl = ThreadsafeCastableFunction::new(factory_func);
 
@@ -202,7 +280,10 @@ where
             )),
         );
 
-        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+        Ok(
+            AsyncBindingWhenConfigurator::new(self.di_container, BindingOptions::new())
+                .with_replaced_binding(replaced_binding),
+        )
     }
 
     /// Creates a binding of factory type `Interface` to a async factory inside of the
@@ -259,7 +340,7 @@ where
     #[cfg(feature = "factory")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
     pub fn to_async_factory<Args, Return, FactoryFunc>(
-        self,
+        mut self,
         factory_func: &'static FactoryFunc,
     ) -> Result<
         AsyncBindingWhenConfigurator<'di_container, Interface>,
@@ -276,15 +357,7 @@ where
             + Send
             + Sync,
     {
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >(
-            )));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let factory_impl = ThreadsafeCastableFunction::new(factory_func);
 
@@ -292,11 +365,14 @@ where
             BindingOptions::new(),
             Box::new(AsyncFunctionProvider::new(
                 Arc::new(factory_impl),
-                ProvidableFunctionKind::UserCalled,
+                ProvidableFunctionKind::AsyncUserCalled,
             )),
         );
 
-        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+        Ok(
+            AsyncBindingWhenConfigurator::new(self.di_container, BindingOptions::new())
+                .with_replaced_binding(replaced_binding),
+        )
     }
 
     /// Creates a binding of type `Interface` to a value resolved using the given
@@ -343,7 +419,7 @@ where
     /// # }
     /// ```
     pub fn to_dynamic_value<Func>(
-        self,
+        mut self,
         func: &'static Func,
     ) -> Result<
         AsyncBindingWhenConfigurator<'di_container, Interface>,
@@ -356,15 +432,7 @@ where
             + Send
             + Sync,
     {
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >(
-            )));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let castable_func = ThreadsafeCastableFunction::new(func);
 
@@ -376,7 +444,10 @@ where
             )),
         );
 
-        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+        Ok(
+            AsyncBindingWhenConfigurator::new(self.di_container, BindingOptions::new())
+                .with_replaced_binding(replaced_binding),
+        )
     }
 
     /// Creates a binding of type `Interface` to a value resolved using the given
@@ -430,7 +501,7 @@ where
     /// # }
     /// ```
     pub fn to_async_dynamic_value<Func>(
-        self,
+        mut self,
         func: &'static Func,
     ) -> Result<
         AsyncBindingWhenConfigurator<'di_container, Interface>,
@@ -444,15 +515,7 @@ where
             > + Send
             + Sync,
     {
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >(
-            )));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let castable_func = ThreadsafeCastableFunction::new(func);
 
@@ -464,7 +527,33 @@ where
             )),
         );
 
-        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+        Ok(
+            AsyncBindingWhenConfigurator::new(self.di_container, BindingOptions::new())
+                .with_replaced_binding(replaced_binding),
+        )
+    }
+
+    /// Returns the binding without a name that the new binding replaces when
+    /// rebinding.
+    fn take_replaced_binding(
+        &mut self,
+    ) -> Result<Option<ReplacedBinding>, AsyncBindingBuilderError>
+    {
+        if self.is_rebinding {
+            return Ok(ReplacedBinding::take::<Interface>(self.di_container));
+        }
+
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(AsyncBindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >(
+            )));
+        }
+
+        Ok(None)
     }
 }
 
@@ -476,6 +565,7 @@ mod tests
     use super::*;
     use crate::dependency_history::MockDependencyHistory;
     use crate::di_container::asynchronous::MockAsyncDIContainer;
+    use crate::provider::r#async::MockIAsyncProvider;
     use crate::test_utils::subjects_async;
 
     #[tokio::test]
@@ -504,6 +594,61 @@ mod tests
         binding_builder.to::<subjects_async::UserManager>().unwrap();
     }
 
+    #[tokio::test]
+    async fn can_rebind_to()
+    {
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_has_binding::<dyn subjects_async::IUserManager>()
+            .never();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects_async::IUserManager>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_options| Some(Box::new(MockIAsyncProvider::new())))
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::IUserManager>()
+            .withf(|binding_options, _provider| binding_options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_builder =
+            AsyncBindingBuilder::<dyn subjects_async::IUserManager>::new(
+                &mut di_container_mock,
+                MockDependencyHistory::new,
+            );
+
+        binding_builder
+            .rebinding()
+            .to::<subjects_async::UserManager>()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn can_bind_to_additional()
+    {
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::IUserManager>()
+            .withf(|binding_options, _provider| {
+                binding_options.name.is_none() && binding_options.additional_id.is_some()
+            })
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_builder =
+            AsyncBindingBuilder::<dyn subjects_async::IUserManager>::new(
+                &mut di_container_mock,
+                MockDependencyHistory::new,
+            );
+
+        binding_builder.to_additional::<subjects_async::UserManager>();
+    }
+
     #[tokio::test]
     #[cfg(feature = "factory")]
     async fn can_bind_to_factory()
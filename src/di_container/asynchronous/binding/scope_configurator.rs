@@ -3,13 +3,22 @@
 use std::any::type_name;
 use std::marker::PhantomData;
 
-use crate::di_container::asynchronous::binding::when_configurator::AsyncBindingWhenConfigurator;
+use crate::di_container::asynchronous::binding::when_configurator::{
+    AsyncBindingWhenConfigurator,
+    ReplacedBinding,
+};
 use crate::di_container::BindingOptions;
 use crate::errors::async_di_container::{AsyncBindingScopeConfiguratorError, AsyncDIContainerError};
 use crate::errors::injectable::InjectableError;
 use crate::errors::injectable::InjectableError::PrepareDependencyFailed;
 use crate::interfaces::async_injectable::AsyncInjectable;
-use crate::provider::r#async::{AsyncSingletonProvider, AsyncTransientTypeProvider};
+use crate::interfaces::async_lifecycle::{AsyncStartable, AsyncStoppable};
+use crate::provider::r#async::{
+    AsyncLazySingletonProvider,
+    AsyncLifecycleHooks,
+    AsyncSingletonProvider,
+    AsyncTransientTypeProvider,
+};
 use crate::ptr::ThreadsafeSingletonPtr;
 use crate::util::use_double;
 
@@ -24,6 +33,9 @@ where
 {
     di_container: &'di_container mut AsyncDIContainer,
     dependency_history_factory: fn() -> DependencyHistory,
+    binding_options: BindingOptions<'static>,
+    hooks: AsyncLifecycleHooks<Implementation>,
+    replaced_binding: Option<ReplacedBinding>,
 
     interface_phantom: PhantomData<Interface>,
     implementation_phantom: PhantomData<Implementation>,
@@ -38,16 +50,69 @@ where
     pub(crate) fn new(
         di_container: &'di_container mut AsyncDIContainer,
         dependency_history_factory: fn() -> DependencyHistory,
+        binding_options: BindingOptions<'static>,
     ) -> Self
     {
         Self {
             di_container,
             dependency_history_factory,
+            binding_options,
+            hooks: AsyncLifecycleHooks::default(),
+            replaced_binding: None,
             interface_phantom: PhantomData,
             implementation_phantom: PhantomData,
         }
     }
 
+    pub(crate) fn with_replaced_binding(
+        mut self,
+        replaced_binding: Option<ReplacedBinding>,
+    ) -> Self
+    {
+        self.replaced_binding = replaced_binding;
+
+        self
+    }
+
+    /// Makes the singleton of the binding be started by
+    /// [`AsyncDIContainer::start_all`].
+    ///
+    /// Must be called before the scope of the binding is configured. Only singletons &
+    /// lazy singletons are started.
+    ///
+    /// See [`AsyncDIContainer::start_all`] for a example.
+    ///
+    /// [`AsyncDIContainer::start_all`]: crate::AsyncDIContainer::start_all
+    #[must_use]
+    pub fn startable(mut self) -> Self
+    where
+        Implementation: AsyncStartable,
+    {
+        self.hooks.set_startable();
+
+        self
+    }
+
+    /// Makes the singleton of the binding be stopped by
+    /// [`AsyncDIContainer::stop_all`].
+    ///
+    /// Must be called before the scope of the binding is configured. Only singletons &
+    /// lazy singletons are stopped.
+    ///
+    /// See [`AsyncDIContainer::start_all`] for a example.
+    ///
+    /// [`AsyncDIContainer::stop_all`]: crate::AsyncDIContainer::stop_all
+    /// [`AsyncDIContainer::start_all`]: crate::AsyncDIContainer::start_all
+    #[must_use]
+    pub fn stoppable(mut self) -> Self
+    where
+        Implementation: AsyncStoppable,
+    {
+        self.hooks.set_stoppable();
+
+        self
+    }
+
     /// Configures the binding to be in a transient scope.
     ///
     /// This is the default.
@@ -86,7 +151,8 @@ where
     {
         self.set_in_transient_scope();
 
-        AsyncBindingWhenConfigurator::new(self.di_container)
+        AsyncBindingWhenConfigurator::new(self.di_container, self.binding_options)
+            .with_replaced_binding(self.replaced_binding)
     }
 
     /// Configures the binding to be in a singleton scope.
@@ -160,25 +226,107 @@ where
         AsyncBindingScopeConfiguratorError,
     >
     {
-        let singleton: ThreadsafeSingletonPtr<Implementation> =
-            ThreadsafeSingletonPtr::from(
-                Implementation::resolve(
-                    self.di_container,
-                    (self.dependency_history_factory)(),
-                )
-                .await
-                .map_err(AsyncBindingScopeConfiguratorError::SingletonResolveFailed)?,
-            );
+        let resolved = Implementation::resolve(
+            self.di_container,
+            (self.dependency_history_factory)(),
+        )
+        .await;
+
+        let singleton: ThreadsafeSingletonPtr<Implementation> = match resolved {
+            Ok(singleton) => ThreadsafeSingletonPtr::from(singleton),
+            Err(err) => {
+                self.restore_replaced_binding();
+
+                return Err(AsyncBindingScopeConfiguratorError::SingletonResolveFailed(
+                    err,
+                ));
+            }
+        };
 
         self.di_container.set_binding::<Interface>(
-            BindingOptions::new(),
-            Box::new(AsyncSingletonProvider::new(singleton)),
+            self.binding_options.clone(),
+            Box::new(AsyncSingletonProvider::new(singleton, self.hooks)),
         );
 
-        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+        Ok(
+            AsyncBindingWhenConfigurator::new(self.di_container, self.binding_options)
+                .with_replaced_binding(self.replaced_binding),
+        )
     }
 
 
+    /// Configures the binding to be in a lazy singleton scope.
+    ///
+    /// Unlike [`in_singleton_scope`], the implementation isn't resolved until the
+    /// binding is first resolved. It is then cached and shared by all later resolutions.
+    /// Concurrent first resolutions only ever build a single instance.
+    ///
+    /// Like with [`in_singleton_scope`], the dependencies of the implementation are
+    /// resolved by the DI container that has the binding, even when the binding is
+    /// first resolved through a child container.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::Arc;
+    /// #
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct SearchIndex {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl SearchIndex
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<SearchIndex>()
+    ///     .to::<SearchIndex>()?
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// // The search index is built here
+    /// let search_index = di_container
+    ///     .get::<SearchIndex>()
+    ///     .await?
+    ///     .threadsafe_singleton()?;
+    ///
+    /// let same_search_index = di_container
+    ///     .get::<SearchIndex>()
+    ///     .await?
+    ///     .threadsafe_singleton()?;
+    ///
+    /// assert!(Arc::ptr_eq(&search_index, &same_search_index));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`in_singleton_scope`]: Self::in_singleton_scope
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_lazy_singleton_scope(
+        self,
+    ) -> AsyncBindingWhenConfigurator<'di_container, Interface>
+    {
+        self.di_container.set_binding::<Interface>(
+            self.binding_options.clone(),
+            Box::new(
+                AsyncLazySingletonProvider::<Implementation, AsyncDIContainer>::new(
+                    self.hooks,
+                ),
+            ),
+        );
+
+        AsyncBindingWhenConfigurator::new(self.di_container, self.binding_options)
+            .with_replaced_binding(self.replaced_binding)
+    }
+
     /// Configures the binding to be in a singleton scope, from existing binding.
     ///
     /// # Errors
@@ -270,7 +418,7 @@ where
         AsyncBindingScopeConfiguratorError,
     >
     {
-        let singleton: ThreadsafeSingletonPtr<Implementation> =
+        let existing =
             self.di_container.get::<Implementation>()
             .await
             .map_err(|reason| AsyncBindingScopeConfiguratorError::SingletonResolveFailed(
@@ -279,33 +427,54 @@ where
                     reason: Box::new( reason )
 
                 }
-            ))?
-            .threadsafe_singleton()
+            ))
+            .and_then(|existing| existing.threadsafe_singleton()
             .map_err(|reason| AsyncBindingScopeConfiguratorError::SingletonResolveFailed(
                 InjectableError::AsyncResolveFailed {
                     affected: type_name::<Implementation>(),
                     reason: Box::new( AsyncDIContainerError::SingletonPtrNotFound (reason, type_name::<Implementation>()))
 
                 }
-            ))?;
+            )));
+
+        let singleton: ThreadsafeSingletonPtr<Implementation> = match existing {
+            Ok(singleton) => singleton,
+            Err(err) => {
+                self.restore_replaced_binding();
+
+                return Err(err);
+            }
+        };
 
         self.di_container.set_binding::<Interface>(
-            BindingOptions::new(),
-            Box::new(AsyncSingletonProvider::new(singleton)),
+            self.binding_options.clone(),
+            Box::new(AsyncSingletonProvider::new(singleton, self.hooks)),
         );
 
-        Ok(AsyncBindingWhenConfigurator::new(self.di_container))
+        Ok(
+            AsyncBindingWhenConfigurator::new(self.di_container, self.binding_options)
+                .with_replaced_binding(self.replaced_binding),
+        )
     }
 
     pub(crate) fn set_in_transient_scope(&mut self)
     {
         self.di_container.set_binding::<Interface>(
-            BindingOptions::new(),
+            self.binding_options.clone(),
             Box::new(
                 AsyncTransientTypeProvider::<Implementation, AsyncDIContainer>::new(),
             ),
         );
     }
+
+    /// Puts back the binding that a rebound binding replaced, as the rebound binding
+    /// couldn't be configured.
+    fn restore_replaced_binding(self)
+    {
+        if let Some(replaced_binding) = self.replaced_binding {
+            replaced_binding.restore::<Interface>(self.di_container);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -331,7 +500,11 @@ mod tests
             AsyncBindingScopeConfigurator::<
                 dyn subjects_async::IUserManager,
                 subjects_async::UserManager,
-            >::new(&mut di_container_mock, MockDependencyHistory::new);
+            >::new(
+                &mut di_container_mock,
+                MockDependencyHistory::new,
+                BindingOptions::new(),
+            );
 
         binding_scope_configurator.in_transient_scope();
     }
@@ -351,11 +524,39 @@ mod tests
             AsyncBindingScopeConfigurator::<
                 dyn subjects_async::IUserManager,
                 subjects_async::UserManager,
-            >::new(&mut di_container_mock, MockDependencyHistory::new);
+            >::new(
+                &mut di_container_mock,
+                MockDependencyHistory::new,
+                BindingOptions::new(),
+            );
 
         assert!(binding_scope_configurator
             .in_singleton_scope()
             .await
             .is_ok());
     }
+
+    #[tokio::test]
+    async fn in_lazy_singleton_scope_works()
+    {
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::IUserManager>()
+            .withf(|binding_options, _provider| binding_options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_scope_configurator =
+            AsyncBindingScopeConfigurator::<
+                dyn subjects_async::IUserManager,
+                subjects_async::UserManager,
+            >::new(
+                &mut di_container_mock,
+                MockDependencyHistory::new,
+                BindingOptions::new(),
+            );
+
+        binding_scope_configurator.in_lazy_singleton_scope();
+    }
 }
@@ -1,19 +1,53 @@
 //! When configurator for a binding for types inside of a [`AsyncDIContainer`].
 use std::any::type_name;
+use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
-use crate::di_container::BindingOptions;
+use crate::di_container::resolution_context::{BindingCondition, ResolutionContext};
+use crate::di_container::{BindingKey, BindingOptions};
 use crate::errors::async_di_container::AsyncBindingWhenConfiguratorError;
+use crate::provider::r#async::IAsyncProvider;
 use crate::util::use_double;
 
 use_double!(crate::di_container::asynchronous::AsyncDIContainer);
 
+/// The binding without a name that a rebound binding replaced. It's put back if the
+/// rebound binding is given a name, tags or a condition, as the rebound binding then
+/// doesn't replace it.
+pub(crate) struct ReplacedBinding
+{
+    provider: Box<dyn IAsyncProvider<AsyncDIContainer>>,
+}
+
+impl ReplacedBinding
+{
+    /// Removes the binding of `Interface` without a name from the DI container.
+    pub(crate) fn take<Interface>(di_container: &mut AsyncDIContainer) -> Option<Self>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let provider = di_container.remove_binding::<Interface>(BindingOptions::new())?;
+
+        Some(Self { provider })
+    }
+
+    pub(crate) fn restore<Interface>(self, di_container: &mut AsyncDIContainer)
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        di_container.set_binding::<Interface>(BindingOptions::new(), self.provider);
+    }
+}
+
 /// When configurator for a binding for type `Interface` inside a [`AsyncDIContainer`].
 pub struct AsyncBindingWhenConfigurator<'di_container, Interface>
 where
     Interface: 'static + ?Sized + Send + Sync,
 {
     di_container: &'di_container mut AsyncDIContainer,
+    binding_options: BindingOptions<'static>,
+    replaced_binding: Option<ReplacedBinding>,
 
     interface_phantom: PhantomData<Interface>,
 }
@@ -22,15 +56,31 @@ impl<'di_container, Interface> AsyncBindingWhenConfigurator<'di_container, Inter
 where
     Interface: 'static + ?Sized + Send + Sync,
 {
-    pub(crate) fn new(di_container: &'di_container mut AsyncDIContainer) -> Self
+    pub(crate) fn new(
+        di_container: &'di_container mut AsyncDIContainer,
+        binding_options: BindingOptions<'static>,
+    ) -> Self
     {
         Self {
             di_container,
+            binding_options,
+            replaced_binding: None,
             interface_phantom: PhantomData,
         }
     }
 
-    /// Configures the binding to have a name.
+    pub(crate) fn with_replaced_binding(
+        mut self,
+        replaced_binding: Option<ReplacedBinding>,
+    ) -> Self
+    {
+        self.replaced_binding = replaced_binding;
+
+        self
+    }
+
+    /// Configures the binding to have a name. The name can be a `&'static str` or a
+    /// `String` created at runtime.
     ///
     /// # Errors
     /// Will return Err if no binding for the interface already exists.
@@ -64,13 +114,13 @@ where
     /// # }
     /// ```
     pub fn when_named(
-        self,
-        name: &'static str,
+        mut self,
+        name: impl Into<Cow<'static, str>>,
     ) -> Result<(), AsyncBindingWhenConfiguratorError>
     {
         let binding = self
             .di_container
-            .remove_binding::<Interface>(BindingOptions::new())
+            .remove_binding::<Interface>(self.binding_options.clone())
             .map_or_else(
                 || {
                     Err(AsyncBindingWhenConfiguratorError::BindingNotFound(
@@ -80,11 +130,400 @@ where
                 Ok,
             )?;
 
+        self.restore_replaced_binding();
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.name(name), binding);
+
+        Ok(())
+    }
+
+    /// Configures the binding to have a binding key.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::BindingKey;
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # struct Kitten {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Kitten
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// struct Billy;
+    ///
+    /// impl BindingKey for Billy {}
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<Kitten>()
+    ///     .to::<Kitten>()?
+    ///     .in_transient_scope()
+    ///     .when_keyed::<Billy>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_keyed<Key>(mut self) -> Result<(), AsyncBindingWhenConfiguratorError>
+    where
+        Key: BindingKey,
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                AsyncBindingWhenConfiguratorError::BindingNotFound(
+                    type_name::<Interface>(),
+                )
+            })?;
+
+        self.restore_replaced_binding();
+
         self.di_container
-            .set_binding::<Interface>(BindingOptions::new().name(name), binding);
+            .set_binding::<Interface>(self.binding_options.keyed::<Key>(), binding);
 
         Ok(())
     }
+
+    /// Configures the binding to have a tag. Can be called multiple times to give the
+    /// binding several tags.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait IDatabase: Send + Sync {}
+    /// #
+    /// # struct EuReplica {}
+    /// #
+    /// # #[injectable(IDatabase, async = true)]
+    /// # impl EuReplica
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IDatabase for EuReplica {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IDatabase>()
+    ///     .to::<EuReplica>()?
+    ///     .in_transient_scope()
+    ///     .when_tagged("region", "eu")?
+    ///     .when_tagged("access", "read_only")?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_tagged(
+        mut self,
+        key: &'static str,
+        value: &'static str,
+    ) -> Result<Self, AsyncBindingWhenConfiguratorError>
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                AsyncBindingWhenConfiguratorError::BindingNotFound(
+                    type_name::<Interface>(),
+                )
+            })?;
+
+        self.restore_replaced_binding();
+
+        self.binding_options = self.binding_options.tag(key, value);
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.clone(), binding);
+
+        Ok(self)
+    }
+
+    /// Configures the binding to only be used when injected into a `Consumer`.
+    ///
+    /// A binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IWeapon: Send + Sync {}
+    /// #
+    /// # struct Katana {}
+    /// #
+    /// # #[injectable(IWeapon, async = true)]
+    /// # impl Katana
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IWeapon for Katana {}
+    /// #
+    /// # struct Ninja {}
+    /// #
+    /// # #[injectable(async = true)]
+    /// # impl Ninja
+    /// # {
+    /// #     fn new(weapon: TransientPtr<dyn IWeapon>) -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IWeapon>()
+    ///     .to::<Katana>()?
+    ///     .in_transient_scope()
+    ///     .when_injected_into::<Ninja>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    // A path to the method isn't general enough over the lifetime of the context
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn when_injected_into<Consumer>(
+        self,
+    ) -> Result<(), AsyncBindingWhenConfiguratorError>
+    where
+        Consumer: 'static + ?Sized,
+    {
+        self.set_condition(Arc::new(|context| context.is_injected_into::<Consumer>()))
+    }
+
+    /// Configures the binding to only be used when a `Ancestor` is being built somewhere
+    /// above it.
+    ///
+    /// A binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait ILogger: Send + Sync {}
+    /// #
+    /// # struct AuditLogger {}
+    /// #
+    /// # #[injectable(ILogger, async = true)]
+    /// # impl AuditLogger
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl ILogger for AuditLogger {}
+    /// #
+    /// # struct PaymentService {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn ILogger>()
+    ///     .to::<AuditLogger>()?
+    ///     .in_transient_scope()
+    ///     .when_any_ancestor_is::<PaymentService>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    // A path to the method isn't general enough over the lifetime of the context
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn when_any_ancestor_is<Ancestor>(
+        self,
+    ) -> Result<(), AsyncBindingWhenConfiguratorError>
+    where
+        Ancestor: 'static + ?Sized,
+    {
+        self.set_condition(Arc::new(|context| context.has_ancestor::<Ancestor>()))
+    }
+
+    /// Configures the binding to only be used when injected into something resolved
+    /// from a binding with the specified name.
+    ///
+    /// A binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait IConnection: Send + Sync {}
+    /// #
+    /// # struct ReplicaConnection {}
+    /// #
+    /// # #[injectable(IConnection, async = true)]
+    /// # impl ReplicaConnection
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IConnection for ReplicaConnection {}
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IConnection>()
+    ///     .to::<ReplicaConnection>()?
+    ///     .in_transient_scope()
+    ///     .when_parent_named("read_only")?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_parent_named(
+        self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<(), AsyncBindingWhenConfiguratorError>
+    {
+        let name = name.into();
+
+        self.set_condition(Arc::new(move |context| {
+            context.parent_name() == Some(name.as_ref())
+        }))
+    }
+
+    /// Configures the binding to only be used when `predicate` returns `true` for the
+    /// context it is resolved in.
+    ///
+    /// The predicate is evaluated every time the binding is about to be resolved. A
+    /// binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// #
+    /// # use syrette::{AsyncDIContainer, injectable};
+    /// #
+    /// # trait IRenderer: Send + Sync {}
+    /// #
+    /// # struct VulkanRenderer {}
+    /// #
+    /// # #[injectable(IRenderer, async = true)]
+    /// # impl VulkanRenderer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IRenderer for VulkanRenderer {}
+    /// #
+    /// static USE_VULKAN: AtomicBool = AtomicBool::new(false);
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = AsyncDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IRenderer>()
+    ///     .to::<VulkanRenderer>()?
+    ///     .in_transient_scope()
+    ///     .when(|_context| USE_VULKAN.load(Ordering::Relaxed))?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when<Predicate>(
+        self,
+        predicate: Predicate,
+    ) -> Result<(), AsyncBindingWhenConfiguratorError>
+    where
+        Predicate: Fn(&ResolutionContext<'_>) -> bool + Send + Sync + 'static,
+    {
+        self.set_condition(Arc::new(predicate))
+    }
+
+    fn set_condition(
+        mut self,
+        condition: BindingCondition,
+    ) -> Result<(), AsyncBindingWhenConfiguratorError>
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                AsyncBindingWhenConfiguratorError::BindingNotFound(
+                    type_name::<Interface>(),
+                )
+            })?;
+
+        self.restore_replaced_binding();
+
+        // Bindings with a condition don't replace each other nor the binding without a
+        // condition
+        let binding_options = self.binding_options.additional();
+
+        self.di_container
+            .set_binding::<Interface>(binding_options.clone(), binding);
+
+        self.di_container
+            .set_binding_condition::<Interface>(binding_options, condition);
+
+        Ok(())
+    }
+
+    /// Puts back the binding that the binding replaced, as the binding no longer
+    /// replaces it once it's moved away from having no name, tags or condition.
+    fn restore_replaced_binding(&mut self)
+    {
+        if let Some(replaced_binding) = self.replaced_binding.take() {
+            replaced_binding.restore::<Interface>(self.di_container);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +532,7 @@ mod tests
     use mockall::predicate::eq;
 
     use super::*;
+    use crate::dependency_history::DependencyHistory;
     use crate::di_container::asynchronous::MockAsyncDIContainer;
     use crate::provider::r#async::MockIAsyncProvider;
     use crate::test_utils::subjects_async;
@@ -110,14 +550,90 @@ mod tests
 
         di_container_mock
             .expect_set_binding::<dyn subjects_async::INumber>()
-            .withf(|binding_options, _provider| binding_options.name == Some("awesome"))
+            .withf(|binding_options, _provider| {
+                binding_options.name.as_deref() == Some("awesome")
+            })
             .return_once(|_name, _provider| ())
             .once();
 
         let binding_when_configurator = AsyncBindingWhenConfigurator::<
             dyn subjects_async::INumber,
-        >::new(&mut di_container_mock);
+        >::new(&mut di_container_mock, BindingOptions::new());
 
         assert!(binding_when_configurator.when_named("awesome").is_ok());
     }
+
+    #[tokio::test]
+    async fn when_tagged_works()
+    {
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects_async::INumber>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_name| Some(Box::new(MockIAsyncProvider::new())))
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::INumber>()
+            .with(
+                eq(BindingOptions::new().tag("region", "eu")),
+                mockall::predicate::always(),
+            )
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_when_configurator = AsyncBindingWhenConfigurator::<
+            dyn subjects_async::INumber,
+        >::new(&mut di_container_mock, BindingOptions::new());
+
+        assert!(binding_when_configurator
+            .when_tagged("region", "eu")
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn when_any_ancestor_is_works()
+    {
+        let mut di_container_mock = MockAsyncDIContainer::new();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects_async::INumber>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_name| Some(Box::new(MockIAsyncProvider::new())))
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects_async::INumber>()
+            .withf(|binding_options, _provider| {
+                binding_options.name.is_none() && binding_options.additional_id.is_some()
+            })
+            .return_once(|_name, _provider| ())
+            .once();
+
+        di_container_mock
+            .expect_set_binding_condition::<dyn subjects_async::INumber>()
+            .withf(|binding_options, condition| {
+                let mut dependency_history = DependencyHistory::new();
+
+                dependency_history.push::<subjects_async::UserManager>();
+                dependency_history.push::<subjects_async::Number>();
+
+                binding_options.additional_id.is_some()
+                    && condition(&ResolutionContext::new(
+                        &dependency_history,
+                        &BindingOptions::new(),
+                    ))
+            })
+            .return_once(|_name, _condition| ())
+            .once();
+
+        let binding_when_configurator = AsyncBindingWhenConfigurator::<
+            dyn subjects_async::INumber,
+        >::new(&mut di_container_mock, BindingOptions::new());
+
+        assert!(binding_when_configurator
+            .when_any_ancestor_is::<subjects_async::UserManager>()
+            .is_ok());
+    }
 }
@@ -0,0 +1,97 @@
+//! Builder of frozen [`AsyncDIContainer`]s.
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::di_container::asynchronous::binding::builder::AsyncBindingBuilder;
+use crate::di_container::asynchronous::AsyncDIContainer;
+use crate::errors::build::BuildError;
+
+/// Collects bindings and builds a [`FrozenAsyncDIContainer`] out of them. Created with
+/// [`AsyncDIContainer::builder`].
+#[derive(Default)]
+pub struct AsyncDIContainerBuilder
+{
+    di_container: AsyncDIContainer,
+}
+
+impl AsyncDIContainerBuilder
+{
+    /// Returns a new `AsyncDIContainerBuilder`.
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self {
+            di_container: AsyncDIContainer::new(),
+        }
+    }
+
+    /// Returns a new [`AsyncBindingBuilder`] for the given interface.
+    ///
+    /// See [`AsyncDIContainer::bind`].
+    pub fn bind<Interface>(&mut self) -> AsyncBindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.di_container.bind::<Interface>()
+    }
+
+    /// Returns a new [`AsyncBindingBuilder`] for the given interface that replaces the
+    /// existing binding of the interface without a name instead of failing.
+    ///
+    /// See [`AsyncDIContainer::rebind`].
+    pub fn rebind<Interface>(&mut self) -> AsyncBindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.di_container.rebind::<Interface>()
+    }
+
+    /// Builds a [`FrozenAsyncDIContainer`] out of the collected bindings.
+    ///
+    /// The bindings are validated and the singletons of the bindings in a lazy
+    /// singleton scope are resolved before the container is handed back.
+    ///
+    /// # Errors
+    /// Will return `Err` if the bindings are invalid or if resolving a lazy singleton
+    /// fails.
+    pub async fn build(self) -> Result<FrozenAsyncDIContainer, BuildError>
+    {
+        self.di_container.validate()?;
+
+        self.di_container.resolve_lazy_singletons().await?;
+
+        Ok(FrozenAsyncDIContainer {
+            di_container: Arc::new(self.di_container),
+        })
+    }
+}
+
+/// Cheaply clonable handle to an [`AsyncDIContainer`] that can no longer be modified.
+/// Created with [`AsyncDIContainerBuilder::build`].
+#[derive(Clone)]
+pub struct FrozenAsyncDIContainer
+{
+    di_container: Arc<AsyncDIContainer>,
+}
+
+impl FrozenAsyncDIContainer
+{
+    /// Returns a new child [`AsyncDIContainer`] of the container.
+    ///
+    /// See [`AsyncDIContainer::create_child`].
+    #[must_use]
+    pub fn create_child(&self) -> AsyncDIContainer
+    {
+        self.di_container.create_child()
+    }
+}
+
+impl Deref for FrozenAsyncDIContainer
+{
+    type Target = AsyncDIContainer;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.di_container
+    }
+}
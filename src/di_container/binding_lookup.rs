@@ -0,0 +1,84 @@
+//! Finding bindings of DI containers without resolving them.
+use crate::dependency::{DependencyInfo, DependencyKind};
+use crate::dependency_history::DependencyHistory;
+use crate::di_container::binding_storage::{DIContainerBindingStorage, StoredBinding};
+
+/// Finds the bindings of a DI container and of it's ancestors the same way as they are
+/// found when resolving.
+pub(crate) struct BindingLookup<'storage, Provider>
+where
+    Provider: 'static + ?Sized,
+{
+    /// The binding storages of the DI container and of it's ancestors, starting with the
+    /// DI container's own.
+    storages: Vec<&'storage DIContainerBindingStorage<Provider>>,
+}
+
+impl<'storage, Provider> BindingLookup<'storage, Provider>
+where
+    Provider: 'static + ?Sized,
+{
+    pub(crate) fn new(
+        storages: Vec<&'storage DIContainerBindingStorage<Provider>>,
+    ) -> Self
+    {
+        Self { storages }
+    }
+
+    /// Returns the bindings of all of the binding storages.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = StoredBinding<'storage, Provider>>
+    {
+        self.storages
+            .clone()
+            .into_iter()
+            .flat_map(DIContainerBindingStorage::iter)
+    }
+
+    /// Returns the bindings matching a dependency in the nearest DI container that has
+    /// any.
+    pub(crate) fn find(
+        &self,
+        dependency: &DependencyInfo,
+        dependency_history: &DependencyHistory,
+    ) -> Vec<StoredBinding<'storage, Provider>>
+    {
+        let request = dependency.binding_options();
+
+        self.storages
+            .iter()
+            .copied()
+            .map(|storage| {
+                storage.find_matching_bindings(
+                    dependency.interface_id(),
+                    request,
+                    |condition| dependency_history.satisfies(condition, request),
+                )
+            })
+            .find(|bindings| !bindings.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Returns the bindings that a collection dependency is resolved from.
+    pub(crate) fn find_all(
+        &self,
+        dependency: &DependencyInfo,
+        dependency_history: &DependencyHistory,
+    ) -> Vec<StoredBinding<'storage, Provider>>
+    {
+        self.iter()
+            .filter(|binding| binding.interface_id == dependency.interface_id())
+            .filter(|binding| {
+                if dependency.kind() == DependencyKind::All {
+                    binding.options.is_unqualified()
+                } else {
+                    binding.options.name.is_some() && binding.options.tags.is_empty()
+                }
+            })
+            .filter(|binding| {
+                binding.condition.map_or(true, |condition| {
+                    dependency_history.satisfies(condition, binding.options)
+                })
+            })
+            .collect()
+    }
+}
@@ -1,14 +1,23 @@
-use std::any::TypeId;
+use std::any::{type_name, TypeId};
 
 use ahash::AHashMap;
 
+use crate::di_container::resolution_context::BindingCondition;
 use crate::di_container::BindingOptions;
+use crate::private::cast::can_cast;
 
 pub struct DIContainerBindingStorage<Provider>
 where
     Provider: 'static + ?Sized,
 {
-    inner: AHashMap<BindingId<'static>, Box<Provider>>,
+    // The options are stored next to the provider as well, so that the options of a
+    // binding found by key outlive the request
+    inner: AHashMap<BindingId<'static>, (BindingOptions<'static>, Box<Provider>)>,
+    registration_order: Vec<BindingId<'static>>,
+    interface_bindings: AHashMap<TypeId, Vec<BindingId<'static>>>,
+    conditions: AHashMap<BindingId<'static>, BindingCondition>,
+    conditional_bindings: AHashMap<TypeId, Vec<BindingId<'static>>>,
+    interfaces: AHashMap<TypeId, BoundInterface>,
 }
 
 impl<Provider> DIContainerBindingStorage<Provider>
@@ -19,9 +28,15 @@ where
     {
         Self {
             inner: AHashMap::new(),
+            registration_order: Vec::new(),
+            interface_bindings: AHashMap::new(),
+            conditions: AHashMap::new(),
+            conditional_bindings: AHashMap::new(),
+            interfaces: AHashMap::new(),
         }
     }
 
+    #[cfg(test)]
     #[allow(clippy::borrowed_box)]
     pub fn get<'this, Interface>(
         &'this self,
@@ -30,7 +45,153 @@ where
     where
         Interface: 'static + ?Sized,
     {
-        self.inner.get(&BindingId::new::<Interface>(options))
+        self.inner
+            .get(&BindingId::new::<Interface>(options))
+            .map(|(_, provider)| provider)
+    }
+
+    /// Returns the options & providers of the bindings that can be used to resolve a
+    /// request. Bindings with a satisfied condition take precedence over the ones
+    /// without a condition.
+    pub fn find_matching<'this, Interface>(
+        &'this self,
+        request: &BindingOptions<'this>,
+        is_satisfied: impl Fn(&BindingCondition) -> bool,
+    ) -> Vec<(&'this BindingOptions<'static>, &'this Provider)>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.find_matching_by_type_id(TypeId::of::<Interface>(), request, is_satisfied)
+    }
+
+    /// Returns the options & provider of the binding that a request is resolved with.
+    /// Fails with the number of matching bindings when more than one binding matches.
+    pub fn find_single<'this, Interface>(
+        &'this self,
+        request: &BindingOptions<'this>,
+        is_satisfied: impl Fn(&BindingCondition) -> bool,
+    ) -> Result<Option<(&'this BindingOptions<'static>, &'this Provider)>, usize>
+    where
+        Interface: 'static + ?Sized,
+    {
+        match self
+            .find_matching::<Interface>(request, is_satisfied)
+            .as_slice()
+        {
+            [] => Ok(None),
+            [binding] => Ok(Some(*binding)),
+            bindings => Err(bindings.len()),
+        }
+    }
+
+    pub fn find_matching_by_type_id<'this>(
+        &'this self,
+        type_id: TypeId,
+        request: &BindingOptions<'this>,
+        is_satisfied: impl Fn(&BindingCondition) -> bool,
+    ) -> Vec<(&'this BindingOptions<'static>, &'this Provider)>
+    {
+        let conditional_bindings = self.find_conditional(type_id, request, is_satisfied);
+
+        if !conditional_bindings.is_empty() {
+            return conditional_bindings
+                .into_iter()
+                .map(|binding| (binding.options, binding.provider))
+                .collect();
+        }
+
+        if request.tags.is_empty() {
+            return self
+                .inner
+                .get(&BindingId {
+                    type_id,
+                    options: request.clone(),
+                })
+                .map(|(options, provider)| (options, provider.as_ref()))
+                .into_iter()
+                .collect();
+        }
+
+        self.find_unconditional(type_id, request)
+            .into_iter()
+            .map(|binding| (binding.options, binding.provider))
+            .collect()
+    }
+
+    /// Returns the bindings that can be used to resolve a request. Finds the same
+    /// bindings as [`Self::find_matching_by_type_id`] but without using the faster
+    /// lookup by key.
+    pub fn find_matching_bindings<'this>(
+        &'this self,
+        type_id: TypeId,
+        request: &BindingOptions,
+        is_satisfied: impl Fn(&BindingCondition) -> bool,
+    ) -> Vec<StoredBinding<'this, Provider>>
+    {
+        let conditional_bindings = self.find_conditional(type_id, request, is_satisfied);
+
+        if !conditional_bindings.is_empty() {
+            return conditional_bindings;
+        }
+
+        if request.tags.is_empty() {
+            return self.find(type_id, |options, _| options == request);
+        }
+
+        self.find_unconditional(type_id, request)
+    }
+
+    pub fn get_condition<Interface>(
+        &self,
+        options: &BindingOptions<'static>,
+    ) -> Option<&BindingCondition>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.conditions
+            .get(&BindingId::new::<Interface>(options.clone()))
+    }
+
+    /// Returns all bindings in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = StoredBinding<'_, Provider>>
+    {
+        self.registration_order
+            .iter()
+            .filter_map(|binding_id| self.get_stored(binding_id))
+    }
+
+    /// Returns the options & providers of the bindings of `Interface` that either have
+    /// a satisfied condition or no condition, in the order they were registered.
+    pub fn get_all_satisfied<Interface>(
+        &self,
+        is_satisfied: impl Fn(&BindingCondition, &BindingOptions<'static>) -> bool,
+    ) -> Vec<(&BindingOptions<'static>, &Provider)>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_all::<Interface>()
+            .into_iter()
+            .filter(|(options, _)| {
+                self.get_condition::<Interface>(options)
+                    .map_or(true, |condition| is_satisfied(condition, options))
+            })
+            .collect()
+    }
+
+    pub fn get_all<Interface>(&self) -> Vec<(&BindingOptions<'static>, &Provider)>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.interface_bindings
+            .get(&TypeId::of::<Interface>())
+            .into_iter()
+            .flatten()
+            .filter_map(|binding_id| {
+                self.inner
+                    .get(binding_id)
+                    .map(|(_, provider)| (&binding_id.options, provider.as_ref()))
+            })
+            .collect()
     }
 
     pub fn set<Interface>(
@@ -40,8 +201,47 @@ where
     ) where
         Interface: 'static + ?Sized,
     {
-        self.inner
-            .insert(BindingId::new::<Interface>(options), provider);
+        let binding_id = BindingId::new::<Interface>(options);
+
+        self.remove_condition(&binding_id);
+
+        self.interfaces
+            .entry(binding_id.type_id)
+            .or_insert_with(BoundInterface::new::<Interface>);
+
+        if self
+            .inner
+            .insert(binding_id.clone(), (binding_id.options.clone(), provider))
+            .is_none()
+        {
+            self.interface_bindings
+                .entry(binding_id.type_id)
+                .or_default()
+                .push(binding_id.clone());
+
+            self.registration_order.push(binding_id);
+        }
+    }
+
+    pub fn set_condition<Interface>(
+        &mut self,
+        options: BindingOptions<'static>,
+        condition: BindingCondition,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        let binding_id = BindingId::new::<Interface>(options);
+
+        if self
+            .conditions
+            .insert(binding_id.clone(), condition)
+            .is_none()
+        {
+            self.conditional_bindings
+                .entry(binding_id.type_id)
+                .or_default()
+                .push(binding_id);
+        }
     }
 
     pub fn remove<Interface>(
@@ -51,7 +251,114 @@ where
     where
         Interface: 'static + ?Sized,
     {
-        self.inner.remove(&BindingId::new::<Interface>(options))
+        let binding_id = BindingId::new::<Interface>(options);
+
+        self.registration_order
+            .retain(|registered_binding_id| *registered_binding_id != binding_id);
+
+        if let Some(interface_bindings) =
+            self.interface_bindings.get_mut(&binding_id.type_id)
+        {
+            interface_bindings
+                .retain(|interface_binding_id| *interface_binding_id != binding_id);
+        }
+
+        self.remove_condition(&binding_id);
+
+        self.inner.remove(&binding_id).map(|(_, provider)| provider)
+    }
+
+    /// Removes all bindings, returning their providers in the order they were
+    /// registered in.
+    pub fn take_all(&mut self) -> Vec<Box<Provider>>
+    {
+        self.interface_bindings.clear();
+        self.conditions.clear();
+        self.conditional_bindings.clear();
+
+        self.registration_order
+            .drain(..)
+            .filter_map(|binding_id| self.inner.remove(&binding_id))
+            .map(|(_, provider)| provider)
+            .collect()
+    }
+
+    fn get_stored<'this>(
+        &'this self,
+        binding_id: &'this BindingId<'static>,
+    ) -> Option<StoredBinding<'this, Provider>>
+    {
+        Some(StoredBinding {
+            interface_id: binding_id.type_id,
+            interface: self.interfaces.get(&binding_id.type_id)?,
+            options: &binding_id.options,
+            condition: self.conditions.get(binding_id),
+            provider: self.inner.get(binding_id)?.1.as_ref(),
+        })
+    }
+
+    fn remove_condition(&mut self, binding_id: &BindingId<'static>)
+    {
+        if self.conditions.remove(binding_id).is_none() {
+            return;
+        }
+
+        if let Some(conditional_bindings) =
+            self.conditional_bindings.get_mut(&binding_id.type_id)
+        {
+            conditional_bindings
+                .retain(|conditional_binding_id| conditional_binding_id != binding_id);
+        }
+    }
+
+    fn find_conditional(
+        &self,
+        type_id: TypeId,
+        request: &BindingOptions,
+        is_satisfied: impl Fn(&BindingCondition) -> bool,
+    ) -> Vec<StoredBinding<'_, Provider>>
+    {
+        // Only the bindings of the interface that have a condition are looked at, so
+        // that requests of interfaces without any stay a single lookup by key
+        self.conditional_bindings
+            .get(&type_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|binding_id| self.get_stored(binding_id))
+            .filter(|binding| {
+                binding.condition.map_or(false, |condition| {
+                    binding.options.matches(request) && is_satisfied(condition)
+                })
+            })
+            .collect()
+    }
+
+    fn find_unconditional(
+        &self,
+        type_id: TypeId,
+        request: &BindingOptions,
+    ) -> Vec<StoredBinding<'_, Provider>>
+    {
+        self.find(type_id, |options, condition| {
+            condition.is_none()
+                && options.additional_id.is_none()
+                && options.matches(request)
+        })
+    }
+
+    fn find(
+        &self,
+        type_id: TypeId,
+        is_match: impl Fn(&BindingOptions<'static>, Option<&BindingCondition>) -> bool,
+    ) -> Vec<StoredBinding<'_, Provider>>
+    {
+        self.interface_bindings
+            .get(&type_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|binding_id| self.get_stored(binding_id))
+            .filter(|binding| is_match(binding.options, binding.condition))
+            .collect()
     }
 
     pub fn has<Interface>(&self, options: BindingOptions) -> bool
@@ -73,7 +380,43 @@ where
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+/// A interface that bindings exist for.
+#[derive(Debug, Clone, Copy)]
+pub struct BoundInterface
+{
+    pub name: &'static str,
+
+    /// Returns whether or not a injectable with the given type ID can be cast to the
+    /// interface.
+    pub is_castable_from: fn(TypeId) -> bool,
+}
+
+impl BoundInterface
+{
+    fn new<Interface>() -> Self
+    where
+        Interface: 'static + ?Sized,
+    {
+        Self {
+            name: type_name::<Interface>(),
+            is_castable_from: can_cast::<Interface>,
+        }
+    }
+}
+
+/// A binding inside of a [`DIContainerBindingStorage`].
+pub struct StoredBinding<'storage, Provider>
+where
+    Provider: 'static + ?Sized,
+{
+    pub interface_id: TypeId,
+    pub interface: &'storage BoundInterface,
+    pub options: &'storage BindingOptions<'static>,
+    pub condition: Option<&'storage BindingCondition>,
+    pub provider: &'storage Provider,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct BindingId<'opts>
 {
     type_id: TypeId,
@@ -96,7 +439,12 @@ impl<'opts> BindingId<'opts>
 #[cfg(test)]
 mod tests
 {
+    use std::cell::Cell;
+    use std::sync::Arc;
+
     use super::*;
+    use crate::dependency_history::DependencyHistory;
+    use crate::di_container::resolution_context::ResolutionContext;
 
     mod subjects
     {
@@ -129,7 +477,10 @@ mod tests
 
         binding_map.inner.insert(
             BindingId::new::<Interface>(BindingOptions::new()),
-            Box::new(subjects::SomeProviderImpl { id: 20 }),
+            (
+                BindingOptions::new(),
+                Box::new(subjects::SomeProviderImpl { id: 20 }),
+            ),
         );
 
         assert!(binding_map
@@ -147,7 +498,10 @@ mod tests
 
         binding_map.inner.insert(
             BindingId::new::<Interface>(BindingOptions::new().name("hello")),
-            Box::new(subjects::SomeProviderImpl { id: 11 }),
+            (
+                BindingOptions::new().name("hello"),
+                Box::new(subjects::SomeProviderImpl { id: 11 }),
+            ),
         );
 
         assert!(binding_map
@@ -176,7 +530,7 @@ mod tests
 
         assert!(binding_map.inner.contains_key(&expected_key));
 
-        assert_eq!(binding_map.inner[&expected_key].get_id(), 65);
+        assert_eq!(binding_map.inner[&expected_key].1.get_id(), 65);
     }
 
     #[test]
@@ -197,7 +551,7 @@ mod tests
 
         assert!(binding_map.inner.contains_key(&expected_key));
 
-        assert_eq!(binding_map.inner[&expected_key].get_id(), 3);
+        assert_eq!(binding_map.inner[&expected_key].1.get_id(), 3);
     }
 
     #[test]
@@ -210,7 +564,10 @@ mod tests
 
         binding_map.inner.insert(
             BindingId::new::<Interface>(BindingOptions::new()),
-            Box::new(subjects::SomeProviderImpl { id: 103 }),
+            (
+                BindingOptions::new(),
+                Box::new(subjects::SomeProviderImpl { id: 103 }),
+            ),
         );
 
         binding_map.remove::<Interface>(BindingOptions::new());
@@ -230,7 +587,10 @@ mod tests
 
         binding_map.inner.insert(
             BindingId::new::<Interface>(BindingOptions::new().name("cool")),
-            Box::new(subjects::SomeProviderImpl { id: 42 }),
+            (
+                BindingOptions::new().name("cool"),
+                Box::new(subjects::SomeProviderImpl { id: 42 }),
+            ),
         );
 
         binding_map.remove::<Interface>(BindingOptions::new().name("cool"));
@@ -242,6 +602,44 @@ mod tests
         );
     }
 
+    #[test]
+    fn can_get_all()
+    {
+        type Interface = ();
+
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<Interface>(
+            BindingOptions::new().additional(),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        binding_map.set::<u8>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 2 }),
+        );
+
+        binding_map.set::<Interface>(
+            BindingOptions::new().name("cool"),
+            Box::new(subjects::SomeProviderImpl { id: 3 }),
+        );
+
+        binding_map.set::<Interface>(
+            BindingOptions::new().additional(),
+            Box::new(subjects::SomeProviderImpl { id: 4 }),
+        );
+
+        assert_eq!(
+            binding_map
+                .get_all::<Interface>()
+                .into_iter()
+                .map(|(_, provider)| provider.get_id())
+                .collect::<Vec<_>>(),
+            vec![1, 3, 4]
+        );
+    }
+
     #[test]
     fn can_get_has()
     {
@@ -254,7 +652,10 @@ mod tests
 
         binding_map.inner.insert(
             BindingId::new::<Interface>(BindingOptions::new()),
-            Box::new(subjects::SomeProviderImpl { id: 103 }),
+            (
+                BindingOptions::new(),
+                Box::new(subjects::SomeProviderImpl { id: 103 }),
+            ),
         );
 
         assert!(binding_map.has::<Interface>(BindingOptions::new()));
@@ -272,9 +673,292 @@ mod tests
 
         binding_map.inner.insert(
             BindingId::new::<Interface>(BindingOptions::new().name("awesome")),
-            Box::new(subjects::SomeProviderImpl { id: 101 }),
+            (
+                BindingOptions::new().name("awesome"),
+                Box::new(subjects::SomeProviderImpl { id: 101 }),
+            ),
         );
 
         assert!(binding_map.has::<Interface>(BindingOptions::new().name("awesome")));
     }
+
+    #[test]
+    fn can_find_matching()
+    {
+        type Interface = ();
+
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<Interface>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        binding_map.set::<Interface>(
+            BindingOptions::new().tag("region", "eu"),
+            Box::new(subjects::SomeProviderImpl { id: 2 }),
+        );
+
+        for (id, is_satisfied) in [(3, true), (4, false), (5, true)] {
+            let options = BindingOptions::new().additional();
+
+            binding_map.set::<Interface>(
+                options.clone(),
+                Box::new(subjects::SomeProviderImpl { id }),
+            );
+
+            binding_map.set_condition::<Interface>(
+                options,
+                Arc::new(move |_context| is_satisfied),
+            );
+        }
+
+        let dependency_history = DependencyHistory::new();
+
+        let find_ids = |request: &BindingOptions, use_conditions: bool| {
+            binding_map
+                .find_matching::<Interface>(request, |condition| {
+                    use_conditions
+                        && condition(&ResolutionContext::new(
+                            &dependency_history,
+                            &BindingOptions::new(),
+                        ))
+                })
+                .iter()
+                .map(|(_, provider)| provider.get_id())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(find_ids(&BindingOptions::new(), true), vec![3, 5]);
+        assert_eq!(find_ids(&BindingOptions::new(), false), vec![1]);
+        assert_eq!(
+            find_ids(&BindingOptions::new().tag("region", "eu"), false),
+            vec![2]
+        );
+        assert!(find_ids(&BindingOptions::new().name("foo"), false).is_empty());
+
+        assert!(binding_map
+            .find_matching::<String>(&BindingOptions::new(), |_condition| true)
+            .is_empty());
+    }
+
+    #[test]
+    fn find_matching_only_visits_bindings_of_interface()
+    {
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<u8>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        let conditional_options = BindingOptions::new().additional();
+
+        binding_map.set::<u8>(
+            conditional_options.clone(),
+            Box::new(subjects::SomeProviderImpl { id: 2 }),
+        );
+
+        binding_map.set_condition::<u8>(conditional_options, Arc::new(|_context| false));
+
+        for id in 0..200 {
+            let options = BindingOptions::new().additional();
+
+            binding_map.set::<String>(
+                options.clone(),
+                Box::new(subjects::SomeProviderImpl { id }),
+            );
+
+            binding_map.set_condition::<String>(options, Arc::new(|_context| false));
+        }
+
+        assert_eq!(binding_map.interface_bindings[&TypeId::of::<u8>()].len(), 2);
+        assert_eq!(
+            binding_map.conditional_bindings[&TypeId::of::<u8>()].len(),
+            1
+        );
+
+        let visited_condition_cnt = Cell::new(0);
+
+        let ids = binding_map
+            .find_matching_by_type_id(
+                TypeId::of::<u8>(),
+                &BindingOptions::new(),
+                |_condition| {
+                    visited_condition_cnt.set(visited_condition_cnt.get() + 1);
+                    false
+                },
+            )
+            .iter()
+            .map(|(_, provider)| provider.get_id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![1]);
+        assert_eq!(visited_condition_cnt.get(), 1);
+
+        assert_eq!(
+            binding_map
+                .get::<u8>(BindingOptions::new())
+                .map(|provider| provider.get_id()),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn can_find_single()
+    {
+        type Interface = ();
+
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<Interface>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        for id in [2, 3] {
+            let options = BindingOptions::new().additional();
+
+            binding_map.set::<Interface>(
+                options.clone(),
+                Box::new(subjects::SomeProviderImpl { id }),
+            );
+
+            binding_map.set_condition::<Interface>(options, Arc::new(|_context| true));
+        }
+
+        assert_eq!(
+            binding_map
+                .find_single::<Interface>(&BindingOptions::new(), |_condition| false)
+                .map(|binding| binding.map(|(_, provider)| provider.get_id())),
+            Ok(Some(1))
+        );
+
+        assert_eq!(
+            binding_map
+                .find_single::<Interface>(&BindingOptions::new(), |_condition| true)
+                .map(|binding| binding.map(|(_, provider)| provider.get_id())),
+            Err(2)
+        );
+
+        assert!(matches!(
+            binding_map
+                .find_single::<Interface>(&BindingOptions::new().name("foo"), |_| true),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn can_get_all_satisfied()
+    {
+        type Interface = ();
+
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<Interface>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        for (id, is_satisfied) in [(2, false), (3, true)] {
+            let options = BindingOptions::new().additional();
+
+            binding_map.set::<Interface>(
+                options.clone(),
+                Box::new(subjects::SomeProviderImpl { id }),
+            );
+
+            binding_map.set_condition::<Interface>(
+                options,
+                Arc::new(move |_context| is_satisfied),
+            );
+        }
+
+        let dependency_history = DependencyHistory::new();
+
+        assert_eq!(
+            binding_map
+                .get_all_satisfied::<Interface>(|condition, options| {
+                    condition(&ResolutionContext::new(&dependency_history, options))
+                })
+                .into_iter()
+                .map(|(_, provider)| provider.get_id())
+                .collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn removing_conditional_binding_removes_condition()
+    {
+        type Interface = ();
+
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<Interface>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        let options = BindingOptions::new().additional();
+
+        binding_map.set::<Interface>(
+            options.clone(),
+            Box::new(subjects::SomeProviderImpl { id: 2 }),
+        );
+
+        binding_map.set_condition::<Interface>(options.clone(), Arc::new(|_| true));
+
+        binding_map.remove::<Interface>(options);
+
+        assert!(binding_map.conditional_bindings[&TypeId::of::<Interface>()].is_empty());
+
+        let ids = binding_map
+            .find_matching::<Interface>(&BindingOptions::new(), |_condition| true)
+            .iter()
+            .map(|(_, provider)| provider.get_id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![1]);
+    }
+
+    #[test]
+    fn can_iter()
+    {
+        let mut binding_map =
+            DIContainerBindingStorage::<dyn subjects::SomeProvider>::new();
+
+        binding_map.set::<u8>(
+            BindingOptions::new(),
+            Box::new(subjects::SomeProviderImpl { id: 1 }),
+        );
+
+        binding_map.set::<String>(
+            BindingOptions::new().name("foo"),
+            Box::new(subjects::SomeProviderImpl { id: 2 }),
+        );
+
+        binding_map.set_condition::<String>(
+            BindingOptions::new().name("foo"),
+            Arc::new(|_context| true),
+        );
+
+        let bindings = binding_map.iter().collect::<Vec<_>>();
+
+        assert_eq!(bindings.len(), 2);
+
+        assert_eq!(bindings[0].interface_id, TypeId::of::<u8>());
+        assert_eq!(bindings[0].interface.name, type_name::<u8>());
+        assert!(bindings[0].condition.is_none());
+        assert_eq!(bindings[0].provider.get_id(), 1);
+
+        assert_eq!(bindings[1].interface.name, type_name::<String>());
+        assert_eq!(bindings[1].options, &BindingOptions::new().name("foo"));
+        assert!(bindings[1].condition.is_some());
+    }
 }
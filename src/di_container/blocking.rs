@@ -50,21 +50,47 @@
 //! }
 //! ```
 use std::any::type_name;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::iter::successors;
+use std::rc::{Rc, Weak};
+
+use once_cell::unsync::OnceCell;
 
 use crate::castable_function::CastableFunction;
+use crate::deferred::{Lazy, Provider};
+use crate::dependency::{DependencyInfo, DependencyKind};
+use crate::dependency_graph::DependencyGraph;
+use crate::di_container::binding_lookup::BindingLookup;
 use crate::di_container::binding_storage::DIContainerBindingStorage;
 use crate::di_container::blocking::binding::builder::BindingBuilder;
-use crate::di_container::BindingOptions;
+use crate::di_container::blocking::frozen::DIContainerBuilder;
+use crate::di_container::blocking::scope::Scope;
+use crate::di_container::blocking::weak_singletons::WeakSingletons;
+use crate::di_container::resolution_context::BindingCondition;
+use crate::di_container::validation::Validator;
+use crate::di_container::{BindingInfo, BindingKey, BindingOptions, BindingScope};
+use crate::errors::build::BuildError;
 use crate::errors::di_container::DIContainerError;
+use crate::errors::lifecycle::{LifecycleError, ServiceFailure};
+use crate::errors::validation::ValidationError;
+use crate::explanation::{Explainer, Explanation};
+use crate::interfaces::injectable::Injectable;
 use crate::private::cast::boxed::CastBox;
 use crate::private::cast::rc::CastRc;
 use crate::provider::blocking::{IProvider, Providable, ProvidableFunctionKind};
-use crate::ptr::{SomePtr, TransientPtr};
+use crate::provider::HeldSingleton;
+use crate::ptr::{SingletonPtr, SomePtr, TransientPtr, WeakSingletonPtr};
 use crate::util::use_double;
 
 use_double!(crate::dependency_history::DependencyHistory);
 
 pub mod binding;
+pub mod frozen;
+pub mod scope;
+
+mod weak_singletons;
 
 #[cfg(not(test))]
 pub(crate) type BindingOptionsWithLt<'a> = BindingOptions<'a>;
@@ -72,11 +98,32 @@ pub(crate) type BindingOptionsWithLt<'a> = BindingOptions<'a>;
 #[cfg(test)]
 pub(crate) type BindingOptionsWithLt = BindingOptions<'static>;
 
+/// The instances of a scope, in the order they were created in.
+type ScopedInstances = Vec<ScopedInstance>;
+
+/// The options & provider of a binding.
+type FoundBinding<'di_container> = (
+    &'di_container BindingOptions<'static>,
+    &'di_container dyn IProvider<DIContainer>,
+);
+
+struct ScopedInstance
+{
+    scoped_binding_id: usize,
+    type_name: &'static str,
+    instance: SingletonPtr<dyn Injectable<DIContainer>>,
+    on_deactivation: Option<Box<dyn FnOnce()>>,
+}
+
 /// Blocking dependency injection container.
 #[derive(Default)]
 pub struct DIContainer
 {
     binding_storage: DIContainerBindingStorage<dyn IProvider<Self>>,
+    parent: Option<Rc<DIContainer>>,
+    scoped_instances: Option<RefCell<ScopedInstances>>,
+    weak_self: Rc<OnceCell<Weak<DIContainer>>>,
+    weak_singletons: WeakSingletons,
 }
 
 impl DIContainer
@@ -87,23 +134,26 @@ impl DIContainer
     {
         Self {
             binding_storage: DIContainerBindingStorage::new(),
+            parent: None,
+            scoped_instances: None,
+            weak_self: Rc::new(OnceCell::new()),
+            weak_singletons: WeakSingletons::new(),
         }
     }
-}
 
-#[cfg_attr(test, mockall::automock)]
-impl DIContainer
-{
-    /// Returns a new [`BindingBuilder`] for the given interface.
+    /// Returns a new [`DIContainerBuilder`] for building a [`FrozenDIContainer`].
+    ///
+    /// A [`FrozenDIContainer`] can't be shared between threads. Use
+    /// [`ThreadsafeDIContainer::builder`] for a frozen container that can be.
     ///
     /// # Examples
     /// ```
     /// # use syrette::{DIContainer, injectable};
     /// #
-    /// # struct DiskWriter {}
+    /// # struct Clock {}
     /// #
     /// # #[injectable]
-    /// # impl DiskWriter
+    /// # impl Clock
     /// # {
     /// #     fn new() -> Self
     /// #     {
@@ -112,41 +162,62 @@ impl DIContainer
     /// # }
     /// #
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// let mut di_container = DIContainer::new();
+    /// let mut di_container_builder = DIContainer::builder();
     ///
-    /// di_container.bind::<DiskWriter>().to::<DiskWriter>()?;
+    /// di_container_builder.bind::<Clock>().to::<Clock>()?;
+    ///
+    /// let di_container = di_container_builder.build()?;
+    ///
+    /// let clock = di_container.get::<Clock>()?.transient()?;
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    #[allow(clippy::missing_panics_doc)]
-    pub fn bind<Interface>(&mut self) -> BindingBuilder<'_, Interface>
-    where
-        Interface: 'static + ?Sized,
+    ///
+    /// [`FrozenDIContainer`]: crate::di_container::blocking::frozen::FrozenDIContainer
+    /// [`ThreadsafeDIContainer::builder`]: crate::ThreadsafeDIContainer::builder
+    #[must_use]
+    pub fn builder() -> DIContainerBuilder
     {
-        #[cfg(test)]
-        panic!("Nope");
-
-        #[cfg(not(test))]
-        BindingBuilder::new(self, DependencyHistory::new)
+        DIContainerBuilder::new()
     }
 
-    /// Returns the type bound with `Interface`.
+    /// Returns a new child `DIContainer` of this `DIContainer`.
     ///
-    /// # Errors
-    /// Will return `Err` if:
-    /// - No binding for `Interface` exists
-    /// - Resolving the binding for `Interface` fails
-    /// - Casting the binding for `Interface` fails
+    /// Bindings are first looked up in the child. If the child doesn't have a matching
+    /// binding, the lookup falls back to the parent and then to the parent's ancestors.
+    /// Bindings created in the child are never visible to the parent and singletons
+    /// bound in the parent are shared with the child.
+    ///
+    /// The child is not shared, so [`Lazy`] and [`Provider`] dependencies resolved
+    /// through it can't resolve anything until it's shared using
+    /// [`into_shared`](Self::into_shared).
     ///
     /// # Examples
     /// ```
+    /// # use std::rc::Rc;
+    /// #
     /// # use syrette::{DIContainer, injectable};
     /// #
-    /// # struct DeviceManager {}
+    /// # trait ILogger {}
     /// #
-    /// # #[injectable]
-    /// # impl DeviceManager
+    /// # struct StdoutLogger {}
+    /// #
+    /// # #[injectable(ILogger)]
+    /// # impl StdoutLogger
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl ILogger for StdoutLogger {}
+    /// #
+    /// # struct FileLogger {}
+    /// #
+    /// # #[injectable(ILogger)]
+    /// # impl FileLogger
     /// # {
     /// #     fn new() -> Self
     /// #     {
@@ -154,39 +225,55 @@ impl DIContainer
     /// #     }
     /// # }
     /// #
+    /// # impl ILogger for FileLogger {}
+    /// #
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let mut di_container = DIContainer::new();
     ///
-    /// di_container.bind::<DeviceManager>().to::<DeviceManager>()?;
+    /// di_container.bind::<dyn ILogger>().to::<StdoutLogger>()?;
     ///
-    /// let device_manager = di_container.get::<DeviceManager>()?.transient();
+    /// let di_container = Rc::new(di_container);
+    ///
+    /// let mut child_di_container = di_container.create_child();
+    ///
+    /// child_di_container.bind::<dyn ILogger>().to::<FileLogger>()?;
+    ///
+    /// // Resolved using the binding in the child
+    /// let logger = child_di_container.get::<dyn ILogger>()?.transient()?;
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get<Interface>(&self) -> Result<SomePtr<Interface>, DIContainerError>
-    where
-        Interface: 'static + ?Sized,
+    #[must_use]
+    pub fn create_child(self: &Rc<Self>) -> Self
     {
-        self.get_bound::<Interface>(DependencyHistory::new(), BindingOptions::new())
+        self.share();
+
+        Self {
+            binding_storage: DIContainerBindingStorage::new(),
+            parent: Some(self.clone()),
+            scoped_instances: None,
+            weak_self: Rc::new(OnceCell::new()),
+            weak_singletons: WeakSingletons::new(),
+        }
     }
 
-    /// Returns the type bound with `Interface` and the specified name.
+    /// Begins a new scope for this `DIContainer`.
     ///
-    /// # Errors
-    /// Will return `Err` if:
-    /// - No binding for `Interface` with name `name` exists
-    /// - Resolving the binding for `Interface` fails
-    /// - Casting the binding for `Interface` fails
+    /// Every resolution through the returned [`Scope`] reuses one instance per binding
+    /// in a scoped scope. The instances are dropped together with the [`Scope`] if
+    /// nothing else holds a reference to them.
     ///
     /// # Examples
     /// ```
+    /// # use std::rc::Rc;
+    /// #
     /// # use syrette::{DIContainer, injectable};
     /// #
-    /// # struct DeviceManager {}
+    /// # struct RequestContext {}
     /// #
     /// # #[injectable]
-    /// # impl DeviceManager
+    /// # impl RequestContext
     /// # {
     /// #     fn new() -> Self
     /// #     {
@@ -198,119 +285,1339 @@ impl DIContainer
     /// let mut di_container = DIContainer::new();
     ///
     /// di_container
-    ///     .bind::<DeviceManager>()
-    ///     .to::<DeviceManager>()?
-    ///     .in_transient_scope()
-    ///     .when_named("usb")?;
+    ///     .bind::<RequestContext>()
+    ///     .to::<RequestContext>()?
+    ///     .in_scoped_scope();
     ///
-    /// let device_manager = di_container.get_named::<DeviceManager>("usb")?.transient();
+    /// let di_container = Rc::new(di_container);
+    ///
+    /// let scope = di_container.begin_scope();
+    ///
+    /// let first_context = scope.get::<RequestContext>()?.singleton()?;
+    /// let second_context = scope.get::<RequestContext>()?.singleton()?;
+    ///
+    /// assert!(Rc::ptr_eq(&first_context, &second_context));
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_named<Interface>(
-        &self,
-        name: &'static str,
-    ) -> Result<SomePtr<Interface>, DIContainerError>
-    where
-        Interface: 'static + ?Sized,
+    #[must_use]
+    pub fn begin_scope(self: &Rc<Self>) -> Scope
     {
-        self.get_bound::<Interface>(
-            DependencyHistory::new(),
-            BindingOptions::new().name(name),
+        self.share();
+
+        Scope::new(
+            Self {
+                binding_storage: DIContainerBindingStorage::new(),
+                parent: Some(self.clone()),
+                scoped_instances: Some(RefCell::new(Vec::new())),
+                weak_self: Rc::new(OnceCell::new()),
+                weak_singletons: WeakSingletons::new(),
+            }
+            .into_shared(),
         )
     }
 
-    /// Returns the type bound with `Interface` where the binding has the specified
-    /// options.
+    /// Moves this `DIContainer` into a [`Rc`] that it keeps a weak reference to.
     ///
-    /// `dependency_history` is passed to the bound type when it is being resolved.
+    /// A shared `DIContainer` is required for resolving [`Lazy`] and [`Provider`]
+    /// dependencies, since they resolve through the container after being injected.
+    /// Bindings can't be added to a shared `DIContainer`.
     ///
-    /// # Errors
-    /// Will return `Err` if:
-    /// - No binding for `Interface` exists
-    /// - Resolving the binding for `Interface` fails
-    /// - Casting the binding for `Interface` fails
+    /// [`Lazy`] and [`Provider`] dependencies injected before the `DIContainer` is
+    /// shared, like the ones of singletons or of a child created with
+    /// [`create_child`](Self::create_child), resolve through it once it's shared. Until
+    /// then they fail with [`DIContainerError::NotShared`].
     ///
     /// # Examples
-    /// ```no_run
-    /// # use syrette::di_container::blocking::DIContainer;
+    /// ```
     /// # use syrette::dependency_history::DependencyHistory;
     /// # use syrette::di_container::BindingOptions;
+    /// # use syrette::{DIContainer, injectable};
     /// #
-    /// # struct EventHandler {}
-    /// # struct Button {}
+    /// # struct Clock {}
+    /// #
+    /// # #[injectable]
+    /// # impl Clock
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
     /// #
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// # let di_container = DIContainer::new();
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<Clock>().to::<Clock>()?;
+    ///
+    /// let di_container = di_container.into_shared();
+    ///
+    /// let clock_provider = di_container
+    ///     .get_provider::<Clock>(DependencyHistory::new(), BindingOptions::new())?;
+    ///
+    /// let clock = clock_provider.get()?.transient()?;
     /// #
-    /// let mut dependency_history = DependencyHistory::new();
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_shared(mut self) -> Rc<Self>
+    {
+        Rc::new_cyclic(|weak_self| {
+            // Deferred dependency handles that were created before this DI container
+            // was shared share the cell and resolve through the Rc from now on
+            let _ = self.weak_self.set(weak_self.clone());
+
+            Self {
+                binding_storage: std::mem::take(&mut self.binding_storage),
+                parent: self.parent.take(),
+                scoped_instances: self.scoped_instances.take(),
+                weak_self: self.weak_self.clone(),
+                weak_singletons: std::mem::take(&mut self.weak_singletons),
+            }
+        })
+    }
+
+    /// Drops the singletons & scoped instances of the `DIContainer` in the reverse order
+    /// of them being constructed, so that every instance is dropped before the
+    /// instances it depends on. Removes all bindings.
     ///
-    /// dependency_history.push::<EventHandler>();
+    /// Instances that are still referenced outside of the `DIContainer` are not dropped
+    /// until those references are. Such instances are returned.
     ///
-    /// di_container.get_bound::<Button>(
-    ///     dependency_history,
-    ///     BindingOptions::new().name("huge_red"),
-    /// )?;
+    /// This is also done when the `DIContainer` is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{injectable, DIContainer};
+    /// #
+    /// # struct Database {}
+    /// #
+    /// # #[injectable]
+    /// # impl Database
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<Database>()
+    ///     .to::<Database>()?
+    ///     .in_singleton_scope()?;
+    ///
+    /// let database = di_container.get::<Database>()?.singleton()?;
+    ///
+    /// let referenced_instances = di_container.dispose();
+    ///
+    /// assert_eq!(referenced_instances.len(), 1);
+    /// assert_eq!(referenced_instances[0].reference_count(), 1);
+    ///
+    /// drop(database);
     /// #
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_bound<Interface>(
-        &self,
-        dependency_history: DependencyHistory,
-        binding_options: BindingOptionsWithLt,
-    ) -> Result<SomePtr<Interface>, DIContainerError>
-    where
-        Interface: 'static + ?Sized,
+    pub fn dispose(&mut self) -> Vec<ReferencedInstance>
     {
-        let binding_providable = self
-            .get_binding_providable::<Interface>(binding_options, dependency_history)?;
+        let mut referenced_instances = Vec::new();
+
+        if let Some(scoped_instances) = &self.scoped_instances {
+            for scoped_instance in scoped_instances.take().into_iter().rev() {
+                if let Some(on_deactivation) = scoped_instance.on_deactivation {
+                    on_deactivation();
+                }
+
+                let reference_count =
+                    SingletonPtr::strong_count(&scoped_instance.instance);
+
+                if reference_count > 1 {
+                    referenced_instances.push(ReferencedInstance {
+                        type_name: scoped_instance.type_name,
+                        reference_count: reference_count - 1,
+                    });
+                }
+            }
+        }
 
-        match binding_providable {
-            Providable::Transient(transient_binding) => Ok(SomePtr::Transient(
-                transient_binding.cast::<Interface>().map_err(|_| {
-                    DIContainerError::CastFailed {
-                        interface: type_name::<Interface>(),
-                        binding_kind: "transient",
-                    }
-                })?,
-            )),
-            Providable::Singleton(singleton_binding) => Ok(SomePtr::Singleton(
-                singleton_binding.cast::<Interface>().map_err(|_| {
-                    DIContainerError::CastFailed {
-                        interface: type_name::<Interface>(),
-                        binding_kind: "singleton",
-                    }
-                })?,
-            )),
-            #[cfg(feature = "factory")]
-            Providable::Function(func_bound, ProvidableFunctionKind::UserCalled) => {
-                let factory = func_bound
-                    .as_any()
-                    .downcast_ref::<CastableFunction<Interface, Self>>()
-                    .ok_or_else(|| DIContainerError::CastFailed {
-                        interface: type_name::<Interface>(),
-                        binding_kind: "factory",
-                    })?;
+        referenced_instances
+            .extend(deactivate_providers(self.binding_storage.take_all()));
 
-                Ok(SomePtr::Factory(factory.call(self).into()))
+        referenced_instances
+    }
+
+    /// Starts the startable singletons of the `DIContainer` in the order they were
+    /// constructed in, meaning that every singleton is started after the singletons it
+    /// depends on. Lazy singletons that haven't been built yet are built first.
+    ///
+    /// Starting stops at the first service that fails, since the services depending on
+    /// it would likely fail as well.
+    ///
+    /// Only the bindings of this `DIContainer` are started, not those of it's parent.
+    ///
+    /// # Errors
+    /// Will return `Err` if a service fails to be resolved or started. The error names
+    /// the service.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::interfaces::lifecycle::{Startable, Stoppable};
+    /// # use syrette::ptr::SingletonPtr;
+    /// # use syrette::{injectable, DIContainer};
+    /// #
+    /// # struct Database {}
+    /// #
+    /// # #[injectable]
+    /// # impl Database
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// impl Startable for Database
+    /// {
+    ///     fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    ///     {
+    ///         println!("Connecting to database");
+    ///
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # struct WebServer
+    /// # {
+    /// #     database: SingletonPtr<Database>,
+    /// # }
+    /// #
+    /// # #[injectable]
+    /// # impl WebServer
+    /// # {
+    /// #     fn new(database: SingletonPtr<Database>) -> Self
+    /// #     {
+    /// #         Self { database }
+    /// #     }
+    /// # }
+    /// #
+    /// impl Startable for WebServer
+    /// {
+    ///     fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    ///     {
+    ///         println!("Listening for requests");
+    ///
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// impl Stoppable for WebServer
+    /// {
+    ///     fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    ///     {
+    ///         println!("No longer listening for requests");
+    ///
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<WebServer>()
+    ///     .to::<WebServer>()?
+    ///     .startable()
+    ///     .stoppable()
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// di_container
+    ///     .bind::<Database>()
+    ///     .to::<Database>()?
+    ///     .startable()
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// // Connects to the database & then starts listening for requests
+    /// di_container.start_all()?;
+    ///
+    /// di_container.stop_all()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_all(&self) -> Result<(), LifecycleError>
+    {
+        for binding in self.binding_storage.iter() {
+            if !binding.provider.is_startable() || binding.provider.is_singleton_built() {
+                continue;
             }
-            Providable::Function(func_bound, ProvidableFunctionKind::Instant) => {
-                type Func<Interface> =
-                    CastableFunction<dyn Fn() -> TransientPtr<Interface>, DIContainer>;
 
-                let dynamic_val_func = func_bound
-                    .as_any()
-                    .downcast_ref::<Func<Interface>>()
-                    .ok_or_else(|| DIContainerError::CastFailed {
-                        interface: type_name::<Interface>(),
-                        binding_kind: "dynamic value function",
-                    })?;
+            if let Err(reason) =
+                binding.provider.provide(self, DependencyHistory::new())
+            {
+                return Err(LifecycleError::new(vec![ServiceFailure::ResolveFailed {
+                    reason,
+                    service: binding
+                        .provider
+                        .injectable()
+                        .map_or(binding.interface.name, |injectable| {
+                            injectable.type_name
+                        }),
+                }]));
+            }
+        }
 
-                Ok(SomePtr::Transient(dynamic_val_func.call(self)()))
+        for (provider, held_singleton) in self.held_singletons() {
+            if let Some(Err(reason)) = provider.start() {
+                return Err(LifecycleError::new(vec![ServiceFailure::StartFailed {
+                    reason,
+                    service: held_singleton.type_name,
+                }]));
             }
         }
+
+        Ok(())
+    }
+
+    /// Stops the stoppable singletons of the `DIContainer` in the reverse order of them
+    /// being constructed, meaning that every singleton is stopped before the singletons
+    /// it depends on. Lazy singletons that haven't been built are not stopped.
+    ///
+    /// Stopping continues past services that fail.
+    ///
+    /// Only the bindings of this `DIContainer` are stopped, not those of it's parent.
+    ///
+    /// See [`start_all`](Self::start_all) for a example.
+    ///
+    /// # Errors
+    /// Will return `Err` if one or more services fail to stop. The error names every
+    /// service that failed.
+    pub fn stop_all(&self) -> Result<(), LifecycleError>
+    {
+        let failures = self
+            .held_singletons()
+            .into_iter()
+            .rev()
+            .filter_map(|(provider, held_singleton)| {
+                provider.stop()?.err().map(|reason| ServiceFailure::StopFailed {
+                    reason,
+                    service: held_singleton.type_name,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if !failures.is_empty() {
+            return Err(LifecycleError::new(failures));
+        }
+
+        Ok(())
+    }
+
+    /// Returns a [`Lazy`] that resolves the type bound with `Interface` where the binding
+    /// has the specified options the first time it is used.
+    ///
+    /// `dependency_history` is used when the bound type is resolved, so that binding
+    /// conditions see the types that the [`Lazy`] was injected into.
+    ///
+    /// The [`Lazy`] can be created before this `DIContainer` is shared using
+    /// [`into_shared`](Self::into_shared), but it can't resolve anything until then.
+    ///
+    /// # Errors
+    /// Currently never returns `Err`. Resolving errors are returned by [`Lazy::get`].
+    pub fn get_lazy<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> Result<Lazy<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        Ok(Lazy::new(self.create_deferred_resolve::<Interface>(
+            dependency_history,
+            binding_options,
+        )))
+    }
+
+    /// Returns a [`Provider`] that resolves the type bound with `Interface` where the
+    /// binding has the specified options every time it is asked for it.
+    ///
+    /// `dependency_history` is used when the bound type is resolved, so that binding
+    /// conditions see the types that the [`Provider`] was injected into.
+    ///
+    /// The [`Provider`] can be created before this `DIContainer` is shared using
+    /// [`into_shared`](Self::into_shared), but it can't resolve anything until then.
+    ///
+    /// # Errors
+    /// Currently never returns `Err`. Resolving errors are returned by
+    /// [`Provider::get`].
+    pub fn get_provider<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> Result<Provider<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        Ok(Provider::new(self.create_deferred_resolve::<Interface>(
+            dependency_history,
+            binding_options,
+        )))
+    }
+
+    /// Validates that every binding of this `DIContainer` and of it's ancestors can be
+    /// resolved, without constructing anything.
+    ///
+    /// # Errors
+    /// Will return `Err` containing every:
+    /// - Dependency without a binding or with more than one matching binding
+    /// - Chain of injectables depending on each other
+    /// - Binding with a implementation that can't be cast to the interface
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// # use syrette::errors::validation::ValidationProblem;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IEngine {}
+    /// #
+    /// # struct Car
+    /// # {
+    /// #     engine: TransientPtr<dyn IEngine>,
+    /// # }
+    /// #
+    /// # #[injectable]
+    /// # impl Car
+    /// # {
+    /// #     fn new(engine: TransientPtr<dyn IEngine>) -> Self
+    /// #     {
+    /// #         Self { engine }
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<Car>().to::<Car>()?;
+    ///
+    /// let err = di_container.validate().unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     err.problems(),
+    ///     [ValidationProblem::MissingBinding { .. }]
+    /// ));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError>
+    {
+        Validator::new(self.binding_lookup(), IProvider::injectable).validate()
+    }
+
+    /// Returns information about the bindings of the DI container, in the order they
+    /// were added. The bindings of ancestors are not included.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::BindingScope;
+    /// # use syrette::{injectable, DIContainer};
+    /// #
+    /// # struct Logger {}
+    /// #
+    /// # #[injectable]
+    /// # impl Logger
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<Logger>()
+    ///     .to::<Logger>()?
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// let binding = di_container.bindings().next().unwrap();
+    ///
+    /// assert_eq!(binding.scope(), BindingScope::LazySingleton);
+    /// assert!(!binding.is_singleton_built());
+    ///
+    /// di_container.get::<Logger>()?;
+    ///
+    /// assert!(di_container.bindings().next().unwrap().is_singleton_built());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bindings(&self) -> impl Iterator<Item = BindingInfo> + '_
+    {
+        self.binding_storage.iter().map(|binding| BindingInfo {
+            interface: binding.interface.name,
+            options: binding.options.clone(),
+            implementation: binding
+                .provider
+                .injectable()
+                .map(|injectable| injectable.type_name),
+            scope: binding.provider.scope(),
+            is_singleton_built: binding.provider.is_singleton_built(),
+        })
+    }
+
+    /// Returns a graph of the bindings of the DI container & of it's ancestors and of
+    /// the constructor dependencies between them.
+    ///
+    /// See [`DependencyGraph`] for a example.
+    #[must_use]
+    pub fn dependency_graph(&self) -> DependencyGraph
+    {
+        DependencyGraph::new(
+            &self.binding_lookup(),
+            IProvider::injectable,
+            IProvider::scope,
+        )
+    }
+
+    /// Returns a explanation of how the DI container would resolve `Interface` and
+    /// every transitive dependency of it, without constructing anything.
+    ///
+    /// See [`Explanation`] for a example.
+    #[must_use]
+    pub fn explain<Interface>(&self) -> Explanation
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.explain_bound::<Interface>(BindingOptions::new())
+    }
+
+    /// Returns a explanation of how the DI container would resolve `Interface` with
+    /// name `name` and every transitive dependency of it, without constructing
+    /// anything.
+    #[must_use]
+    pub fn explain_named<Interface>(&self, name: &str) -> Explanation
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.explain_bound::<Interface>(BindingOptions::new().name(name.to_string()))
+    }
+
+    /// Returns the type bound with `Interface` and the specified binding key.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with the key exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::BindingKey;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct DeviceManager {}
+    /// #
+    /// # #[injectable]
+    /// # impl DeviceManager
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// struct Usb;
+    ///
+    /// impl BindingKey for Usb {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<DeviceManager>()
+    ///     .to::<DeviceManager>()?
+    ///     .in_transient_scope()
+    ///     .when_keyed::<Usb>()?;
+    ///
+    /// let device_manager = di_container.get_keyed::<DeviceManager, Usb>()?.transient();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_keyed<Interface, Key>(
+        &self,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+        Key: BindingKey,
+    {
+        self.get_bound_by_ref::<Interface>(
+            DependencyHistory::new(),
+            &BindingOptions::new().keyed::<Key>(),
+        )
+    }
+
+    fn explain_bound<Interface>(&self, binding_options: BindingOptions<'static>)
+        -> Explanation
+    where
+        Interface: 'static + ?Sized,
+    {
+        let explainer = Explainer::new(
+            self.binding_lookup(),
+            IProvider::injectable,
+            IProvider::scope,
+        );
+
+        explainer.explain(&DependencyInfo::new::<Interface>(
+            DependencyKind::Single,
+            binding_options,
+        ))
+    }
+
+    fn binding_lookup(&self) -> BindingLookup<'_, dyn IProvider<Self>>
+    {
+        BindingLookup::new(
+            successors(Some(self), |di_container| di_container.parent.as_deref())
+                .map(|di_container| &di_container.binding_storage)
+                .collect(),
+        )
+    }
+
+    /// Resolves the singletons of the bindings in a lazy singleton scope that haven't
+    /// been resolved yet.
+    pub(crate) fn resolve_lazy_singletons(&self) -> Result<(), BuildError>
+    {
+        for binding in self.binding_storage.iter() {
+            if binding.provider.scope() != BindingScope::LazySingleton
+                || binding.provider.is_singleton_built()
+            {
+                continue;
+            }
+
+            binding
+                .provider
+                .provide(self, DependencyHistory::new())
+                .map_err(|reason| BuildError::LazySingletonResolveFailed {
+                    reason,
+                    service: binding
+                        .provider
+                        .injectable()
+                        .map_or(binding.interface.name, |injectable| {
+                            injectable.type_name
+                        }),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the providers that hold built singletons, in the order that the
+    /// singletons were constructed in.
+    fn held_singletons(&self) -> Vec<(&dyn IProvider<Self>, HeldSingleton)>
+    {
+        let mut held_singletons = self
+            .binding_storage
+            .iter()
+            .filter_map(|binding| {
+                Some((binding.provider, binding.provider.held_singleton()?))
+            })
+            .collect::<Vec<_>>();
+
+        held_singletons
+            .sort_by_key(|(_, held_singleton)| held_singleton.construction_order);
+
+        held_singletons
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+impl DIContainer
+{
+    /// Returns a new [`BindingBuilder`] for the given interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct DiskWriter {}
+    /// #
+    /// # #[injectable]
+    /// # impl DiskWriter
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<DiskWriter>().to::<DiskWriter>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn bind<Interface>(&mut self) -> BindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized,
+    {
+        #[cfg(test)]
+        panic!("Nope");
+
+        #[cfg(not(test))]
+        BindingBuilder::new(self, DependencyHistory::new)
+    }
+
+    /// Returns a new [`BindingBuilder`] for the given interface that replaces the
+    /// existing binding of the interface without a name instead of failing.
+    ///
+    /// The binding without a name is only replaced if the new binding doesn't end up
+    /// with a name, tags or a condition. Otherwise it's kept and the new binding
+    /// replaces the binding with the same name & tags, if any.
+    ///
+    /// The singleton of a replaced binding is deactivated like with
+    /// [`DIContainer::unbind`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IMailer {}
+    /// #
+    /// # struct SmtpMailer {}
+    /// #
+    /// # #[injectable(IMailer)]
+    /// # impl SmtpMailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IMailer for SmtpMailer {}
+    /// #
+    /// # struct FakeMailer {}
+    /// #
+    /// # #[injectable(IMailer)]
+    /// # impl FakeMailer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IMailer for FakeMailer {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<dyn IMailer>().to::<SmtpMailer>()?;
+    ///
+    /// di_container.rebind::<dyn IMailer>().to::<FakeMailer>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::missing_panics_doc)]
+    pub fn rebind<Interface>(&mut self) -> BindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.bind::<Interface>().rebinding()
+    }
+
+    /// Removes the binding of `Interface` that doesn't have a name.
+    ///
+    /// The singleton of the binding is deactivated like with [`DIContainer::dispose`]
+    /// and is returned if it is still referenced outside of the `DIContainer`.
+    ///
+    /// # Errors
+    /// Will return `Err` if no such binding exists in this container.
+    pub fn unbind<Interface>(
+        &mut self,
+    ) -> Result<Vec<ReferencedInstance>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let provider = self
+            .remove_binding::<Interface>(BindingOptions::new())
+            .ok_or_else(|| DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: None,
+                key: None,
+                searched_levels: 1,
+            })?;
+
+        Ok(deactivate_providers(vec![provider]))
+    }
+
+    /// Removes the binding of `Interface` with the specified name.
+    ///
+    /// The singleton of the binding is deactivated like with [`DIContainer::dispose`]
+    /// and is returned if it is still referenced outside of the `DIContainer`.
+    ///
+    /// # Errors
+    /// Will return `Err` if no such binding exists in this container.
+    pub fn unbind_named<Interface>(
+        &mut self,
+        name: &str,
+    ) -> Result<Vec<ReferencedInstance>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let provider = self
+            .remove_binding::<Interface>(BindingOptions::new().name(name.to_string()))
+            .ok_or_else(|| DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: Some(name.to_string()),
+                key: None,
+                searched_levels: 1,
+            })?;
+
+        Ok(deactivate_providers(vec![provider]))
+    }
+
+    /// Removes all of the bindings of this container.
+    ///
+    /// The singletons of the bindings are deactivated like with [`DIContainer::dispose`]
+    /// and the ones still referenced outside of the `DIContainer` are returned.
+    ///
+    /// The bindings of the ancestors of this container are left untouched.
+    pub fn unbind_all(&mut self) -> Vec<ReferencedInstance>
+    {
+        deactivate_providers(self.binding_storage.take_all())
+    }
+
+    /// Returns the type bound with `Interface`.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct DeviceManager {}
+    /// #
+    /// # #[injectable]
+    /// # impl DeviceManager
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container.bind::<DeviceManager>().to::<DeviceManager>()?;
+    ///
+    /// let device_manager = di_container.get::<DeviceManager>()?.transient();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<Interface>(&self) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_bound::<Interface>(DependencyHistory::new(), BindingOptions::new())
+    }
+
+    /// Returns the type bound with `Interface` and the specified name.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with name `name` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct DeviceManager {}
+    /// #
+    /// # #[injectable]
+    /// # impl DeviceManager
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<DeviceManager>()
+    ///     .to::<DeviceManager>()?
+    ///     .in_transient_scope()
+    ///     .when_named("usb")?;
+    ///
+    /// let device_manager = di_container.get_named::<DeviceManager>("usb")?.transient();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_named<Interface>(
+        &self,
+        name: &str,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_bound_by_ref::<Interface>(
+            DependencyHistory::new(),
+            &BindingOptions::new().name(name),
+        )
+    }
+
+    /// Returns the type bound with `Interface` and the specified name or, if no binding
+    /// has the name, the type bound with `Interface` without a name.
+    ///
+    /// Unlike this method, [`get_named`](Self::get_named) never falls back to the binding
+    /// without a name.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with name `name` nor without a name exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IPaymentGateway {}
+    /// #
+    /// # struct EuPaymentGateway {}
+    /// #
+    /// # #[injectable(IPaymentGateway)]
+    /// # impl EuPaymentGateway
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IPaymentGateway for EuPaymentGateway {}
+    /// #
+    /// # struct GenericPaymentGateway {}
+    /// #
+    /// # #[injectable(IPaymentGateway)]
+    /// # impl GenericPaymentGateway
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IPaymentGateway for GenericPaymentGateway {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IPaymentGateway>()
+    ///     .to::<EuPaymentGateway>()?
+    ///     .in_transient_scope()
+    ///     .when_named("eu")?;
+    ///
+    /// di_container
+    ///     .bind::<dyn IPaymentGateway>()
+    ///     .to::<GenericPaymentGateway>()?;
+    ///
+    /// // Resolves to the EuPaymentGateway
+    /// let eu_payment_gateway = di_container
+    ///     .get_named_or_default::<dyn IPaymentGateway>("eu")?
+    ///     .transient()?;
+    ///
+    /// // Resolves to the GenericPaymentGateway
+    /// let us_payment_gateway = di_container
+    ///     .get_named_or_default::<dyn IPaymentGateway>("us")?
+    ///     .transient()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_named_or_default<Interface>(
+        &self,
+        name: &str,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        match self.get_named::<Interface>(name) {
+            Err(err @ DIContainerError::BindingNotFound { .. }) => {
+                match self.get::<Interface>() {
+                    // The error of the named request tells what was requested
+                    Err(DIContainerError::BindingNotFound { .. }) => Err(err),
+                    result => result,
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Returns the type bound with `Interface` where the binding has the specified tags.
+    ///
+    /// A binding with more tags than the specified ones also matches, as long as no other
+    /// binding does.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with the tags exists
+    /// - More than one binding for `Interface` with the tags exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IDatabase {}
+    /// #
+    /// # struct EuReplica {}
+    /// #
+    /// # #[injectable(IDatabase)]
+    /// # impl EuReplica
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IDatabase for EuReplica {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IDatabase>()
+    ///     .to::<EuReplica>()?
+    ///     .in_transient_scope()
+    ///     .when_tagged("region", "eu")?
+    ///     .when_tagged("access", "read_only")?;
+    ///
+    /// let database = di_container
+    ///     .get_tagged::<dyn IDatabase>(&[("region", "eu"), ("access", "read_only")])?
+    ///     .transient()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_tagged<Interface>(
+        &self,
+        tags: &[(&'static str, &'static str)],
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_bound::<Interface>(
+            DependencyHistory::new(),
+            tags.iter()
+                .fold(BindingOptions::new(), |binding_options, (key, value)| {
+                    binding_options.tag(key, value)
+                }),
+        )
+    }
+
+    /// Returns the type bound with `Interface` where the binding has the specified
+    /// options.
+    ///
+    /// `dependency_history` is passed to the bound type when it is being resolved.
+    ///
+    /// The binding is searched for in this container first and then in each of its
+    /// ancestors.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists in this container or any of its ancestors.
+    ///   The returned error reports how many levels of the hierarchy were searched
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use syrette::di_container::blocking::DIContainer;
+    /// # use syrette::dependency_history::DependencyHistory;
+    /// # use syrette::di_container::BindingOptions;
+    /// #
+    /// # struct EventHandler {}
+    /// # struct Button {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let di_container = DIContainer::new();
+    /// #
+    /// let mut dependency_history = DependencyHistory::new();
+    ///
+    /// dependency_history.push::<EventHandler>();
+    ///
+    /// di_container.get_bound::<Button>(
+    ///     dependency_history,
+    ///     BindingOptions::new().name("huge_red"),
+    /// )?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn get_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptionsWithLt,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_bound_by_ref::<Interface>(dependency_history, &binding_options)
+    }
+
+    /// Returns a weak pointer to the singleton bound with `Interface` where the binding
+    /// has the specified options.
+    ///
+    /// If the binding is being resolved, as is the case when the singleton depends on
+    /// the type asking for it, the weak pointer is filled once the singleton has been
+    /// created. Otherwise the singleton is resolved right away with a new dependency
+    /// history, as it is allowed to depend on the type asking for it.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with the specified options exists
+    /// - Resolving the binding for `Interface` fails
+    /// - The binding for `Interface` is not in a singleton scope
+    pub fn get_weak_singleton_bound<Interface>(
+        &self,
+        binding_options: BindingOptionsWithLt,
+    ) -> Result<WeakSingletonPtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        if let Some(weak_singleton) = self
+            .weak_singletons
+            .add_pending::<Interface>(&binding_options)
+        {
+            return Ok(weak_singleton);
+        }
+
+        let (_, (_, provider)) =
+            self.find_bound::<Interface>(&binding_options, &DependencyHistory::new())?;
+
+        if !provider.scope().is_shared() {
+            return Err(DIContainerError::NotSingleton {
+                interface: type_name::<Interface>(),
+                scope: provider.scope(),
+            });
+        }
+
+        let singleton = self
+            .get_bound::<Interface>(DependencyHistory::new(), binding_options)?
+            .singleton()
+            .map_err(|err| {
+                DIContainerError::SingletonNotFound(err, type_name::<Interface>())
+            })?;
+
+        Ok(WeakSingletonPtr::from(&singleton))
+    }
+
+    /// Returns all of the types bound with `Interface` without a name or tags.
+    ///
+    /// The bindings of the ancestors of this container come first, followed by the
+    /// bindings of this container. The bindings of each container are in the order
+    /// they were bound in.
+    ///
+    /// If both this container and a ancestor of it has a binding bound with
+    /// [`to`], the binding of this container is used. Bindings bound with
+    /// [`to_additional`] are never left out.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IPlugin {}
+    /// #
+    /// # struct AudioPlugin {}
+    /// #
+    /// # #[injectable(IPlugin)]
+    /// # impl AudioPlugin
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IPlugin for AudioPlugin {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IPlugin>()
+    ///     .to_additional::<AudioPlugin>()
+    ///     .in_singleton_scope()?;
+    ///
+    /// let plugins = di_container.get_all::<dyn IPlugin>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to`]: BindingBuilder::to
+    /// [`to_additional`]: BindingBuilder::to_additional
+    pub fn get_all<Interface>(&self) -> Result<Vec<SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_all_bound::<Interface>(DependencyHistory::new())
+    }
+
+    /// Returns all of the types bound with `Interface` without a name or tags.
+    ///
+    /// `dependency_history` is passed to each of the bound types when they are being
+    /// resolved.
+    ///
+    /// The bindings are ordered the same way as with [`get_all`].
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    ///
+    /// [`get_all`]: Self::get_all
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn get_all_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+    ) -> Result<Vec<SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_all_binding_providables::<Interface>(
+            BindingOptions::is_unqualified,
+            &dependency_history,
+        )?
+        .into_iter()
+        .map(|(_, binding_providable)| self.handle_binding_providable(binding_providable))
+        .collect()
+    }
+
+    /// Returns all of the types bound with `Interface` and a name but no tags, keyed by
+    /// name.
+    ///
+    /// If both this container and a ancestor of it has a binding with the same name,
+    /// the binding of this container is used.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait ICommandHandler {}
+    /// #
+    /// # struct QuitHandler {}
+    /// #
+    /// # #[injectable(ICommandHandler)]
+    /// # impl QuitHandler
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl ICommandHandler for QuitHandler {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn ICommandHandler>()
+    ///     .to::<QuitHandler>()?
+    ///     .in_transient_scope()
+    ///     .when_named("quit")?;
+    ///
+    /// let handlers = di_container.get_all_named::<dyn ICommandHandler>()?;
+    ///
+    /// assert!(handlers.contains_key("quit"));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_all_named<Interface>(
+        &self,
+    ) -> Result<HashMap<String, SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_all_named_bound::<Interface>(DependencyHistory::new())
+    }
+
+    /// Returns all of the types bound with `Interface` and a name but no tags, keyed by
+    /// name.
+    ///
+    /// `dependency_history` is passed to each of the bound types when they are being
+    /// resolved.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn get_all_named_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+    ) -> Result<HashMap<String, SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.get_all_binding_providables::<Interface>(
+            |binding_options| {
+                binding_options.name.is_some() && binding_options.tags.is_empty()
+            },
+            &dependency_history,
+        )?
+        .into_iter()
+        .filter_map(|(binding_options, binding_providable)| {
+            let name = binding_options.name?.to_string();
+
+            Some(
+                self.handle_binding_providable(binding_providable)
+                    .map(|ptr| (name, ptr)),
+            )
+        })
+        .collect()
     }
 
     fn has_binding<Interface>(&self, binding_options: BindingOptionsWithLt) -> bool
@@ -340,53 +1647,494 @@ impl DIContainer
     {
         self.binding_storage.remove::<Interface>(binding_options)
     }
+
+    fn set_binding_condition<Interface>(
+        &mut self,
+        binding_options: BindingOptions<'static>,
+        condition: BindingCondition,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        self.binding_storage
+            .set_condition::<Interface>(binding_options, condition);
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn start_resolving_singleton<Interface>(
+        &self,
+        binding_options: BindingOptions<'static>,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        self.weak_singletons
+            .start_resolving::<Interface>(&binding_options);
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn finish_resolving_singleton<Interface>(
+        &self,
+        binding_options: BindingOptions<'static>,
+        singleton: Option<SingletonPtr<dyn Injectable<Self>>>,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        let singleton =
+            singleton.and_then(|singleton| singleton.cast::<Interface>().ok());
+
+        self.weak_singletons
+            .finish_resolving::<Interface>(&binding_options, singleton.as_ref());
+    }
+
+    pub(crate) fn is_scope(&self) -> bool
+    {
+        self.scoped_instances.is_some()
+    }
+
+    pub(crate) fn get_scoped_instance(
+        &self,
+        scoped_binding_id: usize,
+    ) -> Option<SingletonPtr<dyn Injectable<Self>>>
+    {
+        self.scoped_instances
+            .as_ref()?
+            .borrow()
+            .iter()
+            .find(|scoped_instance| {
+                scoped_instance.scoped_binding_id == scoped_binding_id
+            })
+            .map(|scoped_instance| scoped_instance.instance.clone())
+    }
+
+    pub(crate) fn set_scoped_instance(
+        &self,
+        scoped_binding_id: usize,
+        type_name: &'static str,
+        scoped_instance: SingletonPtr<dyn Injectable<Self>>,
+        on_deactivation: Option<Box<dyn FnOnce()>>,
+    )
+    {
+        if let Some(scoped_instances) = &self.scoped_instances {
+            scoped_instances.borrow_mut().push(ScopedInstance {
+                scoped_binding_id,
+                type_name,
+                instance: scoped_instance,
+                on_deactivation,
+            });
+        }
+    }
 }
 
 impl DIContainer
 {
-    fn get_binding_providable<Interface>(
+    /// Lets deferred dependency handles resolve through the [`Rc`] that this DI
+    /// container is in, even if it wasn't shared using [`into_shared`].
+    ///
+    /// [`into_shared`]: Self::into_shared
+    fn share(self: &Rc<Self>)
+    {
+        let _ = self.weak_self.set(Rc::downgrade(self));
+    }
+
+    fn create_deferred_resolve<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> impl Fn() -> Result<SomePtr<Interface>, DIContainerError> + 'static
+    where
+        Interface: 'static + ?Sized,
+    {
+        let weak_self = self.weak_self.clone();
+
+        // The types the deferred dependency is injected into have been resolved by the
+        // time it's resolved
+        let dependency_history = dependency_history.into_resolved();
+
+        move || {
+            let di_container = weak_self
+                .get()
+                .ok_or_else(|| DIContainerError::NotShared {
+                    interface: type_name::<Interface>(),
+                })?
+                .upgrade()
+                .ok_or_else(|| DIContainerError::ContainerDropped {
+                    interface: type_name::<Interface>(),
+                })?;
+
+            di_container.get_bound::<Interface>(
+                dependency_history.clone(),
+                binding_options.clone(),
+            )
+        }
+    }
+
+    fn handle_binding_providable<Interface>(
+        &self,
+        binding_providable: Providable<Self>,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        match binding_providable {
+            Providable::Transient(transient_binding) => Ok(SomePtr::Transient(
+                transient_binding.cast::<Interface>().map_err(|_| {
+                    DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "transient",
+                    }
+                })?,
+            )),
+            Providable::Singleton(singleton_binding) => Ok(SomePtr::Singleton(
+                singleton_binding.cast::<Interface>().map_err(|_| {
+                    DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "singleton",
+                    }
+                })?,
+            )),
+            #[cfg(feature = "factory")]
+            Providable::Function(func_bound, ProvidableFunctionKind::UserCalled) => {
+                let factory = func_bound
+                    .as_any()
+                    .downcast_ref::<CastableFunction<Interface, Self>>()
+                    .ok_or_else(|| DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "factory",
+                    })?;
+
+                Ok(SomePtr::Factory(factory.call(self).into()))
+            }
+            Providable::Function(func_bound, ProvidableFunctionKind::Instant) => {
+                type Func<Interface> =
+                    CastableFunction<dyn Fn() -> TransientPtr<Interface>, DIContainer>;
+
+                let dynamic_val_func = func_bound
+                    .as_any()
+                    .downcast_ref::<Func<Interface>>()
+                    .ok_or_else(|| DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "dynamic value function",
+                    })?;
+
+                Ok(SomePtr::Transient(dynamic_val_func.call(self)()))
+            }
+        }
+    }
+
+    /// Same as [`Self::get_bound`] but with borrowed binding options, so that a binding
+    /// can be looked up by a name that isn't `'static` without copying it.
+    fn get_bound_by_ref<Interface>(
         &self,
-        binding_options: BindingOptionsWithLt,
         dependency_history: DependencyHistory,
+        binding_options: &BindingOptions,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let binding_providable = self
+            .get_binding_providable::<Interface>(binding_options, dependency_history)?;
+
+        self.handle_binding_providable(binding_providable)
+    }
+
+    fn get_binding_providable<Interface>(
+        &self,
+        binding_options: &BindingOptions,
+        mut dependency_history: DependencyHistory,
     ) -> Result<Providable<Self>, DIContainerError>
     where
         Interface: 'static + ?Sized,
     {
-        let name = binding_options.name;
+        let (owner, (bound_options, provider)) =
+            self.find_bound::<Interface>(binding_options, &dependency_history)?;
+
+        dependency_history.set_next_binding_name(bound_options.name.clone());
+
+        let resolver = self.resolver_of(owner, provider);
+
+        if !provider.scope().is_shared() {
+            return provider
+                .provide(resolver, dependency_history)
+                .map_err(|err| DIContainerError::BindingResolveFailed {
+                    reason: err,
+                    interface: type_name::<Interface>(),
+                });
+        }
+
+        resolver
+            .weak_singletons
+            .start_resolving::<Interface>(bound_options);
+
+        let binding_providable = provider.provide(resolver, dependency_history);
+
+        let singleton = match &binding_providable {
+            Ok(Providable::Singleton(singleton)) => {
+                singleton.clone().cast::<Interface>().ok()
+            }
+            _ => None,
+        };
+
+        resolver
+            .weak_singletons
+            .finish_resolving::<Interface>(bound_options, singleton.as_ref());
+
+        binding_providable.map_err(|err| DIContainerError::BindingResolveFailed {
+            reason: err,
+            interface: type_name::<Interface>(),
+        })
+    }
+
+    /// Returns the binding used to resolve a request, together with the DI container
+    /// that has it.
+    fn find_bound<'this, Interface>(
+        &'this self,
+        binding_options: &BindingOptions<'this>,
+        dependency_history: &DependencyHistory,
+    ) -> Result<(&'this Self, FoundBinding<'this>), DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let mut searched_levels = 0;
+
+        let provider = successors(Some(self), |di_container| {
+            di_container.parent.as_deref()
+        })
+        .find_map(|di_container| {
+            searched_levels += 1;
+
+            di_container
+                .find_binding_provider::<Interface>(
+                    binding_options,
+                    dependency_history,
+                )
+                .map(|provider| provider.map(|provider| (di_container, provider)))
+                .transpose()
+        })
+        .transpose()?;
+
+        provider.ok_or_else(|| DIContainerError::BindingNotFound {
+            interface: type_name::<Interface>(),
+            name: binding_options.name.as_deref().map(ToString::to_string),
+            key: binding_options.key.as_ref().map(ToString::to_string),
+            searched_levels,
+        })
+    }
 
+    /// Returns the provider of the binding with a satisfied condition or, if there is no
+    /// such binding, the provider of the binding without a condition.
+    fn find_binding_provider<'this, Interface>(
+        &'this self,
+        binding_options: &BindingOptions<'this>,
+        dependency_history: &DependencyHistory,
+    ) -> Result<Option<FoundBinding<'this>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
         self.binding_storage
-            .get::<Interface>(binding_options)
-            .map_or_else(
-                || {
-                    Err(DIContainerError::BindingNotFound {
-                        interface: type_name::<Interface>(),
-                        name: name.as_ref().map(ToString::to_string),
-                    })
-                },
-                Ok,
-            )?
-            .provide(self, dependency_history)
-            .map_err(|err| DIContainerError::BindingResolveFailed {
-                reason: err,
+            .find_single::<Interface>(binding_options, |condition| {
+                dependency_history.satisfies(condition, binding_options)
+            })
+            .map_err(|matching| DIContainerError::AmbiguousBinding {
                 interface: type_name::<Interface>(),
+                name: binding_options.name.as_deref().map(ToString::to_string),
+                key: binding_options.key.as_ref().map(ToString::to_string),
+                matching,
+            })
+    }
+
+    /// Returns the DI container to resolve the dependencies of `provider` with, where
+    /// `provider` is the provider of a binding of `owner`.
+    ///
+    /// A lazy singleton is shared by every descendant of the DI container that owns
+    /// it's binding, so it's resolved by that DI container instead of by the
+    /// descendant it happens to be requested through first.
+    fn resolver_of<'this>(
+        &'this self,
+        owner: &'this Self,
+        provider: &dyn IProvider<Self>,
+    ) -> &'this Self
+    {
+        if provider.scope() == BindingScope::LazySingleton {
+            owner
+        } else {
+            self
+        }
+    }
+
+    fn get_all_binding_providables<Interface>(
+        &self,
+        filter: impl Fn(&BindingOptions<'static>) -> bool,
+        dependency_history: &DependencyHistory,
+    ) -> Result<Vec<(BindingOptions<'static>, Providable<Self>)>, DIContainerError>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let mut di_containers =
+            successors(Some(self), |di_container| di_container.parent.as_deref())
+                .collect::<Vec<_>>();
+
+        // Ancestors come first
+        di_containers.reverse();
+
+        di_containers
+            .iter()
+            .enumerate()
+            .flat_map(|(index, di_container)| {
+                let descendants = &di_containers[index + 1..];
+
+                di_container
+                    .binding_storage
+                    .get_all_satisfied::<Interface>(|condition, binding_options| {
+                        dependency_history.satisfies(condition, binding_options)
+                    })
+                    .into_iter()
+                    .filter(move |(binding_options, _)| {
+                        // The binding is shadowed by a binding of a descendant
+                        // container with the same options
+                        !descendants.iter().any(|descendant| {
+                            descendant
+                                .binding_storage
+                                .has::<Interface>((*binding_options).clone())
+                        })
+                    })
+                    .map(move |(binding_options, provider)| {
+                        (*di_container, binding_options, provider)
+                    })
+            })
+            .filter(|(_, binding_options, _)| filter(binding_options))
+            .map(|(owner, binding_options, provider)| {
+                let mut dependency_history = dependency_history.clone();
+
+                dependency_history.set_next_binding_name(binding_options.name.clone());
+
+                let binding_providable = provider
+                    .provide(self.resolver_of(owner, provider), dependency_history)
+                    .map_err(|err| DIContainerError::BindingResolveFailed {
+                        reason: err,
+                        interface: type_name::<Interface>(),
+                    })?;
+
+                Ok((binding_options.clone(), binding_providable))
             })
+            .collect()
+    }
+}
+
+impl Drop for DIContainer
+{
+    fn drop(&mut self)
+    {
+        self.dispose();
+    }
+}
+
+/// Runs the deactivation hooks of the singletons held by `providers` in the reverse
+/// order of their construction, then drops the providers. Returns the singletons that
+/// are still referenced elsewhere.
+pub(crate) fn deactivate_providers<DIContainerT>(
+    mut providers: Vec<Box<dyn IProvider<DIContainerT>>>,
+) -> Vec<ReferencedInstance>
+{
+    providers.sort_by_key(|provider| {
+        Reverse(
+            provider
+                .held_singleton()
+                .map(|held_singleton| held_singleton.construction_order),
+        )
+    });
+
+    let addresses = providers
+        .iter()
+        .map(|provider| {
+            provider
+                .held_singleton()
+                .map(|held_singleton| held_singleton.address)
+        })
+        .collect::<Vec<_>>();
+
+    let mut referenced_instances = Vec::new();
+
+    for (index, provider) in providers.into_iter().enumerate() {
+        provider.deactivate();
+
+        if let Some(held_singleton) = provider.held_singleton() {
+            // A singleton bound more than once, like with
+            // `in_singleton_scope_from_existing`, is held by several providers and is
+            // only reported by the last of them to be dropped
+            let is_held_by_later_provider =
+                addresses[index + 1..].contains(&Some(held_singleton.address));
+
+            if held_singleton.strong_count > 1 && !is_held_by_later_provider {
+                referenced_instances.push(ReferencedInstance {
+                    type_name: held_singleton.type_name,
+                    reference_count: held_singleton.strong_count - 1,
+                });
+            }
+        }
+    }
+
+    referenced_instances
+}
+
+/// A instance that was still referenced outside of a [`DIContainer`] when it was
+/// disposed or unbound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferencedInstance
+{
+    type_name: &'static str,
+    reference_count: usize,
+}
+
+impl ReferencedInstance
+{
+    /// Returns the type name of the instance.
+    #[must_use]
+    pub fn type_name(&self) -> &'static str
+    {
+        self.type_name
+    }
+
+    /// Returns the number of references to the instance outside of the
+    /// [`DIContainer`].
+    #[must_use]
+    pub fn reference_count(&self) -> usize
+    {
+        self.reference_count
     }
 }
 
 #[cfg(test)]
 mod tests
 {
+    use std::sync::Arc;
+
     use super::*;
+    use crate::dependency_history::MockDependencyHistory;
     use crate::provider::blocking::MockIProvider;
     use crate::ptr::{SingletonPtr, TransientPtr};
     use crate::test_utils::subjects;
 
+    /// Creates a mock provider that holds no singleton, as providers are asked for
+    /// their singletons when the `DIContainer` is dropped.
+    fn create_mock_provider() -> MockIProvider<DIContainer>
+    {
+        let mut mock_provider = MockIProvider::new();
+
+        mock_provider.expect_held_singleton().returning(|| None);
+        mock_provider.expect_deactivate().returning(|| ());
+
+        mock_provider
+            .expect_scope()
+            .return_const(BindingScope::Transient);
+
+        mock_provider
+    }
+
     #[test]
     fn can_get()
     {
         let mut di_container = DIContainer::new();
 
-        let mut mock_provider = MockIProvider::new();
+        let mut mock_provider = create_mock_provider();
 
         mock_provider.expect_provide().returning(|_, _| {
             Ok(Providable::Transient(TransientPtr::new(
@@ -413,7 +2161,7 @@ mod tests
     {
         let mut di_container = DIContainer::new();
 
-        let mut mock_provider = MockIProvider::new();
+        let mut mock_provider = create_mock_provider();
 
         mock_provider.expect_provide().returning(|_, _| {
             Ok(Providable::Transient(TransientPtr::new(
@@ -435,12 +2183,130 @@ mod tests
             .unwrap();
     }
 
+    #[test]
+    fn get_named_or_default_falls_back_to_binding_without_name()
+    {
+        let mut di_container = DIContainer::new();
+
+        assert!(matches!(
+            di_container.get_named_or_default::<dyn subjects::IUserManager>("special"),
+            Err(DIContainerError::BindingNotFound { name: Some(name), .. })
+                if name == "special"
+        ));
+
+        let mut mock_provider = create_mock_provider();
+
+        mock_provider.expect_provide().returning(|_, _| {
+            Ok(Providable::Transient(TransientPtr::new(
+                subjects::UserManager::new(),
+            )))
+        });
+
+        di_container
+            .binding_storage
+            .set::<dyn subjects::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        assert!(matches!(
+            di_container.get_named::<dyn subjects::IUserManager>("special"),
+            Err(DIContainerError::BindingNotFound { .. })
+        ));
+
+        di_container
+            .get_named_or_default::<dyn subjects::IUserManager>("special")
+            .unwrap()
+            .transient()
+            .unwrap();
+    }
+
+    #[test]
+    fn can_get_with_satisfied_condition()
+    {
+        let mut di_container = DIContainer::new();
+
+        let mut mock_provider = create_mock_provider();
+
+        mock_provider.expect_provide().never();
+
+        di_container.set_binding::<dyn subjects::INumber>(
+            BindingOptions::new(),
+            Box::new(mock_provider),
+        );
+
+        for (num, is_satisfied) in [(1, false), (2, true)] {
+            let mut mock_provider = create_mock_provider();
+
+            mock_provider.expect_provide().returning(move |_, _| {
+                Ok(Providable::Transient(TransientPtr::new(subjects::Number {
+                    num,
+                })))
+            });
+
+            let binding_options = BindingOptions::new().additional();
+
+            di_container.set_binding::<dyn subjects::INumber>(
+                binding_options.clone(),
+                Box::new(mock_provider),
+            );
+
+            di_container.set_binding_condition::<dyn subjects::INumber>(
+                binding_options,
+                Arc::new(move |_context| is_satisfied),
+            );
+        }
+
+        let dependency_history_mock = MockDependencyHistory::new();
+
+        let number = di_container
+            .get_bound::<dyn subjects::INumber>(
+                dependency_history_mock,
+                BindingOptions::new(),
+            )
+            .unwrap()
+            .transient()
+            .unwrap();
+
+        assert_eq!(number.get(), 2);
+    }
+
+    #[test]
+    fn cannot_get_with_ambiguous_conditions()
+    {
+        let mut di_container = DIContainer::new();
+
+        for _ in 0..2 {
+            let binding_options = BindingOptions::new().additional();
+
+            di_container.set_binding::<dyn subjects::INumber>(
+                binding_options.clone(),
+                Box::new(create_mock_provider()),
+            );
+
+            di_container.set_binding_condition::<dyn subjects::INumber>(
+                binding_options,
+                Arc::new(|_context| true),
+            );
+        }
+
+        let dependency_history_mock = MockDependencyHistory::new();
+
+        assert!(matches!(
+            di_container.get_bound::<dyn subjects::INumber>(
+                dependency_history_mock,
+                BindingOptions::new(),
+            ),
+            Err(DIContainerError::AmbiguousBinding { matching: 2, .. })
+        ));
+    }
+
     #[test]
     fn can_get_singleton()
     {
         let mut di_container = DIContainer::new();
 
-        let mut mock_provider = MockIProvider::new();
+        let mut mock_provider = create_mock_provider();
 
         let mut singleton = SingletonPtr::new(subjects::Number::new());
 
@@ -476,7 +2342,7 @@ mod tests
     {
         let mut di_container = DIContainer::new();
 
-        let mut mock_provider = MockIProvider::new();
+        let mut mock_provider = create_mock_provider();
 
         let mut singleton = SingletonPtr::new(subjects::Number::new());
 
@@ -567,7 +2433,7 @@ mod tests
             })
         };
 
-        let mut mock_provider = MockIProvider::new();
+        let mut mock_provider = create_mock_provider();
 
         mock_provider.expect_provide().returning_st(|_, _| {
             Ok(Providable::Function(
@@ -645,7 +2511,7 @@ mod tests
             })
         };
 
-        let mut mock_provider = MockIProvider::new();
+        let mut mock_provider = create_mock_provider();
 
         mock_provider.expect_provide().returning_st(|_, _| {
             Ok(Providable::Function(
@@ -666,6 +2532,258 @@ mod tests
             .unwrap();
     }
 
+    #[test]
+    fn can_get_from_parent()
+    {
+        let mut parent_di_container = DIContainer::new();
+
+        let mut mock_provider = create_mock_provider();
+
+        let mut singleton = SingletonPtr::new(subjects::Number::new());
+
+        SingletonPtr::get_mut(&mut singleton).unwrap().num = 4096;
+
+        mock_provider
+            .expect_provide()
+            .returning_st(move |_, _| Ok(Providable::Singleton(singleton.clone())));
+
+        parent_di_container
+            .binding_storage
+            .set::<dyn subjects::INumber>(BindingOptions::new(), Box::new(mock_provider));
+
+        let parent_di_container = Rc::new(parent_di_container);
+
+        let child_di_container = parent_di_container.create_child();
+
+        let parent_number = parent_di_container
+            .get::<dyn subjects::INumber>()
+            .unwrap()
+            .singleton()
+            .unwrap();
+
+        let child_number = child_di_container
+            .get::<dyn subjects::INumber>()
+            .unwrap()
+            .singleton()
+            .unwrap();
+
+        assert_eq!(child_number.get(), 4096);
+
+        assert!(Rc::ptr_eq(&parent_number, &child_number));
+    }
+
+    #[test]
+    fn can_get_all()
+    {
+        fn create_number_provider(num: i32) -> MockIProvider<DIContainer>
+        {
+            let mut mock_provider = create_mock_provider();
+
+            mock_provider.expect_provide().returning(move |_, _| {
+                Ok(Providable::Transient(TransientPtr::new(subjects::Number {
+                    num,
+                })))
+            });
+
+            mock_provider
+        }
+
+        let mut parent_di_container = DIContainer::new();
+
+        parent_di_container
+            .binding_storage
+            .set::<dyn subjects::INumber>(
+                BindingOptions::new().additional(),
+                Box::new(create_number_provider(1)),
+            );
+
+        let mut di_container = Rc::new(parent_di_container).create_child();
+
+        di_container.binding_storage.set::<dyn subjects::INumber>(
+            BindingOptions::new(),
+            Box::new(create_number_provider(2)),
+        );
+
+        di_container.binding_storage.set::<dyn subjects::INumber>(
+            BindingOptions::new().name("four"),
+            Box::new(create_number_provider(4)),
+        );
+
+        di_container.binding_storage.set::<dyn subjects::INumber>(
+            BindingOptions::new().additional(),
+            Box::new(create_number_provider(3)),
+        );
+
+        let numbers = di_container
+            .get_all::<dyn subjects::INumber>()
+            .unwrap()
+            .into_iter()
+            .map(|number| number.transient().unwrap().get())
+            .collect::<Vec<_>>();
+
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn can_get_all_named()
+    {
+        fn create_number_provider(num: i32) -> MockIProvider<DIContainer>
+        {
+            let mut mock_provider = create_mock_provider();
+
+            mock_provider.expect_provide().returning(move |_, _| {
+                Ok(Providable::Transient(TransientPtr::new(subjects::Number {
+                    num,
+                })))
+            });
+
+            mock_provider
+        }
+
+        let mut parent_di_container = DIContainer::new();
+
+        parent_di_container
+            .binding_storage
+            .set::<dyn subjects::INumber>(
+                BindingOptions::new().name("one"),
+                Box::new(create_number_provider(1)),
+            );
+
+        parent_di_container
+            .binding_storage
+            .set::<dyn subjects::INumber>(
+                BindingOptions::new().name("two"),
+                Box::new(create_number_provider(-2)),
+            );
+
+        let mut di_container = Rc::new(parent_di_container).create_child();
+
+        di_container.binding_storage.set::<dyn subjects::INumber>(
+            BindingOptions::new().name("two"),
+            Box::new(create_number_provider(2)),
+        );
+
+        di_container.binding_storage.set::<dyn subjects::INumber>(
+            BindingOptions::new(),
+            Box::new(create_number_provider(3)),
+        );
+
+        let mut numbers = di_container
+            .get_all_named::<dyn subjects::INumber>()
+            .unwrap()
+            .into_iter()
+            .map(|(name, number)| (name, number.transient().unwrap().get()))
+            .collect::<Vec<_>>();
+
+        numbers.sort_unstable();
+
+        assert_eq!(
+            numbers,
+            vec![("one".to_string(), 1), ("two".to_string(), 2)]
+        );
+    }
+
+    #[test]
+    fn can_get_weak_singleton_while_resolving()
+    {
+        let di_container = DIContainer::new();
+
+        di_container
+            .start_resolving_singleton::<dyn subjects::INumber>(BindingOptions::new());
+
+        let weak_singleton = di_container
+            .get_weak_singleton_bound::<dyn subjects::INumber>(BindingOptions::new())
+            .unwrap();
+
+        assert!(weak_singleton.upgrade().is_none());
+
+        let singleton: SingletonPtr<dyn Injectable<DIContainer>> =
+            SingletonPtr::new(subjects::Number { num: 5 });
+
+        di_container.finish_resolving_singleton::<dyn subjects::INumber>(
+            BindingOptions::new(),
+            Some(singleton.clone()),
+        );
+
+        assert_eq!(weak_singleton.upgrade().unwrap().get(), 5);
+
+        drop(singleton);
+
+        assert!(weak_singleton.upgrade().is_none());
+    }
+
+    #[test]
+    fn child_bindings_are_not_visible_to_parent()
+    {
+        let parent_di_container = Rc::new(DIContainer::new());
+
+        let mut child_di_container = parent_di_container.create_child();
+
+        let mut mock_provider = create_mock_provider();
+
+        mock_provider.expect_provide().returning(|_, _| {
+            Ok(Providable::Transient(TransientPtr::new(
+                subjects::UserManager::new(),
+            )))
+        });
+
+        child_di_container
+            .binding_storage
+            .set::<dyn subjects::IUserManager>(
+                BindingOptions::new(),
+                Box::new(mock_provider),
+            );
+
+        assert!(child_di_container
+            .get::<dyn subjects::IUserManager>()
+            .is_ok());
+
+        assert!(matches!(
+            parent_di_container.get::<dyn subjects::IUserManager>(),
+            Err(DIContainerError::BindingNotFound {
+                searched_levels: 1,
+                ..
+            })
+        ));
+
+        let grandchild_di_container =
+            Rc::new(Rc::new(DIContainer::new()).create_child()).create_child();
+
+        assert!(matches!(
+            grandchild_di_container.get::<dyn subjects::IUserManager>(),
+            Err(DIContainerError::BindingNotFound {
+                searched_levels: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn scoped_instances_work()
+    {
+        let di_container = Rc::new(DIContainer::new());
+
+        let scope = di_container.begin_scope();
+
+        assert!(!di_container.is_scope());
+        assert!(scope.is_scope());
+
+        assert!(scope.get_scoped_instance(0).is_none());
+
+        let scoped_instance: SingletonPtr<dyn Injectable<DIContainer>> =
+            SingletonPtr::new(subjects::UserManager::new());
+
+        scope.set_scoped_instance(0, "UserManager", scoped_instance.clone(), None);
+
+        assert!(scope
+            .get_scoped_instance(0)
+            .map_or(false, |instance| Rc::ptr_eq(&instance, &scoped_instance)));
+
+        drop(scope);
+
+        assert_eq!(Rc::strong_count(&scoped_instance), 1);
+    }
+
     #[test]
     fn has_binding_works()
     {
@@ -676,7 +2794,7 @@ mod tests
 
         di_container.binding_storage.set::<subjects::Ninja>(
             BindingOptions::new(),
-            Box::new(MockIProvider::new()),
+            Box::new(create_mock_provider()),
         );
 
         assert!(di_container.has_binding::<subjects::Ninja>(BindingOptions::new()));
@@ -689,7 +2807,7 @@ mod tests
 
         di_container.set_binding::<subjects::Ninja>(
             BindingOptions::new(),
-            Box::new(MockIProvider::new()),
+            Box::new(create_mock_provider()),
         );
 
         assert!(di_container
@@ -704,7 +2822,7 @@ mod tests
 
         di_container.binding_storage.set::<subjects::Ninja>(
             BindingOptions::new(),
-            Box::new(MockIProvider::new()),
+            Box::new(create_mock_provider()),
         );
 
         assert!(
@@ -721,4 +2839,60 @@ mod tests
                 .has::<subjects::Ninja>(BindingOptions::new())
         );
     }
+
+    #[test]
+    fn unbind_works()
+    {
+        let mut di_container = DIContainer::new();
+
+        di_container.binding_storage.set::<subjects::Ninja>(
+            BindingOptions::new(),
+            Box::new(create_mock_provider()),
+        );
+
+        di_container.binding_storage.set::<subjects::Ninja>(
+            BindingOptions::new().name("hidden"),
+            Box::new(create_mock_provider()),
+        );
+
+        assert!(di_container.unbind::<subjects::Ninja>().is_ok());
+
+        assert!(matches!(
+            di_container.unbind::<subjects::Ninja>(),
+            Err(DIContainerError::BindingNotFound { name: None, .. })
+        ));
+
+        assert!(di_container.unbind_named::<subjects::Ninja>("hidden").is_ok());
+
+        assert!(matches!(
+            di_container.unbind_named::<subjects::Ninja>("hidden"),
+            Err(DIContainerError::BindingNotFound { name: Some(_), .. })
+        ));
+    }
+
+    #[test]
+    fn unbind_all_works()
+    {
+        let mut di_container = DIContainer::new();
+
+        di_container.binding_storage.set::<subjects::Ninja>(
+            BindingOptions::new(),
+            Box::new(create_mock_provider()),
+        );
+
+        di_container.binding_storage.set::<dyn subjects::INumber>(
+            BindingOptions::new().name("five"),
+            Box::new(create_mock_provider()),
+        );
+
+        di_container.unbind_all();
+
+        assert!(!di_container
+            .binding_storage
+            .has::<subjects::Ninja>(BindingOptions::new()));
+
+        assert!(!di_container
+            .binding_storage
+            .has::<dyn subjects::INumber>(BindingOptions::new().name("five")));
+    }
 }
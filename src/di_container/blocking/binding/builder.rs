@@ -5,7 +5,10 @@ use std::rc::Rc;
 
 use crate::castable_function::CastableFunction;
 use crate::di_container::blocking::binding::scope_configurator::BindingScopeConfigurator;
-use crate::di_container::blocking::binding::when_configurator::BindingWhenConfigurator;
+use crate::di_container::blocking::binding::when_configurator::{
+    BindingWhenConfigurator,
+    ReplacedBinding,
+};
 use crate::di_container::BindingOptions;
 use crate::errors::di_container::BindingBuilderError;
 use crate::interfaces::injectable::Injectable;
@@ -24,6 +27,7 @@ where
 {
     di_container: &'di_container mut DIContainer,
     dependency_history_factory: fn() -> DependencyHistory,
+    is_rebinding: bool,
 
     interface_phantom: PhantomData<Interface>,
 }
@@ -40,10 +44,20 @@ where
         Self {
             di_container,
             dependency_history_factory,
+            is_rebinding: false,
             interface_phantom: PhantomData,
         }
     }
 
+    /// Makes the binding builder replace the existing binding of `Interface` without
+    /// a name instead of failing.
+    pub(crate) fn rebinding(mut self) -> Self
+    {
+        self.is_rebinding = true;
+
+        self
+    }
+
     /// Creates a binding of type `Interface` to type `Implementation` inside of the
     /// associated [`DIContainer`].
     ///
@@ -84,7 +98,7 @@ where
     /// # }
     /// ```
     pub fn to<Implementation>(
-        self,
+        mut self,
     ) -> Result<
         BindingScopeConfigurator<'di_container, Interface, Implementation>,
         BindingBuilderError,
@@ -92,25 +106,96 @@ where
     where
         Implementation: Injectable<DIContainer>,
     {
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >()));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let mut binding_scope_configurator = BindingScopeConfigurator::new(
             self.di_container,
             self.dependency_history_factory,
-        );
+            BindingOptions::new(),
+        )
+        .with_replaced_binding(replaced_binding);
 
         binding_scope_configurator.set_in_transient_scope();
 
         Ok(binding_scope_configurator)
     }
 
+    /// Adds a binding of type `Interface` to type `Implementation` inside of the
+    /// associated [`DIContainer`], next to any other bindings of the interface.
+    ///
+    /// Unlike [`to`], this never fails because of an existing binding. All of the
+    /// bindings of the interface are resolved with [`DIContainer::get_all`], in the order
+    /// they were added. Each of them keeps its own scope.
+    ///
+    /// The scope of the binding is transient. But that can be changed by using the
+    /// returned [`BindingScopeConfigurator`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IPlugin {}
+    /// #
+    /// # struct AudioPlugin {}
+    /// #
+    /// # #[injectable(IPlugin)]
+    /// # impl AudioPlugin {
+    /// #   fn new() -> Self
+    /// #   {
+    /// #       Self {}
+    /// #   }
+    /// # }
+    /// #
+    /// # impl IPlugin for AudioPlugin {}
+    /// #
+    /// # struct VideoPlugin {}
+    /// #
+    /// # #[injectable(IPlugin)]
+    /// # impl VideoPlugin {
+    /// #   fn new() -> Self
+    /// #   {
+    /// #       Self {}
+    /// #   }
+    /// # }
+    /// #
+    /// # impl IPlugin for VideoPlugin {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = DIContainer::new();
+    /// #
+    /// di_container.bind::<dyn IPlugin>().to_additional::<AudioPlugin>();
+    /// di_container.bind::<dyn IPlugin>().to_additional::<VideoPlugin>();
+    ///
+    /// let plugins = di_container.get_all::<dyn IPlugin>()?;
+    ///
+    /// assert_eq!(plugins.len(), 2);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`to`]: Self::to
+    #[allow(clippy::must_use_candidate)]
+    pub fn to_additional<Implementation>(
+        self,
+    ) -> BindingScopeConfigurator<'di_container, Interface, Implementation>
+    where
+        Implementation: Injectable<DIContainer>,
+    {
+        let mut binding_scope_configurator = BindingScopeConfigurator::new(
+            self.di_container,
+            self.dependency_history_factory,
+            BindingOptions::new().additional(),
+        );
+
+        binding_scope_configurator.set_in_transient_scope();
+
+        binding_scope_configurator
+    }
+
     /// Creates a binding of factory type `Interface` to a factory inside of the
     /// associated [`DIContainer`].
     ///
@@ -175,7 +260,7 @@ where
     #[cfg(feature = "factory")]
     #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
     pub fn to_factory<Args, Return, Func>(
-        self,
+        mut self,
         factory_func: &'static Func,
     ) -> Result<BindingWhenConfigurator<'di_container, Interface>, BindingBuilderError>
     where
@@ -184,14 +269,7 @@ where
         Interface: Fn<Args, Output = crate::ptr::TransientPtr<Return>>,
         Func: Fn(&DIContainer) -> Box<Interface>,
     {
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >()));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let factory_impl = CastableFunction::new(factory_func);
 
@@ -203,7 +281,10 @@ where
             )),
         );
 
-        Ok(BindingWhenConfigurator::new(self.di_container))
+        Ok(
+            BindingWhenConfigurator::new(self.di_container, BindingOptions::new())
+                .with_replaced_binding(replaced_binding),
+        )
     }
 
     /// Creates a binding of type `Interface` to a value resolved using the given
@@ -253,20 +334,13 @@ where
     /// # }
     /// ```
     pub fn to_dynamic_value<Func>(
-        self,
+        mut self,
         func: &'static Func,
     ) -> Result<BindingWhenConfigurator<'di_container, Interface>, BindingBuilderError>
     where
         Func: Fn(&DIContainer) -> TransientPtr<dyn Fn() -> TransientPtr<Interface>>,
     {
-        if self
-            .di_container
-            .has_binding::<Interface>(BindingOptions::new())
-        {
-            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
-                Interface,
-            >()));
-        }
+        let replaced_binding = self.take_replaced_binding()?;
 
         let castable_func = CastableFunction::new(func);
 
@@ -278,7 +352,32 @@ where
             )),
         );
 
-        Ok(BindingWhenConfigurator::new(self.di_container))
+        Ok(
+            BindingWhenConfigurator::new(self.di_container, BindingOptions::new())
+                .with_replaced_binding(replaced_binding),
+        )
+    }
+
+    /// Returns the binding without a name that the new binding replaces when
+    /// rebinding.
+    fn take_replaced_binding(
+        &mut self,
+    ) -> Result<Option<ReplacedBinding>, BindingBuilderError>
+    {
+        if self.is_rebinding {
+            return Ok(ReplacedBinding::take::<Interface>(self.di_container));
+        }
+
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        Ok(None)
     }
 }
 
@@ -290,6 +389,7 @@ mod tests
     use super::*;
     use crate::dependency_history::MockDependencyHistory;
     use crate::di_container::blocking::MockDIContainer;
+    use crate::provider::blocking::MockIProvider;
     use crate::test_utils::subjects;
 
     #[test]
@@ -317,6 +417,67 @@ mod tests
         binding_builder.to::<subjects::Number>().unwrap();
     }
 
+    #[test]
+    fn can_rebind_to()
+    {
+        let mut mock_di_container = MockDIContainer::new();
+
+        mock_di_container
+            .expect_has_binding::<dyn subjects::INumber>()
+            .never();
+
+        let mut replaced_provider = MockIProvider::new();
+
+        replaced_provider.expect_held_singleton().returning(|| None);
+        replaced_provider
+            .expect_deactivate()
+            .return_const(())
+            .once();
+
+        mock_di_container
+            .expect_remove_binding::<dyn subjects::INumber>()
+            .with(eq(BindingOptions::new()))
+            .return_once(move |_options| Some(Box::new(replaced_provider)))
+            .once();
+
+        mock_di_container
+            .expect_set_binding::<dyn subjects::INumber>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_options, _provider| ())
+            .once();
+
+        let binding_builder = BindingBuilder::<dyn subjects::INumber>::new(
+            &mut mock_di_container,
+            MockDependencyHistory::new,
+        );
+
+        binding_builder
+            .rebinding()
+            .to::<subjects::Number>()
+            .unwrap();
+    }
+
+    #[test]
+    fn can_bind_to_additional()
+    {
+        let mut mock_di_container = MockDIContainer::new();
+
+        mock_di_container
+            .expect_set_binding::<dyn subjects::INumber>()
+            .withf(|options, _provider| {
+                options.name.is_none() && options.additional_id.is_some()
+            })
+            .return_once(|_options, _provider| ())
+            .once();
+
+        let binding_builder = BindingBuilder::<dyn subjects::INumber>::new(
+            &mut mock_di_container,
+            MockDependencyHistory::new,
+        );
+
+        binding_builder.to_additional::<subjects::Number>();
+    }
+
     #[test]
     #[cfg(feature = "factory")]
     fn can_bind_to_factory()
@@ -2,14 +2,25 @@
 
 use std::any::type_name;
 use std::marker::PhantomData;
+use std::rc::Rc;
 
-use crate::di_container::blocking::binding::when_configurator::BindingWhenConfigurator;
+use crate::di_container::blocking::binding::when_configurator::{
+    BindingWhenConfigurator,
+    ReplacedBinding,
+};
 use crate::di_container::BindingOptions;
 use crate::errors::di_container::{BindingScopeConfiguratorError, DIContainerError};
 use crate::errors::injectable::InjectableError;
 use crate::interfaces::injectable::Injectable;
-use crate::provider::blocking::{SingletonProvider, TransientTypeProvider};
-use crate::ptr::SingletonPtr;
+use crate::interfaces::lifecycle::{Startable, Stoppable};
+use crate::provider::blocking::{
+    InstanceHooks,
+    LazySingletonProvider,
+    ScopedProvider,
+    SingletonProvider,
+    TransientTypeProvider,
+};
+use crate::ptr::{SingletonPtr, TransientPtr};
 use crate::util::use_double;
 
 use_double!(crate::dependency_history::DependencyHistory);
@@ -23,6 +34,9 @@ where
 {
     di_container: &'di_container mut DIContainer,
     dependency_history_factory: fn() -> DependencyHistory,
+    binding_options: BindingOptions<'static>,
+    hooks: InstanceHooks<Implementation, DIContainer>,
+    replaced_binding: Option<ReplacedBinding>,
 
     interface_phantom: PhantomData<Interface>,
     implementation_phantom: PhantomData<Implementation>,
@@ -37,16 +51,214 @@ where
     pub(crate) fn new(
         di_container: &'di_container mut DIContainer,
         dependency_history_factory: fn() -> DependencyHistory,
+        binding_options: BindingOptions<'static>,
     ) -> Self
     {
         Self {
             di_container,
             dependency_history_factory,
+            binding_options,
+            hooks: InstanceHooks::default(),
+            replaced_binding: None,
             interface_phantom: PhantomData,
             implementation_phantom: PhantomData,
         }
     }
 
+    pub(crate) fn with_replaced_binding(
+        mut self,
+        replaced_binding: Option<ReplacedBinding>,
+    ) -> Self
+    {
+        self.replaced_binding = replaced_binding;
+
+        self
+    }
+
+    /// Sets a hook that is run on every instance of the binding after it has been
+    /// resolved. The instance returned by the hook is the one that is provided, so the
+    /// hook can both configure & replace the instance.
+    ///
+    /// The hook must be set before the scope of the binding is configured. It isn't run
+    /// on singletons bound with [`in_singleton_scope_from_existing`], as those were
+    /// already resolved by another binding.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::cell::RefCell;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct EventBus
+    /// # {
+    /// #     subscribers: RefCell<Vec<&'static str>>,
+    /// # }
+    /// #
+    /// # #[injectable]
+    /// # impl EventBus
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self { subscribers: RefCell::new(Vec::new()) }
+    /// #     }
+    /// # }
+    /// #
+    /// # struct AuditLog {}
+    /// #
+    /// # #[injectable]
+    /// # impl AuditLog
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<EventBus>()
+    ///     .to::<EventBus>()?
+    ///     .in_singleton_scope()?;
+    ///
+    /// di_container
+    ///     .bind::<AuditLog>()
+    ///     .to::<AuditLog>()?
+    ///     .on_activation(|audit_log, di_container| {
+    ///         let event_bus = di_container
+    ///             .get::<EventBus>()
+    ///             .expect("Failed to get event bus")
+    ///             .singleton()
+    ///             .expect("Event bus is not a singleton");
+    ///
+    ///         event_bus.subscribers.borrow_mut().push("AuditLog");
+    ///
+    ///         audit_log
+    ///     })
+    ///     .in_singleton_scope()?;
+    ///
+    /// let event_bus = di_container.get::<EventBus>()?.singleton()?;
+    ///
+    /// assert_eq!(*event_bus.subscribers.borrow(), vec!["AuditLog"]);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`in_singleton_scope_from_existing`]: Self::in_singleton_scope_from_existing
+    #[allow(clippy::return_self_not_must_use)] // The binding is already transient
+    pub fn on_activation<Hook>(mut self, hook: Hook) -> Self
+    where
+        Hook: Fn(
+                TransientPtr<Implementation>,
+                &DIContainer,
+            ) -> TransientPtr<Implementation>
+            + 'static,
+    {
+        self.hooks.on_activation = Some(Rc::new(hook));
+
+        // The binding is in a transient scope until another scope is configured
+        self.set_in_transient_scope();
+
+        self
+    }
+
+    /// Sets a hook that is run on the singleton or scoped instance of the binding when
+    /// it is disposed. See [`DIContainer::dispose`].
+    ///
+    /// The hook must be set before the scope of the binding is configured. Transient
+    /// instances are never disposed by the [`DIContainer`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::cell::Cell;
+    /// # use std::rc::Rc;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct ConnectionPool {}
+    /// #
+    /// # #[injectable]
+    /// # impl ConnectionPool
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let is_closed = Rc::new(Cell::new(false));
+    ///
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<ConnectionPool>()
+    ///     .to::<ConnectionPool>()?
+    ///     .on_deactivation({
+    ///         let is_closed = is_closed.clone();
+    ///
+    ///         move |_connection_pool| is_closed.set(true)
+    ///     })
+    ///     .in_singleton_scope()?;
+    ///
+    /// di_container.dispose();
+    ///
+    /// assert!(is_closed.get());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DIContainer::dispose`]: crate::DIContainer::dispose
+    #[must_use]
+    pub fn on_deactivation<Hook>(mut self, hook: Hook) -> Self
+    where
+        Hook: Fn(&Implementation) + 'static,
+    {
+        self.hooks.on_deactivation = Some(Rc::new(hook));
+
+        self
+    }
+
+    /// Makes the singleton of the binding be started by [`DIContainer::start_all`].
+    ///
+    /// Must be called before the scope of the binding is configured. Only singletons &
+    /// lazy singletons are started.
+    ///
+    /// See [`DIContainer::start_all`] for a example.
+    ///
+    /// [`DIContainer::start_all`]: crate::DIContainer::start_all
+    #[must_use]
+    pub fn startable(mut self) -> Self
+    where
+        Implementation: Startable,
+    {
+        self.hooks.on_start = Some(<Implementation as Startable>::start);
+
+        self
+    }
+
+    /// Makes the singleton of the binding be stopped by [`DIContainer::stop_all`].
+    ///
+    /// Must be called before the scope of the binding is configured. Only singletons &
+    /// lazy singletons are stopped.
+    ///
+    /// See [`DIContainer::start_all`] for a example.
+    ///
+    /// [`DIContainer::stop_all`]: crate::DIContainer::stop_all
+    /// [`DIContainer::start_all`]: crate::DIContainer::start_all
+    #[must_use]
+    pub fn stoppable(mut self) -> Self
+    where
+        Implementation: Stoppable,
+    {
+        self.hooks.on_stop = Some(<Implementation as Stoppable>::stop);
+
+        self
+    }
+
     /// Configures the binding to be in a transient scope.
     ///
     /// This is the default.
@@ -84,7 +296,8 @@ where
     {
         self.set_in_transient_scope();
 
-        BindingWhenConfigurator::new(self.di_container)
+        BindingWhenConfigurator::new(self.di_container, self.binding_options)
+            .with_replaced_binding(self.replaced_binding)
     }
 
     /// Configures the binding to be in a singleton scope.
@@ -150,23 +363,46 @@ where
         BindingScopeConfiguratorError,
     >
     {
-        let singleton: SingletonPtr<Implementation> = SingletonPtr::from(
-            Implementation::resolve(
-                self.di_container,
-                (self.dependency_history_factory)(),
+        self.di_container
+            .start_resolving_singleton::<Interface>(self.binding_options.clone());
+
+        let resolved = Implementation::resolve(
+            self.di_container,
+            (self.dependency_history_factory)(),
+        )
+        .map(|singleton| {
+            SingletonPtr::<Implementation>::from(
+                self.hooks.activate(singleton, self.di_container),
             )
-            .map_err(BindingScopeConfiguratorError::SingletonResolveFailed)?,
+        });
+
+        self.di_container.finish_resolving_singleton::<Interface>(
+            self.binding_options.clone(),
+            resolved.as_ref().ok().map(|singleton| {
+                singleton.clone() as SingletonPtr<dyn Injectable<DIContainer>>
+            }),
         );
 
+        let singleton = match resolved {
+            Ok(singleton) => singleton,
+            Err(err) => {
+                self.restore_replaced_binding();
+
+                return Err(BindingScopeConfiguratorError::SingletonResolveFailed(err));
+            }
+        };
+
         self.di_container.set_binding::<Interface>(
-            BindingOptions::new(),
-            Box::new(SingletonProvider::new(singleton)),
+            self.binding_options.clone(),
+            Box::new(SingletonProvider::new(singleton, self.hooks)),
         );
 
-        Ok(BindingWhenConfigurator::new(self.di_container))
+        Ok(
+            BindingWhenConfigurator::new(self.di_container, self.binding_options)
+                .with_replaced_binding(self.replaced_binding),
+        )
     }
 
-
     /// Configures the binding to be in a singleton scope.
     ///
     /// # Errors
@@ -247,43 +483,193 @@ where
         BindingScopeConfiguratorError,
     >
     {
-        let singleton: SingletonPtr<Implementation> =
-            self.di_container.get::<Implementation>()
-                .map_err(|reason| BindingScopeConfiguratorError::SingletonResolveFailed(
+        let existing = self
+            .di_container
+            .get::<Implementation>()
+            .map_err(|reason| {
+                BindingScopeConfiguratorError::SingletonResolveFailed(
                     InjectableError::ResolveFailed {
                         affected: type_name::<Implementation>(),
-                        reason: Box::new( reason )
+                        reason: Box::new(reason),
+                    },
+                )
+            })
+            .and_then(|existing| {
+                existing.singleton().map_err(|reason| {
+                    BindingScopeConfiguratorError::SingletonResolveFailed(
+                        InjectableError::ResolveFailed {
+                            affected: type_name::<Implementation>(),
+                            reason: Box::new(DIContainerError::SingletonNotFound(
+                                reason,
+                                type_name::<Implementation>(),
+                            )),
+                        },
+                    )
+                })
+            });
 
-                    }
-                ))?
-                .singleton()
-                .map_err(|reason| BindingScopeConfiguratorError::SingletonResolveFailed(
-                    InjectableError::ResolveFailed {
-                        affected: type_name::<Implementation>(),
-                        reason: Box::new( DIContainerError::SingletonNotFound (reason, type_name::<Implementation>()))
-                    }
-                ))?;
+        let singleton: SingletonPtr<Implementation> = match existing {
+            Ok(singleton) => singleton,
+            Err(err) => {
+                self.restore_replaced_binding();
+
+                return Err(err);
+            }
+        };
 
         self.di_container.set_binding::<Interface>(
-            BindingOptions::new(),
-            Box::new(SingletonProvider::new(singleton)),
+            self.binding_options.clone(),
+            Box::new(SingletonProvider::new(singleton, self.hooks)),
         );
 
-        Ok(BindingWhenConfigurator::new(self.di_container))
+        Ok(
+            BindingWhenConfigurator::new(self.di_container, self.binding_options)
+                .with_replaced_binding(self.replaced_binding),
+        )
+    }
+
+    /// Configures the binding to be in a lazy singleton scope.
+    ///
+    /// Unlike [`in_singleton_scope`], the implementation isn't resolved until the
+    /// binding is first resolved. It is then cached and shared by all later resolutions.
+    ///
+    /// Like with [`in_singleton_scope`], the dependencies of the implementation are
+    /// resolved by the DI container that has the binding, even when the binding is
+    /// first resolved through a child container or a scope.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::rc::Rc;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct SearchIndex {}
+    /// #
+    /// # #[injectable]
+    /// # impl SearchIndex
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<SearchIndex>()
+    ///     .to::<SearchIndex>()?
+    ///     .in_lazy_singleton_scope();
+    ///
+    /// // The search index is built here
+    /// let search_index = di_container.get::<SearchIndex>()?.singleton()?;
+    ///
+    /// let same_search_index = di_container.get::<SearchIndex>()?.singleton()?;
+    ///
+    /// assert!(Rc::ptr_eq(&search_index, &same_search_index));
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`in_singleton_scope`]: Self::in_singleton_scope
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_lazy_singleton_scope(
+        self,
+    ) -> BindingWhenConfigurator<'di_container, Interface>
+    {
+        self.di_container.set_binding::<Interface>(
+            self.binding_options.clone(),
+            Box::new(LazySingletonProvider::<Implementation, DIContainer>::new(
+                self.hooks,
+            )),
+        );
+
+        BindingWhenConfigurator::new(self.di_container, self.binding_options)
+            .with_replaced_binding(self.replaced_binding)
+    }
+
+    /// Configures the binding to be in a scoped scope.
+    ///
+    /// One instance is shared between all resolutions made through the same [`Scope`].
+    /// Resolving the binding outside of a [`Scope`] fails.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::rc::Rc;
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct DatabaseTransaction {}
+    /// #
+    /// # #[injectable]
+    /// # impl DatabaseTransaction
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<DatabaseTransaction>()
+    ///     .to::<DatabaseTransaction>()?
+    ///     .in_scoped_scope();
+    ///
+    /// let di_container = Rc::new(di_container);
+    ///
+    /// {
+    ///     let scope = di_container.begin_scope();
+    ///
+    ///     let transaction = scope.get::<DatabaseTransaction>()?.singleton()?;
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Scope`]: crate::di_container::blocking::scope::Scope
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_scoped_scope(self) -> BindingWhenConfigurator<'di_container, Interface>
+    {
+        self.di_container.set_binding::<Interface>(
+            self.binding_options.clone(),
+            Box::new(ScopedProvider::<Implementation>::new(self.hooks)),
+        );
+
+        BindingWhenConfigurator::new(self.di_container, self.binding_options)
+            .with_replaced_binding(self.replaced_binding)
     }
 
     pub(crate) fn set_in_transient_scope(&mut self)
     {
         self.di_container.set_binding::<Interface>(
-            BindingOptions::new(),
-            Box::new(TransientTypeProvider::<Implementation, DIContainer>::new()),
+            self.binding_options.clone(),
+            Box::new(TransientTypeProvider::<Implementation, DIContainer>::new(
+                self.hooks.clone(),
+            )),
         );
     }
+
+    /// Puts back the binding that a rebound binding replaced, as the rebound binding
+    /// couldn't be configured.
+    fn restore_replaced_binding(self)
+    {
+        if let Some(replaced_binding) = self.replaced_binding {
+            replaced_binding.restore::<Interface>(self.di_container);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests
 {
+    use std::cell::Cell;
+
     use super::*;
     use crate::dependency_history::MockDependencyHistory;
     use crate::di_container::blocking::MockDIContainer;
@@ -306,6 +692,7 @@ mod tests
         >::new(
             &mut di_container_mock,
             MockDependencyHistory::new,
+            BindingOptions::new(),
         );
 
         binding_scope_configurator.in_transient_scope();
@@ -316,6 +703,18 @@ mod tests
     {
         let mut di_container_mock = MockDIContainer::new();
 
+        di_container_mock
+            .expect_start_resolving_singleton::<dyn subjects::IUserManager>()
+            .withf(|options| options.name.is_none())
+            .return_const(())
+            .once();
+
+        di_container_mock
+            .expect_finish_resolving_singleton::<dyn subjects::IUserManager>()
+            .withf(|options, singleton| options.name.is_none() && singleton.is_some())
+            .return_const(())
+            .once();
+
         di_container_mock
             .expect_set_binding::<dyn subjects::IUserManager>()
             .withf(|options, _provider| options.name.is_none())
@@ -328,8 +727,127 @@ mod tests
         >::new(
             &mut di_container_mock,
             MockDependencyHistory::new,
+            BindingOptions::new(),
         );
 
         assert!(binding_scope_configurator.in_singleton_scope().is_ok());
     }
+
+    #[test]
+    fn in_lazy_singleton_scope_works()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::IUserManager>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_scope_configurator = BindingScopeConfigurator::<
+            dyn subjects::IUserManager,
+            subjects::UserManager,
+        >::new(
+            &mut di_container_mock,
+            MockDependencyHistory::new,
+            BindingOptions::new(),
+        );
+
+        binding_scope_configurator.in_lazy_singleton_scope();
+    }
+
+    #[test]
+    fn in_scoped_scope_works()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::IUserManager>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_scope_configurator = BindingScopeConfigurator::<
+            dyn subjects::IUserManager,
+            subjects::UserManager,
+        >::new(
+            &mut di_container_mock,
+            MockDependencyHistory::new,
+            BindingOptions::new(),
+        );
+
+        binding_scope_configurator.in_scoped_scope();
+    }
+
+    #[test]
+    fn on_activation_sets_transient_binding()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::IUserManager>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_scope_configurator = BindingScopeConfigurator::<
+            dyn subjects::IUserManager,
+            subjects::UserManager,
+        >::new(
+            &mut di_container_mock,
+            MockDependencyHistory::new,
+            BindingOptions::new(),
+        );
+
+        let _ = binding_scope_configurator
+            .on_activation(|user_manager, _di_container| user_manager);
+    }
+
+    #[test]
+    fn in_singleton_scope_runs_activation_hook()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::IUserManager>()
+            .withf(|options, _provider| options.name.is_none())
+            .return_const(())
+            .times(2);
+
+        di_container_mock
+            .expect_start_resolving_singleton::<dyn subjects::IUserManager>()
+            .return_const(())
+            .once();
+
+        di_container_mock
+            .expect_finish_resolving_singleton::<dyn subjects::IUserManager>()
+            .return_const(())
+            .once();
+
+        let is_activated = Rc::new(Cell::new(false));
+
+        let binding_scope_configurator = BindingScopeConfigurator::<
+            dyn subjects::IUserManager,
+            subjects::UserManager,
+        >::new(
+            &mut di_container_mock,
+            MockDependencyHistory::new,
+            BindingOptions::new(),
+        );
+
+        assert!(binding_scope_configurator
+            .on_activation({
+                let is_activated = is_activated.clone();
+
+                move |user_manager, _di_container| {
+                    is_activated.set(true);
+
+                    user_manager
+                }
+            })
+            .in_singleton_scope()
+            .is_ok());
+
+        assert!(is_activated.get());
+    }
 }
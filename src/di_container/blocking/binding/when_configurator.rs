@@ -1,19 +1,71 @@
 //! When configurator for a binding for types inside of a [`DIContainer`].
 use std::any::type_name;
+use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
-use crate::di_container::BindingOptions;
+use crate::di_container::blocking::deactivate_providers;
+use crate::di_container::resolution_context::{BindingCondition, ResolutionContext};
+use crate::di_container::{BindingKey, BindingOptions};
 use crate::errors::di_container::BindingWhenConfiguratorError;
+use crate::provider::blocking::IProvider;
 use crate::util::use_double;
 
 use_double!(crate::di_container::blocking::DIContainer);
 
+/// The binding without a name that a rebound binding replaced. It's put back if the
+/// rebound binding is given a name, tags or a condition, as the rebound binding then
+/// doesn't replace it. Otherwise it's deactivated like a unbound binding once dropped.
+pub(crate) struct ReplacedBinding
+{
+    provider: Option<Box<dyn IProvider<DIContainer>>>,
+}
+
+impl ReplacedBinding
+{
+    /// Removes the binding of `Interface` without a name from the DI container.
+    pub(crate) fn take<Interface>(di_container: &mut DIContainer) -> Option<Self>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let provider = di_container.remove_binding::<Interface>(BindingOptions::new())?;
+
+        Some(Self {
+            provider: Some(provider),
+        })
+    }
+
+    pub(crate) fn restore<Interface>(mut self, di_container: &mut DIContainer)
+    where
+        Interface: 'static + ?Sized,
+    {
+        let provider = match self.provider.take() {
+            Some(provider) => provider,
+            None => return,
+        };
+
+        di_container.set_binding::<Interface>(BindingOptions::new(), provider);
+    }
+}
+
+impl Drop for ReplacedBinding
+{
+    fn drop(&mut self)
+    {
+        if let Some(provider) = self.provider.take() {
+            deactivate_providers(vec![provider]);
+        }
+    }
+}
+
 /// When configurator for a binding for type `Interface` inside a [`DIContainer`].
 pub struct BindingWhenConfigurator<'di_container, Interface>
 where
     Interface: 'static + ?Sized,
 {
     di_container: &'di_container mut DIContainer,
+    binding_options: BindingOptions<'static>,
+    replaced_binding: Option<ReplacedBinding>,
 
     interface_phantom: PhantomData<Interface>,
 }
@@ -22,15 +74,31 @@ impl<'di_container, Interface> BindingWhenConfigurator<'di_container, Interface>
 where
     Interface: 'static + ?Sized,
 {
-    pub(crate) fn new(di_container: &'di_container mut DIContainer) -> Self
+    pub(crate) fn new(
+        di_container: &'di_container mut DIContainer,
+        binding_options: BindingOptions<'static>,
+    ) -> Self
     {
         Self {
             di_container,
+            binding_options,
+            replaced_binding: None,
             interface_phantom: PhantomData,
         }
     }
 
-    /// Configures the binding to have a name.
+    pub(crate) fn with_replaced_binding(
+        mut self,
+        replaced_binding: Option<ReplacedBinding>,
+    ) -> Self
+    {
+        self.replaced_binding = replaced_binding;
+
+        self
+    }
+
+    /// Configures the binding to have a name. The name can be a `&'static str` or a
+    /// `String` created at runtime.
     ///
     /// # Errors
     /// Will return Err if no binding for the interface already exists.
@@ -63,13 +131,13 @@ where
     /// # }
     /// ```
     pub fn when_named(
-        self,
-        name: &'static str,
+        mut self,
+        name: impl Into<Cow<'static, str>>,
     ) -> Result<(), BindingWhenConfiguratorError>
     {
         let binding = self
             .di_container
-            .remove_binding::<Interface>(BindingOptions::new())
+            .remove_binding::<Interface>(self.binding_options.clone())
             .map_or_else(
                 || {
                     Err(BindingWhenConfiguratorError::BindingNotFound(type_name::<
@@ -80,11 +148,388 @@ where
                 Ok,
             )?;
 
+        self.restore_replaced_binding();
+
         self.di_container
-            .set_binding::<Interface>(BindingOptions::new().name(name), binding);
+            .set_binding::<Interface>(self.binding_options.name(name), binding);
 
         Ok(())
     }
+
+    /// Configures the binding to have a binding key.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::BindingKey;
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # struct Kitten {}
+    /// #
+    /// # #[injectable]
+    /// # impl Kitten
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// struct Billy;
+    ///
+    /// impl BindingKey for Billy {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<Kitten>()
+    ///     .to::<Kitten>()?
+    ///     .in_transient_scope()
+    ///     .when_keyed::<Billy>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_keyed<Key>(mut self) -> Result<(), BindingWhenConfiguratorError>
+    where
+        Key: BindingKey,
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                BindingWhenConfiguratorError::BindingNotFound(type_name::<Interface>())
+            })?;
+
+        self.restore_replaced_binding();
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.keyed::<Key>(), binding);
+
+        Ok(())
+    }
+
+    /// Configures the binding to have a tag. Can be called multiple times to give the
+    /// binding several tags.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IDatabase {}
+    /// #
+    /// # struct EuReplica {}
+    /// #
+    /// # #[injectable(IDatabase)]
+    /// # impl EuReplica
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IDatabase for EuReplica {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IDatabase>()
+    ///     .to::<EuReplica>()?
+    ///     .in_transient_scope()
+    ///     .when_tagged("region", "eu")?
+    ///     .when_tagged("access", "read_only")?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_tagged(
+        mut self,
+        key: &'static str,
+        value: &'static str,
+    ) -> Result<Self, BindingWhenConfiguratorError>
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                BindingWhenConfiguratorError::BindingNotFound(type_name::<Interface>())
+            })?;
+
+        self.restore_replaced_binding();
+
+        self.binding_options = self.binding_options.tag(key, value);
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.clone(), binding);
+
+        Ok(self)
+    }
+
+    /// Configures the binding to only be used when injected into a `Consumer`.
+    ///
+    /// A binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IWeapon {}
+    /// #
+    /// # struct Katana {}
+    /// #
+    /// # #[injectable(IWeapon)]
+    /// # impl Katana
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IWeapon for Katana {}
+    /// #
+    /// # struct Ninja {}
+    /// #
+    /// # #[injectable]
+    /// # impl Ninja
+    /// # {
+    /// #     fn new(weapon: TransientPtr<dyn IWeapon>) -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IWeapon>()
+    ///     .to::<Katana>()?
+    ///     .in_transient_scope()
+    ///     .when_injected_into::<Ninja>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    // A path to the method isn't general enough over the lifetime of the context
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn when_injected_into<Consumer>(
+        self,
+    ) -> Result<(), BindingWhenConfiguratorError>
+    where
+        Consumer: 'static + ?Sized,
+    {
+        self.set_condition(Arc::new(|context| context.is_injected_into::<Consumer>()))
+    }
+
+    /// Configures the binding to only be used when a `Ancestor` is being built somewhere
+    /// above it.
+    ///
+    /// A binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait ILogger {}
+    /// #
+    /// # struct AuditLogger {}
+    /// #
+    /// # #[injectable(ILogger)]
+    /// # impl AuditLogger
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl ILogger for AuditLogger {}
+    /// #
+    /// # struct PaymentService {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn ILogger>()
+    ///     .to::<AuditLogger>()?
+    ///     .in_transient_scope()
+    ///     .when_any_ancestor_is::<PaymentService>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    // A path to the method isn't general enough over the lifetime of the context
+    #[allow(clippy::redundant_closure_for_method_calls)]
+    pub fn when_any_ancestor_is<Ancestor>(
+        self,
+    ) -> Result<(), BindingWhenConfiguratorError>
+    where
+        Ancestor: 'static + ?Sized,
+    {
+        self.set_condition(Arc::new(|context| context.has_ancestor::<Ancestor>()))
+    }
+
+    /// Configures the binding to only be used when injected into something resolved
+    /// from a binding with the specified name.
+    ///
+    /// A binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IConnection {}
+    /// #
+    /// # struct ReplicaConnection {}
+    /// #
+    /// # #[injectable(IConnection)]
+    /// # impl ReplicaConnection
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IConnection for ReplicaConnection {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IConnection>()
+    ///     .to::<ReplicaConnection>()?
+    ///     .in_transient_scope()
+    ///     .when_parent_named("read_only")?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_parent_named(
+        self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<(), BindingWhenConfiguratorError>
+    {
+        let name = name.into();
+
+        self.set_condition(Arc::new(move |context| {
+            context.parent_name() == Some(name.as_ref())
+        }))
+    }
+
+    /// Configures the binding to only be used when `predicate` returns `true` for the
+    /// context it is resolved in.
+    ///
+    /// The predicate is evaluated every time the binding is about to be resolved. A
+    /// binding without a condition is used when no binding with a condition is
+    /// satisfied.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// #
+    /// # use syrette::{DIContainer, injectable};
+    /// #
+    /// # trait IRenderer {}
+    /// #
+    /// # struct VulkanRenderer {}
+    /// #
+    /// # #[injectable(IRenderer)]
+    /// # impl VulkanRenderer
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # impl IRenderer for VulkanRenderer {}
+    /// #
+    /// static USE_VULKAN: AtomicBool = AtomicBool::new(false);
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = DIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<dyn IRenderer>()
+    ///     .to::<VulkanRenderer>()?
+    ///     .in_transient_scope()
+    ///     .when(|_context| USE_VULKAN.load(Ordering::Relaxed))?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when<Predicate>(
+        self,
+        predicate: Predicate,
+    ) -> Result<(), BindingWhenConfiguratorError>
+    where
+        Predicate: Fn(&ResolutionContext<'_>) -> bool + Send + Sync + 'static,
+    {
+        self.set_condition(Arc::new(predicate))
+    }
+
+    fn set_condition(
+        mut self,
+        condition: BindingCondition,
+    ) -> Result<(), BindingWhenConfiguratorError>
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                BindingWhenConfiguratorError::BindingNotFound(type_name::<Interface>())
+            })?;
+
+        self.restore_replaced_binding();
+
+        // Bindings with a condition don't replace each other nor the binding without a
+        // condition
+        let binding_options = self.binding_options.additional();
+
+        self.di_container
+            .set_binding::<Interface>(binding_options.clone(), binding);
+
+        self.di_container
+            .set_binding_condition::<Interface>(binding_options, condition);
+
+        Ok(())
+    }
+
+    /// Puts back the binding that the binding replaced, as the binding no longer
+    /// replaces it once it's moved away from having no name, tags or condition.
+    fn restore_replaced_binding(&mut self)
+    {
+        if let Some(replaced_binding) = self.replaced_binding.take() {
+            replaced_binding.restore::<Interface>(self.di_container);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -93,6 +538,7 @@ mod tests
     use mockall::predicate::eq;
 
     use super::*;
+    use crate::dependency_history::DependencyHistory;
     use crate::di_container::blocking::MockDIContainer;
     use crate::provider::blocking::MockIProvider;
     use crate::test_utils::subjects;
@@ -110,13 +556,179 @@ mod tests
 
         di_container_mock
             .expect_set_binding::<dyn subjects::INumber>()
-            .withf(|options, _provider| options.name == Some("cool"))
+            .withf(|options, _provider| options.name.as_deref() == Some("cool"))
             .return_once(|_name, _provider| ())
             .once();
 
         let binding_when_configurator =
-            BindingWhenConfigurator::<dyn subjects::INumber>::new(&mut di_container_mock);
+            BindingWhenConfigurator::<dyn subjects::INumber>::new(
+                &mut di_container_mock,
+                BindingOptions::new(),
+            );
 
         assert!(binding_when_configurator.when_named("cool").is_ok());
     }
+
+    #[test]
+    fn when_tagged_works()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects::INumber>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_name| Some(Box::new(MockIProvider::new())))
+            .once();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects::INumber>()
+            .with(eq(BindingOptions::new().tag("region", "eu")))
+            .return_once(|_name| Some(Box::new(MockIProvider::new())))
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::INumber>()
+            .with(
+                eq(BindingOptions::new().tag("region", "eu")),
+                mockall::predicate::always(),
+            )
+            .return_once(|_name, _provider| ())
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::INumber>()
+            .with(
+                eq(BindingOptions::new()
+                    .tag("tier", "primary")
+                    .tag("region", "eu")),
+                mockall::predicate::always(),
+            )
+            .return_once(|_name, _provider| ())
+            .once();
+
+        let binding_when_configurator =
+            BindingWhenConfigurator::<dyn subjects::INumber>::new(
+                &mut di_container_mock,
+                BindingOptions::new(),
+            );
+
+        assert!(binding_when_configurator
+            .when_tagged("region", "eu")
+            .and_then(|configurator| configurator.when_tagged("tier", "primary"))
+            .is_ok());
+    }
+
+    #[test]
+    fn when_injected_into_works()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects::INumber>()
+            .with(eq(BindingOptions::new()))
+            .return_once(|_name| Some(Box::new(MockIProvider::new())))
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::INumber>()
+            .withf(|options, _provider| {
+                options.name.is_none() && options.additional_id.is_some()
+            })
+            .return_once(|_name, _provider| ())
+            .once();
+
+        di_container_mock
+            .expect_set_binding_condition::<dyn subjects::INumber>()
+            .withf(|options, condition| {
+                let mut dependency_history = DependencyHistory::new();
+
+                dependency_history.push::<subjects::UserManager>();
+
+                options.additional_id.is_some()
+                    && condition(&ResolutionContext::new(
+                        &dependency_history,
+                        &BindingOptions::new(),
+                    ))
+            })
+            .return_once(|_name, _condition| ())
+            .once();
+
+        let binding_when_configurator =
+            BindingWhenConfigurator::<dyn subjects::INumber>::new(
+                &mut di_container_mock,
+                BindingOptions::new(),
+            );
+
+        assert!(binding_when_configurator
+            .when_injected_into::<subjects::UserManager>()
+            .is_ok());
+    }
+
+    #[test]
+    fn when_works()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects::INumber>()
+            .with(eq(BindingOptions::new().name("foo")))
+            .return_once(|_name| Some(Box::new(MockIProvider::new())))
+            .once();
+
+        di_container_mock
+            .expect_set_binding::<dyn subjects::INumber>()
+            .withf(|options, _provider| {
+                options.name.as_deref() == Some("foo") && options.additional_id.is_some()
+            })
+            .return_once(|_name, _provider| ())
+            .once();
+
+        di_container_mock
+            .expect_set_binding_condition::<dyn subjects::INumber>()
+            .withf(|_options, condition| {
+                let dependency_history = DependencyHistory::new();
+
+                condition(&ResolutionContext::new(
+                    &dependency_history,
+                    &BindingOptions::new().name("foo"),
+                )) && !condition(&ResolutionContext::new(
+                    &dependency_history,
+                    &BindingOptions::new(),
+                ))
+            })
+            .return_once(|_name, _condition| ())
+            .once();
+
+        let binding_when_configurator =
+            BindingWhenConfigurator::<dyn subjects::INumber>::new(
+                &mut di_container_mock,
+                BindingOptions::new().name("foo"),
+            );
+
+        assert!(binding_when_configurator
+            .when(|context| context.requested_name() == Some("foo"))
+            .is_ok());
+    }
+
+    #[test]
+    fn when_parent_named_fails_without_binding()
+    {
+        let mut di_container_mock = MockDIContainer::new();
+
+        di_container_mock
+            .expect_remove_binding::<dyn subjects::INumber>()
+            .return_once(|_name| None)
+            .once();
+
+        let binding_when_configurator =
+            BindingWhenConfigurator::<dyn subjects::INumber>::new(
+                &mut di_container_mock,
+                BindingOptions::new(),
+            );
+
+        assert!(matches!(
+            binding_when_configurator.when_parent_named("foo"),
+            Err(BindingWhenConfiguratorError::BindingNotFound(_))
+        ));
+    }
 }
@@ -0,0 +1,114 @@
+//! Builder of frozen [`DIContainer`]s.
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::di_container::blocking::binding::builder::BindingBuilder;
+use crate::di_container::blocking::scope::Scope;
+use crate::di_container::blocking::DIContainer;
+use crate::errors::build::BuildError;
+
+/// Collects bindings and builds a [`FrozenDIContainer`] out of them. Created with
+/// [`DIContainer::builder`].
+#[derive(Default)]
+pub struct DIContainerBuilder
+{
+    di_container: DIContainer,
+}
+
+impl DIContainerBuilder
+{
+    /// Returns a new `DIContainerBuilder`.
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self {
+            di_container: DIContainer::new(),
+        }
+    }
+
+    /// Returns a new [`BindingBuilder`] for the given interface.
+    ///
+    /// See [`DIContainer::bind`].
+    pub fn bind<Interface>(&mut self) -> BindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.di_container.bind::<Interface>()
+    }
+
+    /// Returns a new [`BindingBuilder`] for the given interface that replaces the
+    /// existing binding of the interface without a name instead of failing.
+    ///
+    /// See [`DIContainer::rebind`].
+    pub fn rebind<Interface>(&mut self) -> BindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.di_container.rebind::<Interface>()
+    }
+
+    /// Builds a [`FrozenDIContainer`] out of the collected bindings.
+    ///
+    /// The bindings are validated and the singletons of the bindings in a lazy
+    /// singleton scope are resolved before the container is handed back.
+    ///
+    /// # Errors
+    /// Will return `Err` if the bindings are invalid or if resolving a lazy singleton
+    /// fails.
+    pub fn build(self) -> Result<FrozenDIContainer, BuildError>
+    {
+        self.di_container.validate()?;
+
+        let di_container = self.di_container.into_shared();
+
+        di_container.resolve_lazy_singletons()?;
+
+        Ok(FrozenDIContainer { di_container })
+    }
+}
+
+/// Cheaply clonable handle to a [`DIContainer`] that can no longer be modified.
+/// Created with [`DIContainerBuilder::build`].
+///
+/// Since a [`DIContainer`] can't be shared between threads, the handle is backed by a
+/// [`Rc`]. Use [`ThreadsafeDIContainer::builder`] to build a [`Arc`]-backed handle that
+/// can be.
+///
+/// [`ThreadsafeDIContainer::builder`]: crate::ThreadsafeDIContainer::builder
+/// [`Arc`]: std::sync::Arc
+#[derive(Clone)]
+pub struct FrozenDIContainer
+{
+    di_container: Rc<DIContainer>,
+}
+
+impl FrozenDIContainer
+{
+    /// Begins a new [`Scope`] of the container.
+    ///
+    /// See [`DIContainer::begin_scope`].
+    #[must_use]
+    pub fn begin_scope(&self) -> Scope
+    {
+        self.di_container.begin_scope()
+    }
+
+    /// Returns a new child [`DIContainer`] of the container.
+    ///
+    /// See [`DIContainer::create_child`].
+    #[must_use]
+    pub fn create_child(&self) -> DIContainer
+    {
+        self.di_container.create_child()
+    }
+}
+
+impl Deref for FrozenDIContainer
+{
+    type Target = DIContainer;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.di_container
+    }
+}
@@ -0,0 +1,33 @@
+//! Scope of a [`DIContainer`].
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::di_container::blocking::DIContainer;
+
+/// Handle to a scope of a [`DIContainer`]. Created with [`DIContainer::begin_scope`].
+///
+/// Derefs to a [`DIContainer`] that shares a single instance of every binding in a
+/// scoped scope between all resolutions made through it. The instances are dropped
+/// together with the `Scope`.
+pub struct Scope
+{
+    di_container: Rc<DIContainer>,
+}
+
+impl Scope
+{
+    pub(crate) fn new(di_container: Rc<DIContainer>) -> Self
+    {
+        Self { di_container }
+    }
+}
+
+impl Deref for Scope
+{
+    type Target = DIContainer;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.di_container
+    }
+}
@@ -0,0 +1,188 @@
+//! Bookkeeping for [`WeakSingletonPtr`] back-references.
+use std::any::{Any, TypeId};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+use crate::di_container::{BindingOptions, BindingKeyId};
+use crate::ptr::{SingletonPtr, WeakSingletonPtr};
+
+/// A singleton binding that is being resolved.
+struct ResolvingBinding
+{
+    interface: TypeId,
+    name: Option<Cow<'static, str>>,
+    key: Option<BindingKeyId>,
+    pending: Vec<Box<dyn Any>>,
+}
+
+impl ResolvingBinding
+{
+    fn is_binding<Interface>(&self, binding_options: &BindingOptions) -> bool
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.interface == TypeId::of::<Interface>()
+            && self.name.as_deref() == binding_options.name.as_deref()
+            && self.key == binding_options.key
+    }
+}
+
+/// Keeps track of the singleton bindings being resolved and the [`WeakSingletonPtr`]s
+/// waiting for them to finish.
+#[derive(Default)]
+pub(crate) struct WeakSingletons
+{
+    resolving: RefCell<Vec<ResolvingBinding>>,
+}
+
+impl WeakSingletons
+{
+    pub(crate) fn new() -> Self
+    {
+        Self::default()
+    }
+
+    pub(crate) fn start_resolving<Interface>(
+        &self,
+        binding_options: &BindingOptions<'static>,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        self.resolving.borrow_mut().push(ResolvingBinding {
+            interface: TypeId::of::<Interface>(),
+            name: binding_options.name.clone(),
+            key: binding_options.key,
+            pending: Vec::new(),
+        });
+    }
+
+    /// Stops tracking the latest resolution of the binding. Every [`WeakSingletonPtr`]
+    /// waiting for it is given the singleton, if any, once no resolution of the binding
+    /// is left.
+    pub(crate) fn finish_resolving<Interface>(
+        &self,
+        binding_options: &BindingOptions,
+        singleton: Option<&SingletonPtr<Interface>>,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        let mut resolving = self.resolving.borrow_mut();
+
+        let pending = match resolving
+            .iter()
+            .rposition(|binding| binding.is_binding::<Interface>(binding_options))
+        {
+            Some(index) => resolving.remove(index).pending,
+            None => return,
+        };
+
+        if let Some(outer_binding) = resolving
+            .iter_mut()
+            .rfind(|binding| binding.is_binding::<Interface>(binding_options))
+        {
+            outer_binding.pending.extend(pending);
+            return;
+        }
+
+        drop(resolving);
+
+        if let Some(singleton) = singleton {
+            for weak_singleton in pending {
+                if let Some(weak_singleton) =
+                    weak_singleton.downcast_ref::<WeakSingletonPtr<Interface>>()
+                {
+                    weak_singleton.fill(singleton);
+                }
+            }
+        }
+    }
+
+    /// Returns a empty [`WeakSingletonPtr`] that is filled when the binding finishes
+    /// resolving. Returns `None` if the binding isn't being resolved.
+    pub(crate) fn add_pending<Interface>(
+        &self,
+        binding_options: &BindingOptions,
+    ) -> Option<WeakSingletonPtr<Interface>>
+    where
+        Interface: 'static + ?Sized,
+    {
+        let mut resolving = self.resolving.borrow_mut();
+
+        let binding = resolving
+            .iter_mut()
+            .rfind(|binding| binding.is_binding::<Interface>(binding_options))?;
+
+        let weak_singleton = WeakSingletonPtr::new();
+
+        binding.pending.push(Box::new(weak_singleton.clone()));
+
+        Some(weak_singleton)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::test_utils::subjects;
+
+    #[test]
+    fn fills_pending_when_finished()
+    {
+        let weak_singletons = WeakSingletons::new();
+
+        weak_singletons.start_resolving::<dyn subjects::INumber>(&BindingOptions::new());
+
+        assert!(weak_singletons
+            .add_pending::<dyn subjects::INumber>(&BindingOptions::new().name("foo"))
+            .is_none());
+
+        let weak_singleton = weak_singletons
+            .add_pending::<dyn subjects::INumber>(&BindingOptions::new())
+            .expect("Expected binding to be resolving");
+
+        assert!(weak_singleton.upgrade().is_none());
+
+        let singleton: SingletonPtr<dyn subjects::INumber> =
+            SingletonPtr::new(subjects::Number { num: 7 });
+
+        weak_singletons.finish_resolving(&BindingOptions::new(), Some(&singleton));
+
+        assert!(weak_singletons
+            .add_pending::<dyn subjects::INumber>(&BindingOptions::new())
+            .is_none());
+
+        assert_eq!(
+            weak_singleton
+                .upgrade()
+                .expect("Expected weak singleton to be filled")
+                .get(),
+            7
+        );
+    }
+
+    #[test]
+    fn waits_for_outermost_resolution()
+    {
+        let weak_singletons = WeakSingletons::new();
+
+        weak_singletons.start_resolving::<dyn subjects::INumber>(&BindingOptions::new());
+        weak_singletons.start_resolving::<dyn subjects::INumber>(&BindingOptions::new());
+
+        let weak_singleton = weak_singletons
+            .add_pending::<dyn subjects::INumber>(&BindingOptions::new())
+            .expect("Expected binding to be resolving");
+
+        weak_singletons
+            .finish_resolving::<dyn subjects::INumber>(&BindingOptions::new(), None);
+
+        assert!(weak_singleton.upgrade().is_none());
+
+        let singleton: SingletonPtr<dyn subjects::INumber> =
+            SingletonPtr::new(subjects::Number { num: 3 });
+
+        weak_singletons.finish_resolving(&BindingOptions::new(), Some(&singleton));
+
+        assert!(weak_singleton.upgrade().is_some());
+    }
+}
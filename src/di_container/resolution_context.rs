@@ -0,0 +1,185 @@
+//! Context in which a binding is resolved.
+use std::any::type_name;
+use std::sync::Arc;
+
+use crate::dependency_history::DependencyHistory;
+use crate::di_container::BindingOptions;
+
+/// Condition deciding whether or not a binding is used in a [`ResolutionContext`].
+pub(crate) type BindingCondition =
+    Arc<dyn Fn(&ResolutionContext<'_>) -> bool + Send + Sync>;
+
+/// Context in which a binding is resolved.
+///
+/// Describes the chain of injectables currently being built, from the one asked for
+/// from the DI container down to the one that depends on the binding, along with the
+/// name and tags that the binding is requested with.
+#[derive(Debug)]
+pub struct ResolutionContext<'context>
+{
+    dependency_history: &'context DependencyHistory,
+    request: &'context BindingOptions<'context>,
+}
+
+impl<'context> ResolutionContext<'context>
+{
+    pub(crate) fn new(
+        dependency_history: &'context DependencyHistory,
+        request: &'context BindingOptions<'context>,
+    ) -> Self
+    {
+        Self {
+            dependency_history,
+            request,
+        }
+    }
+
+    /// Returns the type names of the injectables being built, starting with the one
+    /// asked for from the DI container and ending with the one that depends on the
+    /// binding.
+    #[must_use]
+    pub fn type_chain(&self) -> &[&'static str]
+    {
+        self.dependency_history.as_slice()
+    }
+
+    /// Returns the name that the binding is requested with, if any.
+    #[must_use]
+    pub fn requested_name(&self) -> Option<&str>
+    {
+        self.request.name.as_deref()
+    }
+
+    /// Returns the tags that the binding is requested with, sorted by key.
+    #[must_use]
+    pub fn requested_tags(&self) -> &[(&str, &str)]
+    {
+        &self.request.tags
+    }
+
+    /// Returns the value of the tag with the key `key` that the binding is requested
+    /// with, if any.
+    #[must_use]
+    pub fn requested_tag(&self, key: &str) -> Option<&str>
+    {
+        self.request
+            .tags
+            .iter()
+            .find(|(tag_key, _)| *tag_key == key)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns the type name of the injectable that depends on the binding, if any.
+    #[must_use]
+    pub fn parent_type_name(&self) -> Option<&'static str>
+    {
+        self.dependency_history.last()
+    }
+
+    /// Returns the name of the binding that the injectable depending on the binding
+    /// was resolved from, if any.
+    #[must_use]
+    pub fn parent_name(&self) -> Option<&str>
+    {
+        self.dependency_history.last_binding_name()
+    }
+
+    /// Returns whether or not the binding is being injected into a `Consumer`.
+    #[must_use]
+    pub fn is_injected_into<Consumer>(&self) -> bool
+    where
+        Consumer: 'static + ?Sized,
+    {
+        self.parent_type_name() == Some(type_name::<Consumer>())
+    }
+
+    /// Returns whether or not a `Ancestor` is being built somewhere above the binding.
+    #[must_use]
+    pub fn has_ancestor<Ancestor>(&self) -> bool
+    where
+        Ancestor: 'static + ?Sized,
+    {
+        self.dependency_history.contains::<Ancestor>()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::test_utils::subjects;
+
+    #[test]
+    fn can_get_parent()
+    {
+        let mut dependency_history = DependencyHistory::new();
+
+        assert!(ResolutionContext::new(&dependency_history, &BindingOptions::new())
+            .parent_type_name()
+            .is_none());
+
+        dependency_history.push::<subjects::UserManager>();
+
+        dependency_history.set_next_binding_name(Some("foo".into()));
+        dependency_history.push::<subjects::Number>();
+
+        let request = BindingOptions::new();
+
+        let context = ResolutionContext::new(&dependency_history, &request);
+
+        assert_eq!(
+            context.parent_type_name(),
+            Some(type_name::<subjects::Number>())
+        );
+        assert_eq!(context.parent_name(), Some("foo"));
+
+        assert!(context.is_injected_into::<subjects::Number>());
+        assert!(!context.is_injected_into::<subjects::UserManager>());
+    }
+
+    #[test]
+    fn can_check_ancestors()
+    {
+        let mut dependency_history = DependencyHistory::new();
+
+        dependency_history.push::<subjects::UserManager>();
+        dependency_history.push::<subjects::Number>();
+
+        let request = BindingOptions::new();
+
+        let context = ResolutionContext::new(&dependency_history, &request);
+
+        assert!(context.has_ancestor::<subjects::UserManager>());
+        assert!(context.has_ancestor::<subjects::Number>());
+        assert!(!context.has_ancestor::<dyn subjects::INumber>());
+
+        assert_eq!(
+            context.type_chain(),
+            [
+                type_name::<subjects::UserManager>(),
+                type_name::<subjects::Number>()
+            ]
+        );
+    }
+
+    #[test]
+    fn can_get_request()
+    {
+        let dependency_history = DependencyHistory::new();
+
+        let request = BindingOptions::new()
+            .name("foo")
+            .tag("region", "eu")
+            .tag("access", "read_only");
+
+        let context = ResolutionContext::new(&dependency_history, &request);
+
+        assert_eq!(context.requested_name(), Some("foo"));
+        assert_eq!(
+            context.requested_tags(),
+            [("access", "read_only"), ("region", "eu")]
+        );
+        assert_eq!(context.requested_tag("region"), Some("eu"));
+        assert!(context.requested_tag("tier").is_none());
+    }
+}
@@ -0,0 +1,693 @@
+//! Thread-safe blocking dependency injection container.
+//!
+//! # Examples
+//! ```
+//! use std::error::Error;
+//! use std::sync::Arc;
+//! use std::thread;
+//!
+//! use syrette::{injectable, ThreadsafeDIContainer};
+//!
+//! trait IMetrics: Send + Sync
+//! {
+//!     fn record(&self, name: &str);
+//! }
+//!
+//! struct Metrics {}
+//!
+//! #[injectable(IMetrics, threadsafe = true)]
+//! impl Metrics
+//! {
+//!     fn new() -> Self
+//!     {
+//!         Self {}
+//!     }
+//! }
+//!
+//! impl IMetrics for Metrics
+//! {
+//!     fn record(&self, name: &str)
+//!     {
+//!         // Do stuff here
+//!     }
+//! }
+//!
+//! fn main() -> Result<(), Box<dyn Error>>
+//! {
+//!     let mut di_container = ThreadsafeDIContainer::new();
+//!
+//!     di_container
+//!         .bind::<dyn IMetrics>()
+//!         .to::<Metrics>()?
+//!         .in_singleton_scope()?;
+//!
+//!     let di_container = Arc::new(di_container);
+//!
+//!     let worker = thread::spawn({
+//!         let di_container = di_container.clone();
+//!
+//!         move || {
+//!             let metrics = di_container
+//!                 .get::<dyn IMetrics>()
+//!                 .unwrap()
+//!                 .threadsafe_singleton()
+//!                 .unwrap();
+//!
+//!             metrics.record("worker_started");
+//!         }
+//!     });
+//!
+//!     worker.join().unwrap();
+//!
+//!     Ok(())
+//! }
+//! ```
+use std::any::type_name;
+use std::collections::HashMap;
+
+use crate::castable_function::threadsafe::ThreadsafeCastableFunction;
+use crate::di_container::binding_lookup::BindingLookup;
+use crate::di_container::binding_storage::DIContainerBindingStorage;
+use crate::di_container::threadsafe::binding::builder::ThreadsafeBindingBuilder;
+use crate::di_container::threadsafe::frozen::ThreadsafeDIContainerBuilder;
+use crate::di_container::validation::Validator;
+use crate::di_container::{BindingKey, BindingOptions, BindingScope};
+use crate::errors::build::BuildError;
+use crate::errors::di_container::DIContainerError;
+use crate::errors::validation::ValidationError;
+use crate::private::cast::arc::CastArc;
+use crate::private::cast::boxed::CastBox;
+use crate::provider::blocking::ProvidableFunctionKind;
+use crate::provider::threadsafe::{IThreadsafeProvider, ThreadsafeProvidable};
+use crate::ptr::{SomePtr, TransientPtr};
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+pub mod binding;
+pub mod frozen;
+
+/// Thread-safe blocking dependency injection container.
+///
+/// Works like [`DIContainer`] but can be shared between threads. Singletons are
+/// handed out as [`ThreadsafeSingletonPtr`]s & factories as
+/// [`ThreadsafeFactoryPtr`]s.
+///
+/// Types are made injectable into it with `#[injectable(threadsafe = true)]`.
+///
+/// It covers binding & resolving only. Unlike [`DIContainer`], it has no child
+/// containers, scopes, lifecycle hooks, deferred dependencies or `rebind`. A binding is
+/// replaced by unbinding it with [`unbind`](Self::unbind) and binding the interface
+/// again.
+///
+/// [`DIContainer`]: crate::di_container::blocking::DIContainer
+/// [`ThreadsafeSingletonPtr`]: crate::ptr::ThreadsafeSingletonPtr
+/// [`ThreadsafeFactoryPtr`]: crate::ptr::ThreadsafeFactoryPtr
+#[derive(Default)]
+pub struct ThreadsafeDIContainer
+{
+    binding_storage: DIContainerBindingStorage<dyn IThreadsafeProvider<Self>>,
+}
+
+impl ThreadsafeDIContainer
+{
+    /// Returns a new `ThreadsafeDIContainer`.
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self {
+            binding_storage: DIContainerBindingStorage::new(),
+        }
+    }
+
+    /// Returns a new [`ThreadsafeDIContainerBuilder`] for building a
+    /// [`FrozenThreadsafeDIContainer`], which unlike a frozen [`DIContainer`] can be
+    /// shared between threads.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::thread;
+    /// #
+    /// # use syrette::{ThreadsafeDIContainer, injectable};
+    /// #
+    /// # struct Clock {}
+    /// #
+    /// # #[injectable(threadsafe = true)]
+    /// # impl Clock
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container_builder = ThreadsafeDIContainer::builder();
+    ///
+    /// di_container_builder.bind::<Clock>().to::<Clock>()?;
+    ///
+    /// let di_container = di_container_builder.build()?;
+    ///
+    /// thread::spawn(move || {
+    ///     let clock = di_container.get::<Clock>().unwrap().transient().unwrap();
+    /// })
+    /// .join()
+    /// .unwrap();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`FrozenThreadsafeDIContainer`]: crate::di_container::threadsafe::frozen::FrozenThreadsafeDIContainer
+    /// [`DIContainer`]: crate::di_container::blocking::DIContainer
+    #[must_use]
+    pub fn builder() -> ThreadsafeDIContainerBuilder
+    {
+        ThreadsafeDIContainerBuilder::new()
+    }
+
+    /// Returns a new [`ThreadsafeBindingBuilder`] for the given interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{ThreadsafeDIContainer, injectable};
+    /// #
+    /// # struct DiskWriter {}
+    /// #
+    /// # #[injectable(threadsafe = true)]
+    /// # impl DiskWriter
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = ThreadsafeDIContainer::new();
+    ///
+    /// di_container.bind::<DiskWriter>().to::<DiskWriter>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bind<Interface>(&mut self) -> ThreadsafeBindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        ThreadsafeBindingBuilder::new(self, DependencyHistory::new)
+    }
+
+    /// Removes the binding of `Interface` that doesn't have a name.
+    ///
+    /// # Errors
+    /// Will return `Err` if no such binding exists.
+    pub fn unbind<Interface>(&mut self) -> Result<(), DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.remove_binding::<Interface>(BindingOptions::new())
+            .ok_or_else(|| DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: None,
+                key: None,
+                searched_levels: 1,
+            })?;
+
+        Ok(())
+    }
+
+    /// Removes the binding of `Interface` with the specified name.
+    ///
+    /// # Errors
+    /// Will return `Err` if no such binding exists.
+    pub fn unbind_named<Interface>(&mut self, name: &str) -> Result<(), DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.remove_binding::<Interface>(BindingOptions::new().name(name.to_string()))
+            .ok_or_else(|| DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: Some(name.to_string()),
+                key: None,
+                searched_levels: 1,
+            })?;
+
+        Ok(())
+    }
+
+    /// Removes all of the bindings of this container.
+    pub fn unbind_all(&mut self)
+    {
+        self.binding_storage = DIContainerBindingStorage::new();
+    }
+
+    /// Returns the type bound with `Interface`.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{ThreadsafeDIContainer, injectable};
+    /// #
+    /// # struct DeviceManager {}
+    /// #
+    /// # #[injectable(threadsafe = true)]
+    /// # impl DeviceManager
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = ThreadsafeDIContainer::new();
+    ///
+    /// di_container.bind::<DeviceManager>().to::<DeviceManager>()?;
+    ///
+    /// let device_manager = di_container.get::<DeviceManager>()?.transient();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get<Interface>(&self) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_bound::<Interface>(DependencyHistory::new(), BindingOptions::new())
+    }
+
+    /// Returns the type bound with `Interface` and the specified name.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with name `name` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    pub fn get_named<Interface>(
+        &self,
+        name: &str,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_bound::<Interface>(
+            DependencyHistory::new(),
+            BindingOptions::new().name(name),
+        )
+    }
+
+    /// Returns the type bound with `Interface` and the specified binding key.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with the key exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    pub fn get_keyed<Interface, Key>(
+        &self,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+        Key: BindingKey,
+    {
+        self.get_bound::<Interface>(
+            DependencyHistory::new(),
+            BindingOptions::new().keyed::<Key>(),
+        )
+    }
+
+    /// Returns the type bound with `Interface` and the specified name or, if no binding
+    /// has the name, the type bound with `Interface` without a name.
+    ///
+    /// Unlike this method, [`get_named`](Self::get_named) never falls back to the binding
+    /// without a name.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with name `name` nor without a name exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    pub fn get_named_or_default<Interface>(
+        &self,
+        name: &str,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        match self.get_named::<Interface>(name) {
+            Err(err @ DIContainerError::BindingNotFound { .. }) => {
+                match self.get::<Interface>() {
+                    // The error of the named request tells what was requested
+                    Err(DIContainerError::BindingNotFound { .. }) => Err(err),
+                    result => result,
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Returns the type bound with `Interface` where the binding has the specified tags.
+    ///
+    /// A binding with more tags than the specified ones also matches, as long as no other
+    /// binding does.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` with the tags exists
+    /// - More than one binding for `Interface` with the tags exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    pub fn get_tagged<Interface>(
+        &self,
+        tags: &[(&'static str, &'static str)],
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_bound::<Interface>(
+            DependencyHistory::new(),
+            tags.iter()
+                .fold(BindingOptions::new(), |binding_options, (key, value)| {
+                    binding_options.tag(key, value)
+                }),
+        )
+    }
+
+    /// Returns the type bound with `Interface` where the binding has the specified
+    /// options.
+    ///
+    /// `dependency_history` is passed to the bound type when it is being resolved.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - No binding for `Interface` exists
+    /// - Resolving the binding for `Interface` fails
+    /// - Casting the binding for `Interface` fails
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn get_bound<Interface>(
+        &self,
+        mut dependency_history: DependencyHistory,
+        binding_options: BindingOptions<'_>,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        let (bound_options, provider) = self
+            .binding_storage
+            .find_single::<Interface>(&binding_options, |condition| {
+                dependency_history.satisfies(condition, &binding_options)
+            })
+            .map_err(|matching| DIContainerError::AmbiguousBinding {
+                interface: type_name::<Interface>(),
+                name: binding_options.name.as_deref().map(ToString::to_string),
+                key: binding_options.key.as_ref().map(ToString::to_string),
+                matching,
+            })?
+            .ok_or_else(|| DIContainerError::BindingNotFound {
+                interface: type_name::<Interface>(),
+                name: binding_options.name.as_deref().map(ToString::to_string),
+                key: binding_options.key.as_ref().map(ToString::to_string),
+                searched_levels: 1,
+            })?;
+
+        dependency_history.set_next_binding_name(bound_options.name.clone());
+
+        let binding_providable =
+            provider.provide(self, dependency_history).map_err(|err| {
+                DIContainerError::BindingResolveFailed {
+                    reason: err,
+                    interface: type_name::<Interface>(),
+                }
+            })?;
+
+        self.handle_binding_providable(binding_providable)
+    }
+
+    /// Returns all of the types bound with `Interface` without a name or tags, in the
+    /// order they were bound in.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    pub fn get_all<Interface>(&self) -> Result<Vec<SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_all_bound::<Interface>(DependencyHistory::new())
+    }
+
+    /// Returns all of the types bound with `Interface` without a name or tags, in the
+    /// order they were bound in.
+    ///
+    /// `dependency_history` is passed to each of the bound types when they are being
+    /// resolved.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn get_all_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+    ) -> Result<Vec<SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_all_binding_providables::<Interface>(
+            BindingOptions::is_unqualified,
+            &dependency_history,
+        )?
+        .into_iter()
+        .map(|(_, binding_providable)| self.handle_binding_providable(binding_providable))
+        .collect()
+    }
+
+    /// Returns all of the types bound with `Interface` and a name but no tags, keyed by
+    /// name.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    pub fn get_all_named<Interface>(
+        &self,
+    ) -> Result<HashMap<String, SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_all_named_bound::<Interface>(DependencyHistory::new())
+    }
+
+    /// Returns all of the types bound with `Interface` and a name but no tags, keyed by
+    /// name.
+    ///
+    /// `dependency_history` is passed to each of the bound types when they are being
+    /// resolved.
+    ///
+    /// # Errors
+    /// Will return `Err` if:
+    /// - Resolving a binding for `Interface` fails
+    /// - Casting a binding for `Interface` fails
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn get_all_named_bound<Interface>(
+        &self,
+        dependency_history: DependencyHistory,
+    ) -> Result<HashMap<String, SomePtr<Interface>>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.get_all_binding_providables::<Interface>(
+            |binding_options| {
+                binding_options.name.is_some() && binding_options.tags.is_empty()
+            },
+            &dependency_history,
+        )?
+        .into_iter()
+        .filter_map(|(binding_options, binding_providable)| {
+            let name = binding_options.name?.to_string();
+
+            Some(
+                self.handle_binding_providable(binding_providable)
+                    .map(|ptr| (name, ptr)),
+            )
+        })
+        .collect()
+    }
+
+    /// Validates that every binding of this `ThreadsafeDIContainer` can be resolved,
+    /// without constructing anything.
+    ///
+    /// # Errors
+    /// Will return `Err` containing every:
+    /// - Dependency without a binding or with more than one matching binding
+    /// - Chain of injectables depending on each other
+    pub fn validate(&self) -> Result<(), ValidationError>
+    {
+        Validator::new(
+            BindingLookup::new(vec![&self.binding_storage]),
+            IThreadsafeProvider::injectable,
+        )
+        .validate()
+    }
+
+    /// Resolves the singletons of the bindings in a lazy singleton scope that haven't
+    /// been resolved yet.
+    pub(crate) fn resolve_lazy_singletons(&self) -> Result<(), BuildError>
+    {
+        for binding in self.binding_storage.iter() {
+            if binding.provider.scope() != BindingScope::LazySingleton
+                || binding.provider.is_singleton_built()
+            {
+                continue;
+            }
+
+            binding
+                .provider
+                .provide(self, DependencyHistory::new())
+                .map_err(|reason| BuildError::LazySingletonResolveFailed {
+                    reason,
+                    service: binding
+                        .provider
+                        .injectable()
+                        .map_or(binding.interface.name, |injectable| {
+                            injectable.type_name
+                        }),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    fn has_binding<Interface>(&self, binding_options: BindingOptions) -> bool
+    where
+        Interface: ?Sized + 'static,
+    {
+        self.binding_storage.has::<Interface>(binding_options)
+    }
+
+    fn set_binding<Interface>(
+        &mut self,
+        binding_options: BindingOptions<'static>,
+        provider: Box<dyn IThreadsafeProvider<Self>>,
+    ) where
+        Interface: 'static + ?Sized,
+    {
+        self.binding_storage
+            .set::<Interface>(binding_options, provider);
+    }
+
+    fn remove_binding<Interface>(
+        &mut self,
+        binding_options: BindingOptions<'static>,
+    ) -> Option<Box<dyn IThreadsafeProvider<Self>>>
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.binding_storage.remove::<Interface>(binding_options)
+    }
+
+    fn handle_binding_providable<Interface>(
+        &self,
+        binding_providable: ThreadsafeProvidable<Self>,
+    ) -> Result<SomePtr<Interface>, DIContainerError>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        match binding_providable {
+            ThreadsafeProvidable::Transient(transient_binding) => Ok(SomePtr::Transient(
+                transient_binding.cast::<Interface>().map_err(|_| {
+                    DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "transient",
+                    }
+                })?,
+            )),
+            ThreadsafeProvidable::Singleton(singleton_binding) => {
+                Ok(SomePtr::ThreadsafeSingleton(
+                    singleton_binding.cast::<Interface>().map_err(|_| {
+                        DIContainerError::CastFailed {
+                            interface: type_name::<Interface>(),
+                            binding_kind: "singleton",
+                        }
+                    })?,
+                ))
+            }
+            #[cfg(feature = "factory")]
+            ThreadsafeProvidable::Function(
+                func_bound,
+                ProvidableFunctionKind::UserCalled,
+            ) => {
+                let factory = func_bound
+                    .as_any()
+                    .downcast_ref::<ThreadsafeCastableFunction<Interface, Self>>()
+                    .ok_or_else(|| DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "factory",
+                    })?;
+
+                Ok(SomePtr::ThreadsafeFactory(factory.call(self).into()))
+            }
+            ThreadsafeProvidable::Function(
+                func_bound,
+                ProvidableFunctionKind::Instant,
+            ) => {
+                type Func<Interface> = ThreadsafeCastableFunction<
+                    dyn Fn() -> TransientPtr<Interface> + Send + Sync,
+                    ThreadsafeDIContainer,
+                >;
+
+                let dynamic_val_func = func_bound
+                    .as_any()
+                    .downcast_ref::<Func<Interface>>()
+                    .ok_or_else(|| DIContainerError::CastFailed {
+                        interface: type_name::<Interface>(),
+                        binding_kind: "dynamic value function",
+                    })?;
+
+                Ok(SomePtr::Transient(dynamic_val_func.call(self)()))
+            }
+        }
+    }
+
+    fn get_all_binding_providables<Interface>(
+        &self,
+        filter: impl Fn(&BindingOptions<'static>) -> bool,
+        dependency_history: &DependencyHistory,
+    ) -> Result<
+        Vec<(BindingOptions<'static>, ThreadsafeProvidable<Self>)>,
+        DIContainerError,
+    >
+    where
+        Interface: 'static + ?Sized,
+    {
+        self.binding_storage
+            .get_all_satisfied::<Interface>(|condition, binding_options| {
+                dependency_history.satisfies(condition, binding_options)
+            })
+            .into_iter()
+            .filter(|(binding_options, _)| filter(binding_options))
+            .map(|(binding_options, provider)| {
+                let mut dependency_history = dependency_history.clone();
+
+                dependency_history.set_next_binding_name(binding_options.name.clone());
+
+                let binding_providable = provider
+                    .provide(self, dependency_history)
+                    .map_err(|err| DIContainerError::BindingResolveFailed {
+                        reason: err,
+                        interface: type_name::<Interface>(),
+                    })?;
+
+                Ok((binding_options.clone(), binding_providable))
+            })
+            .collect()
+    }
+}
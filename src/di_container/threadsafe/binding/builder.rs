@@ -0,0 +1,301 @@
+//! Binding builder for types inside of a [`ThreadsafeDIContainer`].
+use std::any::type_name;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::castable_function::threadsafe::ThreadsafeCastableFunction;
+use crate::di_container::threadsafe::binding::scope_configurator::ThreadsafeBindingScopeConfigurator;
+use crate::di_container::threadsafe::binding::when_configurator::ThreadsafeBindingWhenConfigurator;
+use crate::di_container::threadsafe::ThreadsafeDIContainer;
+use crate::di_container::BindingOptions;
+use crate::errors::di_container::BindingBuilderError;
+use crate::interfaces::threadsafe_injectable::ThreadsafeInjectable;
+use crate::provider::blocking::ProvidableFunctionKind;
+use crate::provider::threadsafe::ThreadsafeFunctionProvider;
+use crate::ptr::TransientPtr;
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+/// Binding builder for type `Interface` inside a [`ThreadsafeDIContainer`].
+#[must_use = "No binding will be created if you don't use the binding builder"]
+pub struct ThreadsafeBindingBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    di_container: &'di_container mut ThreadsafeDIContainer,
+    dependency_history_factory: fn() -> DependencyHistory,
+
+    interface_phantom: PhantomData<Interface>,
+}
+
+impl<'di_container, Interface> ThreadsafeBindingBuilder<'di_container, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    pub(crate) fn new(
+        di_container: &'di_container mut ThreadsafeDIContainer,
+        dependency_history_factory: fn() -> DependencyHistory,
+    ) -> Self
+    {
+        Self {
+            di_container,
+            dependency_history_factory,
+            interface_phantom: PhantomData,
+        }
+    }
+
+    /// Creates a binding of type `Interface` to type `Implementation` inside of the
+    /// associated [`ThreadsafeDIContainer`].
+    ///
+    /// The scope of the binding is transient. But that can be changed by using the
+    /// returned [`ThreadsafeBindingScopeConfigurator`]
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`ThreadsafeDIContainer`] already have a
+    /// binding for the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::{ThreadsafeDIContainer, injectable};
+    /// #
+    /// # trait Foo: Send + Sync {}
+    /// #
+    /// # struct Bar {}
+    /// #
+    /// # #[injectable(Foo, threadsafe = true)]
+    /// # impl Bar {
+    /// #   fn new() -> Self
+    /// #   {
+    /// #       Self {}
+    /// #   }
+    /// # }
+    /// #
+    /// # impl Foo for Bar {}
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = ThreadsafeDIContainer::new();
+    /// #
+    /// di_container.bind::<dyn Foo>().to::<Bar>();
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to<Implementation>(
+        self,
+    ) -> Result<
+        ThreadsafeBindingScopeConfigurator<'di_container, Interface, Implementation>,
+        BindingBuilderError,
+    >
+    where
+        Implementation: ThreadsafeInjectable<ThreadsafeDIContainer>,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        let mut binding_scope_configurator = ThreadsafeBindingScopeConfigurator::new(
+            self.di_container,
+            self.dependency_history_factory,
+            BindingOptions::new(),
+        );
+
+        binding_scope_configurator.set_in_transient_scope();
+
+        Ok(binding_scope_configurator)
+    }
+
+    /// Adds a binding of type `Interface` to type `Implementation` inside of the
+    /// associated [`ThreadsafeDIContainer`], next to any other bindings of the
+    /// interface.
+    ///
+    /// Unlike [`to`], this never fails because of an existing binding. All of the
+    /// bindings of the interface are resolved with [`ThreadsafeDIContainer::get_all`],
+    /// in the order they were added.
+    ///
+    /// [`to`]: Self::to
+    #[allow(clippy::must_use_candidate)]
+    pub fn to_additional<Implementation>(
+        self,
+    ) -> ThreadsafeBindingScopeConfigurator<'di_container, Interface, Implementation>
+    where
+        Implementation: ThreadsafeInjectable<ThreadsafeDIContainer>,
+    {
+        let mut binding_scope_configurator = ThreadsafeBindingScopeConfigurator::new(
+            self.di_container,
+            self.dependency_history_factory,
+            BindingOptions::new().additional(),
+        );
+
+        binding_scope_configurator.set_in_transient_scope();
+
+        binding_scope_configurator
+    }
+
+    /// Creates a binding of factory type `Interface` to a factory inside of the
+    /// associated [`ThreadsafeDIContainer`].
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`ThreadsafeDIContainer`] already have a
+    /// binding for the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::ThreadsafeDIContainer;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IConnection: Send + Sync {}
+    /// #
+    /// # struct Connection
+    /// # {
+    /// #   address: String,
+    /// # }
+    /// #
+    /// # impl IConnection for Connection {}
+    /// #
+    /// # type IConnectionFactory =
+    /// #     dyn Fn(String) -> TransientPtr<dyn IConnection> + Send + Sync;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = ThreadsafeDIContainer::new();
+    /// #
+    /// di_container.bind::<IConnectionFactory>().to_factory(&|_| {
+    ///     Box::new(|address| {
+    ///         TransientPtr::new(Connection { address }) as TransientPtr<dyn IConnection>
+    ///     })
+    /// })?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "factory")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
+    pub fn to_factory<Args, Return, Func>(
+        self,
+        factory_func: &'static Func,
+    ) -> Result<
+        ThreadsafeBindingWhenConfigurator<'di_container, Interface>,
+        BindingBuilderError,
+    >
+    where
+        Args: std::marker::Tuple + 'static,
+        Return: 'static + ?Sized,
+        Interface: Fn<Args, Output = TransientPtr<Return>>,
+        Func: Fn(&ThreadsafeDIContainer) -> Box<Interface> + Send + Sync,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        let factory_impl = ThreadsafeCastableFunction::new(factory_func);
+
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(ThreadsafeFunctionProvider::new(
+                Arc::new(factory_impl),
+                ProvidableFunctionKind::UserCalled,
+            )),
+        );
+
+        Ok(ThreadsafeBindingWhenConfigurator::new(
+            self.di_container,
+            BindingOptions::new(),
+        ))
+    }
+
+    /// Creates a binding of type `Interface` to a value resolved using the given
+    /// function.
+    ///
+    /// # Errors
+    /// Will return Err if the associated [`ThreadsafeDIContainer`] already have a
+    /// binding for the interface.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # use syrette::ThreadsafeDIContainer;
+    /// # use syrette::ptr::TransientPtr;
+    /// #
+    /// # trait IBuffer: Send + Sync {}
+    /// #
+    /// # struct Buffer<const SIZE: usize>
+    /// # {
+    /// #   buf: [u8; SIZE]
+    /// # }
+    /// #
+    /// # impl<const SIZE: usize> IBuffer for Buffer<SIZE> {}
+    /// #
+    /// # const BUFFER_SIZE: usize = 12;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>>
+    /// # {
+    /// # let mut di_container = ThreadsafeDIContainer::new();
+    /// #
+    /// di_container.bind::<dyn IBuffer>().to_dynamic_value(&|_| {
+    ///     Box::new(|| {
+    ///         TransientPtr::new(Buffer::<BUFFER_SIZE> {
+    ///             buf: [0; BUFFER_SIZE],
+    ///         })
+    ///     })
+    /// })?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_dynamic_value<Func>(
+        self,
+        func: &'static Func,
+    ) -> Result<
+        ThreadsafeBindingWhenConfigurator<'di_container, Interface>,
+        BindingBuilderError,
+    >
+    where
+        Func: Fn(
+                &ThreadsafeDIContainer,
+            )
+                -> TransientPtr<dyn Fn() -> TransientPtr<Interface> + Send + Sync>
+            + Send
+            + Sync,
+    {
+        if self
+            .di_container
+            .has_binding::<Interface>(BindingOptions::new())
+        {
+            return Err(BindingBuilderError::BindingAlreadyExists(type_name::<
+                Interface,
+            >()));
+        }
+
+        let castable_func = ThreadsafeCastableFunction::new(func);
+
+        self.di_container.set_binding::<Interface>(
+            BindingOptions::new(),
+            Box::new(ThreadsafeFunctionProvider::new(
+                Arc::new(castable_func),
+                ProvidableFunctionKind::Instant,
+            )),
+        );
+
+        Ok(ThreadsafeBindingWhenConfigurator::new(
+            self.di_container,
+            BindingOptions::new(),
+        ))
+    }
+}
@@ -0,0 +1,187 @@
+//! Scope configurator for a binding for types inside of a [`ThreadsafeDIContainer`].
+use std::marker::PhantomData;
+
+use crate::di_container::threadsafe::binding::when_configurator::ThreadsafeBindingWhenConfigurator;
+use crate::di_container::threadsafe::ThreadsafeDIContainer;
+use crate::di_container::BindingOptions;
+use crate::errors::di_container::BindingScopeConfiguratorError;
+use crate::interfaces::threadsafe_injectable::ThreadsafeInjectable;
+use crate::provider::threadsafe::{
+    ThreadsafeLazySingletonProvider,
+    ThreadsafeSingletonProvider,
+    ThreadsafeTransientTypeProvider,
+};
+use crate::ptr::ThreadsafeSingletonPtr;
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+/// Scope configurator for a binding for type `Interface` inside a
+/// [`ThreadsafeDIContainer`].
+pub struct ThreadsafeBindingScopeConfigurator<'di_container, Interface, Implementation>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+    Implementation: ThreadsafeInjectable<ThreadsafeDIContainer>,
+{
+    di_container: &'di_container mut ThreadsafeDIContainer,
+    dependency_history_factory: fn() -> DependencyHistory,
+    binding_options: BindingOptions<'static>,
+
+    interface_phantom: PhantomData<Interface>,
+    implementation_phantom: PhantomData<Implementation>,
+}
+
+impl<'di_container, Interface, Implementation>
+    ThreadsafeBindingScopeConfigurator<'di_container, Interface, Implementation>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+    Implementation: ThreadsafeInjectable<ThreadsafeDIContainer>,
+{
+    pub(crate) fn new(
+        di_container: &'di_container mut ThreadsafeDIContainer,
+        dependency_history_factory: fn() -> DependencyHistory,
+        binding_options: BindingOptions<'static>,
+    ) -> Self
+    {
+        Self {
+            di_container,
+            dependency_history_factory,
+            binding_options,
+            interface_phantom: PhantomData,
+            implementation_phantom: PhantomData,
+        }
+    }
+
+    /// Configures the binding to be in a transient scope.
+    ///
+    /// This is the default.
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_transient_scope(
+        mut self,
+    ) -> ThreadsafeBindingWhenConfigurator<'di_container, Interface>
+    {
+        self.set_in_transient_scope();
+
+        ThreadsafeBindingWhenConfigurator::new(self.di_container, self.binding_options)
+    }
+
+    /// Configures the binding to be in a singleton scope.
+    ///
+    /// # Errors
+    /// Will return Err if resolving the implementation fails.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # use std::sync::Arc;
+    /// # use std::thread;
+    /// #
+    /// # use syrette::{ThreadsafeDIContainer, injectable};
+    /// #
+    /// # struct RequestCounter
+    /// # {
+    /// #     count: AtomicUsize
+    /// # }
+    /// #
+    /// # #[injectable(threadsafe = true)]
+    /// # impl RequestCounter
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self { count: AtomicUsize::new(0) }
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = ThreadsafeDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<RequestCounter>()
+    ///     .to::<RequestCounter>()?
+    ///     .in_singleton_scope()?;
+    ///
+    /// let di_container = Arc::new(di_container);
+    ///
+    /// thread::spawn({
+    ///     let di_container = di_container.clone();
+    ///
+    ///     move || {
+    ///         let request_counter = di_container
+    ///             .get::<RequestCounter>()
+    ///             .unwrap()
+    ///             .threadsafe_singleton()
+    ///             .unwrap();
+    ///
+    ///         request_counter.count.fetch_add(1, Ordering::SeqCst);
+    ///     }
+    /// })
+    /// .join()
+    /// .unwrap();
+    ///
+    /// let request_counter = di_container
+    ///     .get::<RequestCounter>()?
+    ///     .threadsafe_singleton()?;
+    ///
+    /// assert_eq!(request_counter.count.load(Ordering::SeqCst), 1);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn in_singleton_scope(
+        self,
+    ) -> Result<
+        ThreadsafeBindingWhenConfigurator<'di_container, Interface>,
+        BindingScopeConfiguratorError,
+    >
+    {
+        let singleton = ThreadsafeSingletonPtr::<Implementation>::from(
+            Implementation::resolve(
+                self.di_container,
+                (self.dependency_history_factory)(),
+            )
+            .map_err(BindingScopeConfiguratorError::SingletonResolveFailed)?,
+        );
+
+        self.di_container.set_binding::<Interface>(
+            self.binding_options.clone(),
+            Box::new(ThreadsafeSingletonProvider::new(singleton)),
+        );
+
+        Ok(ThreadsafeBindingWhenConfigurator::new(
+            self.di_container,
+            self.binding_options,
+        ))
+    }
+
+    /// Configures the binding to be in a singleton scope that is resolved the first
+    /// time the binding is resolved rather than right away.
+    ///
+    /// When several threads resolve the binding at the same time, all of them are
+    /// handed the same singleton.
+    #[allow(clippy::must_use_candidate)]
+    pub fn in_lazy_singleton_scope(
+        self,
+    ) -> ThreadsafeBindingWhenConfigurator<'di_container, Interface>
+    {
+        self.di_container.set_binding::<Interface>(
+            self.binding_options.clone(),
+            Box::new(ThreadsafeLazySingletonProvider::<
+                Implementation,
+                ThreadsafeDIContainer,
+            >::new()),
+        );
+
+        ThreadsafeBindingWhenConfigurator::new(self.di_container, self.binding_options)
+    }
+
+    pub(crate) fn set_in_transient_scope(&mut self)
+    {
+        self.di_container.set_binding::<Interface>(
+            self.binding_options.clone(),
+            Box::new(ThreadsafeTransientTypeProvider::<
+                Implementation,
+                ThreadsafeDIContainer,
+            >::new()),
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! When configurator for a binding for types inside of a [`ThreadsafeDIContainer`].
+use std::any::type_name;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+use crate::di_container::threadsafe::ThreadsafeDIContainer;
+use crate::di_container::{BindingKey, BindingOptions};
+use crate::errors::di_container::BindingWhenConfiguratorError;
+
+/// When configurator for a binding for type `Interface` inside a
+/// [`ThreadsafeDIContainer`].
+pub struct ThreadsafeBindingWhenConfigurator<'di_container, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    di_container: &'di_container mut ThreadsafeDIContainer,
+    binding_options: BindingOptions<'static>,
+
+    interface_phantom: PhantomData<Interface>,
+}
+
+impl<'di_container, Interface> ThreadsafeBindingWhenConfigurator<'di_container, Interface>
+where
+    Interface: 'static + ?Sized + Send + Sync,
+{
+    pub(crate) fn new(
+        di_container: &'di_container mut ThreadsafeDIContainer,
+        binding_options: BindingOptions<'static>,
+    ) -> Self
+    {
+        Self {
+            di_container,
+            binding_options,
+            interface_phantom: PhantomData,
+        }
+    }
+
+    /// Configures the binding to have a name. The name can be a `&'static str` or a
+    /// `String` created at runtime.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::{ThreadsafeDIContainer, injectable};
+    /// #
+    /// # struct Kitten {}
+    /// #
+    /// # #[injectable(threadsafe = true)]
+    /// # impl Kitten
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = ThreadsafeDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<Kitten>()
+    ///     .to::<Kitten>()?
+    ///     .in_transient_scope()
+    ///     .when_named("Billy")?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_named(
+        self,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Result<(), BindingWhenConfiguratorError>
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                BindingWhenConfiguratorError::BindingNotFound(type_name::<Interface>())
+            })?;
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.name(name), binding);
+
+        Ok(())
+    }
+
+    /// Configures the binding to have a binding key.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// # use syrette::di_container::BindingKey;
+    /// # use syrette::{ThreadsafeDIContainer, injectable};
+    /// #
+    /// # struct Kitten {}
+    /// #
+    /// # #[injectable(threadsafe = true)]
+    /// # impl Kitten
+    /// # {
+    /// #     fn new() -> Self
+    /// #     {
+    /// #         Self {}
+    /// #     }
+    /// # }
+    /// #
+    /// struct Billy;
+    ///
+    /// impl BindingKey for Billy {}
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut di_container = ThreadsafeDIContainer::new();
+    ///
+    /// di_container
+    ///     .bind::<Kitten>()
+    ///     .to::<Kitten>()?
+    ///     .in_transient_scope()
+    ///     .when_keyed::<Billy>()?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn when_keyed<Key>(self) -> Result<(), BindingWhenConfiguratorError>
+    where
+        Key: BindingKey,
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                BindingWhenConfiguratorError::BindingNotFound(type_name::<Interface>())
+            })?;
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.keyed::<Key>(), binding);
+
+        Ok(())
+    }
+
+    /// Configures the binding to have a tag. Can be called multiple times to give the
+    /// binding several tags.
+    ///
+    /// # Errors
+    /// Will return Err if no binding for the interface already exists.
+    pub fn when_tagged(
+        mut self,
+        key: &'static str,
+        value: &'static str,
+    ) -> Result<Self, BindingWhenConfiguratorError>
+    {
+        let binding = self
+            .di_container
+            .remove_binding::<Interface>(self.binding_options.clone())
+            .ok_or_else(|| {
+                BindingWhenConfiguratorError::BindingNotFound(type_name::<Interface>())
+            })?;
+
+        self.binding_options = self.binding_options.tag(key, value);
+
+        self.di_container
+            .set_binding::<Interface>(self.binding_options.clone(), binding);
+
+        Ok(self)
+    }
+}
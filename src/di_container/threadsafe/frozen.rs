@@ -0,0 +1,76 @@
+//! Builder of frozen [`ThreadsafeDIContainer`]s.
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::di_container::threadsafe::binding::builder::ThreadsafeBindingBuilder;
+use crate::di_container::threadsafe::ThreadsafeDIContainer;
+use crate::errors::build::BuildError;
+
+/// Collects bindings and builds a [`FrozenThreadsafeDIContainer`] out of them. Created
+/// with [`ThreadsafeDIContainer::builder`].
+#[derive(Default)]
+pub struct ThreadsafeDIContainerBuilder
+{
+    di_container: ThreadsafeDIContainer,
+}
+
+impl ThreadsafeDIContainerBuilder
+{
+    /// Returns a new `ThreadsafeDIContainerBuilder`.
+    #[must_use]
+    pub fn new() -> Self
+    {
+        Self {
+            di_container: ThreadsafeDIContainer::new(),
+        }
+    }
+
+    /// Returns a new [`ThreadsafeBindingBuilder`] for the given interface.
+    ///
+    /// See [`ThreadsafeDIContainer::bind`].
+    pub fn bind<Interface>(&mut self) -> ThreadsafeBindingBuilder<'_, Interface>
+    where
+        Interface: 'static + ?Sized + Send + Sync,
+    {
+        self.di_container.bind::<Interface>()
+    }
+
+    /// Builds a [`FrozenThreadsafeDIContainer`] out of the collected bindings.
+    ///
+    /// The bindings are validated and the singletons of the bindings in a lazy
+    /// singleton scope are resolved before the container is handed back.
+    ///
+    /// # Errors
+    /// Will return `Err` if the bindings are invalid or if resolving a lazy singleton
+    /// fails.
+    pub fn build(self) -> Result<FrozenThreadsafeDIContainer, BuildError>
+    {
+        self.di_container.validate()?;
+
+        self.di_container.resolve_lazy_singletons()?;
+
+        Ok(FrozenThreadsafeDIContainer {
+            di_container: Arc::new(self.di_container),
+        })
+    }
+}
+
+/// Cheaply clonable handle to a [`ThreadsafeDIContainer`] that can no longer be
+/// modified. Created with [`ThreadsafeDIContainerBuilder::build`].
+///
+/// The handle is backed by a [`Arc`] and can be shared between threads.
+#[derive(Clone)]
+pub struct FrozenThreadsafeDIContainer
+{
+    di_container: Arc<ThreadsafeDIContainer>,
+}
+
+impl Deref for FrozenThreadsafeDIContainer
+{
+    type Target = ThreadsafeDIContainer;
+
+    fn deref(&self) -> &Self::Target
+    {
+        &self.di_container
+    }
+}
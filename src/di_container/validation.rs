@@ -0,0 +1,177 @@
+//! Validation of the bindings of DI containers without resolving them.
+use crate::dependency::DependencyKind;
+use crate::dependency_history::DependencyHistory;
+use crate::di_container::binding_lookup::BindingLookup;
+use crate::di_container::BindingOptions;
+use crate::errors::validation::{ValidationError, ValidationProblem};
+use crate::provider::ProvidedInjectable;
+
+/// Validates the bindings of a DI container and of it's ancestors.
+pub(crate) struct Validator<'storage, Provider>
+where
+    Provider: 'static + ?Sized,
+{
+    lookup: BindingLookup<'storage, Provider>,
+    get_injectable: fn(&Provider) -> Option<ProvidedInjectable>,
+}
+
+impl<'storage, Provider> Validator<'storage, Provider>
+where
+    Provider: 'static + ?Sized,
+{
+    pub(crate) fn new(
+        lookup: BindingLookup<'storage, Provider>,
+        get_injectable: fn(&Provider) -> Option<ProvidedInjectable>,
+    ) -> Self
+    {
+        Self {
+            lookup,
+            get_injectable,
+        }
+    }
+
+    /// Walks every binding and the dependencies of it.
+    pub(crate) fn validate(&self) -> Result<(), ValidationError>
+    {
+        let mut problems = Vec::new();
+
+        for binding in self.lookup.iter() {
+            let injectable = match (self.get_injectable)(binding.provider) {
+                Some(injectable) => injectable,
+                None => continue,
+            };
+
+            if !(binding.interface.is_castable_from)(injectable.type_id) {
+                add_problem(
+                    &mut problems,
+                    ValidationProblem::CastImpossible {
+                        interface: binding.interface.name,
+                        implementation: injectable.type_name,
+                    },
+                );
+            }
+
+            let mut dependency_history = DependencyHistory::new();
+
+            dependency_history.set_next_binding_name(binding.options.name.clone());
+            dependency_history.push_type_name(injectable.type_name);
+
+            self.validate_dependencies(&injectable, &dependency_history, &mut problems);
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+
+        Err(ValidationError::new(problems))
+    }
+
+    fn validate_dependencies(
+        &self,
+        injectable: &ProvidedInjectable,
+        dependency_history: &DependencyHistory,
+        problems: &mut Vec<ValidationProblem>,
+    )
+    {
+        for dependency in &injectable.dependencies {
+            if matches!(
+                dependency.kind(),
+                DependencyKind::All | DependencyKind::AllNamed
+            ) {
+                for binding in self.lookup.find_all(dependency, dependency_history) {
+                    self.validate_provided(
+                        binding.provider,
+                        binding.options,
+                        dependency_history,
+                        problems,
+                    );
+                }
+
+                continue;
+            }
+
+            let bindings = self.lookup.find(dependency, dependency_history);
+
+            match bindings.as_slice() {
+                [] if dependency.kind() == DependencyKind::Optional => {}
+                [] => add_problem(
+                    problems,
+                    ValidationProblem::MissingBinding {
+                        dependent: injectable.type_name,
+                        interface: dependency.interface_name(),
+                        name: dependency.name().map(ToString::to_string),
+                        key: dependency
+                            .binding_options()
+                            .key
+                            .as_ref()
+                            .map(ToString::to_string),
+                    },
+                ),
+                [binding] if dependency.kind().is_eager() => self.validate_provided(
+                    binding.provider,
+                    dependency.binding_options(),
+                    dependency_history,
+                    problems,
+                ),
+                // Deferred dependencies & weak singleton pointers don't construct
+                // anything together with the injectable depending on them
+                [_] => {}
+                _ => add_problem(
+                    problems,
+                    ValidationProblem::AmbiguousBinding {
+                        dependent: injectable.type_name,
+                        interface: dependency.interface_name(),
+                        name: dependency.name().map(ToString::to_string),
+                        key: dependency
+                            .binding_options()
+                            .key
+                            .as_ref()
+                            .map(ToString::to_string),
+                        matching: bindings.len(),
+                    },
+                ),
+            }
+        }
+    }
+
+    fn validate_provided(
+        &self,
+        provider: &Provider,
+        binding_options: &BindingOptions<'static>,
+        dependency_history: &DependencyHistory,
+        problems: &mut Vec<ValidationProblem>,
+    )
+    {
+        let injectable = match (self.get_injectable)(provider) {
+            Some(injectable) => injectable,
+            None => return,
+        };
+
+        if dependency_history
+            .as_slice()
+            .contains(&injectable.type_name)
+        {
+            let mut chain = dependency_history.as_slice().to_vec();
+
+            chain.push(injectable.type_name);
+
+            add_problem(problems, ValidationProblem::CircularDependency { chain });
+
+            return;
+        }
+
+        let mut dependency_history = dependency_history.clone();
+
+        dependency_history.set_next_binding_name(binding_options.name.clone());
+        dependency_history.push_type_name(injectable.type_name);
+
+        self.validate_dependencies(&injectable, &dependency_history, problems);
+    }
+}
+
+fn add_problem(problems: &mut Vec<ValidationProblem>, problem: ValidationProblem)
+{
+    if !problems.contains(&problem) {
+        problems.push(problem);
+    }
+}
@@ -1,8 +1,11 @@
 //! Error types for various components of the library.
 
+pub mod build;
 pub mod di_container;
 pub mod injectable;
+pub mod lifecycle;
 pub mod ptr;
+pub mod validation;
 
 #[cfg(feature = "async")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
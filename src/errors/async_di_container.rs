@@ -36,10 +36,13 @@ pub enum AsyncDIContainerError
         interface: &'static str,
     },
 
-    /// No binding exists for a interface (and optionally a name).
+    /// No binding exists for a interface (and optionally a name or key).
     #[error(
-        "No binding exists for interface '{interface}' {}",
-        .name.map_or_else(String::new, |name| format!("with name '{name}'"))
+        "No binding exists for interface '{interface}' {}{}(searched {searched_levels} \
+         {})",
+        name.as_ref().map(|name| format!("with name '{name}' ")).unwrap_or_default(),
+        key.as_ref().map(|key| format!("with key '{key}' ")).unwrap_or_default(),
+        if *searched_levels == 1 { "container" } else { "containers" }
     )]
     BindingNotFound
     {
@@ -47,7 +50,36 @@ pub enum AsyncDIContainerError
         interface: &'static str,
 
         /// The name of the binding if one exists.
-        name: Option<&'static str>,
+        name: Option<String>,
+
+        /// The binding key of the binding if one exists.
+        key: Option<String>,
+
+        /// The number of levels of the container hierarchy that were searched.
+        searched_levels: usize,
+    },
+
+    /// More than one binding for a interface (and optionally a name or key) matches what
+    /// was asked for.
+    #[error(
+        "Expected a single binding for interface '{interface}' {}{}to match but \
+         {matching} did",
+        name.as_ref().map(|name| format!("with name '{name}' ")).unwrap_or_default(),
+        key.as_ref().map(|key| format!("with key '{key}' ")).unwrap_or_default()
+    )]
+    AmbiguousBinding
+    {
+        /// The interface.
+        interface: &'static str,
+
+        /// The name of the bindings if they have one.
+        name: Option<String>,
+
+        /// The binding key of the bindings if they have one.
+        key: Option<String>,
+
+        /// The number of matching bindings.
+        matching: usize,
     },
 
     /// A interface has not been marked async.
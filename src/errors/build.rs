@@ -0,0 +1,24 @@
+//! Error types for building frozen DI containers.
+use crate::errors::injectable::InjectableError;
+use crate::errors::validation::ValidationError;
+
+/// Error type for building a frozen DI container.
+#[derive(thiserror::Error, Debug)]
+pub enum BuildError
+{
+    /// Validating the bindings failed.
+    #[error("The bindings are invalid")]
+    InvalidBindings(#[from] ValidationError),
+
+    /// Resolving a singleton in a lazy singleton scope failed.
+    #[error("Failed to resolve lazy singleton '{service}'")]
+    LazySingletonResolveFailed
+    {
+        /// The reason for the problem.
+        #[source]
+        reason: InjectableError,
+
+        /// The type name of the singleton.
+        service: &'static str,
+    },
+}
@@ -2,12 +2,14 @@
 //!
 //! [`DIContainer`]: crate::di_container::blocking::DIContainer
 
+use crate::di_container::BindingScope;
 use crate::errors::injectable::InjectableError;
 use crate::errors::ptr::SomePtrError;
 
-/// Error type for [`DIContainer`].
+/// Error type for [`DIContainer`] & [`ThreadsafeDIContainer`].
 ///
 /// [`DIContainer`]: crate::di_container::blocking::DIContainer
+/// [`ThreadsafeDIContainer`]: crate::di_container::threadsafe::ThreadsafeDIContainer
 #[derive(thiserror::Error, Debug)]
 pub enum DIContainerError
 {
@@ -36,10 +38,13 @@ pub enum DIContainerError
         interface: &'static str,
     },
 
-    /// No binding exists for a interface (and optionally a name).
+    /// No binding exists for a interface (and optionally a name or key).
     #[error(
-        "No binding exists for interface '{interface}' {}",
-        name.as_ref().map(|name| format!("with name '{name}'")).unwrap_or_default()
+        "No binding exists for interface '{interface}' {}{}(searched {searched_levels} \
+         {})",
+        name.as_ref().map(|name| format!("with name '{name}' ")).unwrap_or_default(),
+        key.as_ref().map(|key| format!("with key '{key}' ")).unwrap_or_default(),
+        if *searched_levels == 1 { "container" } else { "containers" }
     )]
     BindingNotFound
     {
@@ -48,16 +53,80 @@ pub enum DIContainerError
 
         /// The name of the binding if one exists.
         name: Option<String>,
+
+        /// The binding key of the binding if one exists.
+        key: Option<String>,
+
+        /// The number of levels of the container hierarchy that were searched.
+        searched_levels: usize,
+    },
+
+    /// More than one binding for a interface (and optionally a name or key) matches what
+    /// was asked for.
+    #[error(
+        "Expected a single binding for interface '{interface}' {}{}to match but \
+         {matching} did",
+        name.as_ref().map(|name| format!("with name '{name}' ")).unwrap_or_default(),
+        key.as_ref().map(|key| format!("with key '{key}' ")).unwrap_or_default()
+    )]
+    AmbiguousBinding
+    {
+        /// The interface.
+        interface: &'static str,
+
+        /// The name of the bindings if they have one.
+        name: Option<String>,
+
+        /// The binding key of the bindings if they have one.
+        key: Option<String>,
+
+        /// The number of matching bindings.
+        matching: usize,
+    },
+
+    /// Tried to resolve a deferred dependency through a container that isn't shared.
+    #[error(
+        "Unable to resolve the deferred '{interface}'. The container must be shared \
+         using 'DIContainer::into_shared' first"
+    )]
+    NotShared
+    {
+        /// The interface of the deferred dependency.
+        interface: &'static str,
+    },
+
+    /// The container of a deferred dependency handle has been dropped.
+    #[error("The container of the deferred '{interface}' has been dropped")]
+    ContainerDropped
+    {
+        /// The interface of the deferred dependency.
+        interface: &'static str,
+    },
+
+    /// A weak singleton pointer was asked for a binding that isn't in a singleton
+    /// scope.
+    #[error(
+        "Unable to create a weak singleton pointer to '{interface}'. Its binding is in \
+         a {scope} scope"
+    )]
+    NotSingleton
+    {
+        /// The interface of the binding.
+        interface: &'static str,
+
+        /// The scope of the binding.
+        scope: BindingScope,
     },
 
-    /// No binding exists for a interface (and optionally a name).
+    /// No binding exists for a interface (and optionally a name or key).
     #[error("Singleton not found for type {1}. Please ensure such type exists registered as Singleton.")]
     SingletonNotFound(SomePtrError, &'static str)
 }
 
-/// Error type for [`BindingBuilder`].
+/// Error type for [`BindingBuilder`] & [`ThreadsafeBindingBuilder`].
 ///
 /// [`BindingBuilder`]: crate::di_container::blocking::binding::builder::BindingBuilder
+/// [`ThreadsafeBindingBuilder`]: crate::di_container::threadsafe::binding::builder::ThreadsafeBindingBuilder
 #[derive(thiserror::Error, Debug)]
 pub enum BindingBuilderError
 {
@@ -66,9 +135,10 @@ pub enum BindingBuilderError
     BindingAlreadyExists(&'static str),
 }
 
-/// Error type for [`BindingScopeConfigurator`].
+/// Error type for [`BindingScopeConfigurator`] & [`ThreadsafeBindingScopeConfigurator`].
 ///
 /// [`BindingScopeConfigurator`]: crate::di_container::blocking::binding::scope_configurator::BindingScopeConfigurator
+/// [`ThreadsafeBindingScopeConfigurator`]: crate::di_container::threadsafe::binding::scope_configurator::ThreadsafeBindingScopeConfigurator
 #[derive(thiserror::Error, Debug)]
 pub enum BindingScopeConfiguratorError
 {
@@ -77,9 +147,10 @@ pub enum BindingScopeConfiguratorError
     SingletonResolveFailed(#[from] InjectableError),
 }
 
-/// Error type for [`BindingWhenConfigurator`].
+/// Error type for [`BindingWhenConfigurator`] & [`ThreadsafeBindingWhenConfigurator`].
 ///
 /// [`BindingWhenConfigurator`]: crate::di_container::blocking::binding::when_configurator::BindingWhenConfigurator
+/// [`ThreadsafeBindingWhenConfigurator`]: crate::di_container::threadsafe::binding::when_configurator::ThreadsafeBindingWhenConfigurator
 #[derive(thiserror::Error, Debug)]
 pub enum BindingWhenConfiguratorError
 {
@@ -55,4 +55,12 @@ pub enum InjectableError
         /// The name of the dependency.
         dependency_name: &'static str,
     },
+
+    /// Tried to resolve a scoped binding outside of a scope.
+    #[error("Unable to resolve scoped '{affected}' outside of a scope")]
+    NotInScope
+    {
+        /// The affected injectable type.
+        affected: &'static str,
+    },
 }
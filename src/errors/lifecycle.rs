@@ -0,0 +1,92 @@
+//! Error types for starting & stopping the services of DI containers.
+use std::error::Error;
+
+use crate::errors::injectable::InjectableError;
+
+/// Error type for starting or stopping the services of a DI container.
+///
+/// Contains every service that failed.
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "{} service(s) failed:\n{}",
+    .failures.len(),
+    .failures
+        .iter()
+        .map(|failure| format!("- {failure}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+)]
+pub struct LifecycleError
+{
+    failures: Vec<ServiceFailure>,
+}
+
+impl LifecycleError
+{
+    pub(crate) fn new(failures: Vec<ServiceFailure>) -> Self
+    {
+        Self { failures }
+    }
+
+    /// Returns the services that failed.
+    #[must_use]
+    pub fn failures(&self) -> &[ServiceFailure]
+    {
+        &self.failures
+    }
+}
+
+/// A service of a DI container that failed to start or stop.
+#[derive(thiserror::Error, Debug)]
+pub enum ServiceFailure
+{
+    /// Failed to resolve a service before starting it.
+    #[error("Failed to resolve service '{service}'")]
+    ResolveFailed
+    {
+        /// The reason for the problem.
+        #[source]
+        reason: InjectableError,
+
+        /// The type name of the service.
+        service: &'static str,
+    },
+
+    /// A service failed to start.
+    #[error("Failed to start service '{service}'")]
+    StartFailed
+    {
+        /// The reason for the problem.
+        #[source]
+        reason: Box<dyn Error + Send + Sync>,
+
+        /// The type name of the service.
+        service: &'static str,
+    },
+
+    /// A service failed to stop.
+    #[error("Failed to stop service '{service}'")]
+    StopFailed
+    {
+        /// The reason for the problem.
+        #[source]
+        reason: Box<dyn Error + Send + Sync>,
+
+        /// The type name of the service.
+        service: &'static str,
+    },
+}
+
+impl ServiceFailure
+{
+    /// Returns the type name of the service that failed.
+    #[must_use]
+    pub fn service(&self) -> &'static str
+    {
+        match self {
+            Self::ResolveFailed { service, .. }
+            | Self::StartFailed { service, .. }
+            | Self::StopFailed { service, .. } => service,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Error types for validating the bindings of DI containers.
+
+/// Error type for validating the bindings of a DI container.
+///
+/// Contains every problem that was found, not just the first one.
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "Found {} problem(s) with the bindings:\n{}",
+    .problems.len(),
+    .problems
+        .iter()
+        .map(|problem| format!("- {problem}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+)]
+pub struct ValidationError
+{
+    problems: Vec<ValidationProblem>,
+}
+
+impl ValidationError
+{
+    pub(crate) fn new(problems: Vec<ValidationProblem>) -> Self
+    {
+        Self { problems }
+    }
+
+    /// Returns the problems that were found.
+    #[must_use]
+    pub fn problems(&self) -> &[ValidationProblem]
+    {
+        &self.problems
+    }
+}
+
+/// A problem with the bindings of a DI container.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ValidationProblem
+{
+    /// No binding exists for a dependency of a injectable.
+    #[error(
+        "'{dependent}' depends on '{interface}' {}{}but no binding for it exists",
+        name.as_ref().map(|name| format!("with name '{name}' ")).unwrap_or_default(),
+        key.as_ref().map(|key| format!("with key '{key}' ")).unwrap_or_default()
+    )]
+    MissingBinding
+    {
+        /// The injectable with the dependency.
+        dependent: &'static str,
+
+        /// The interface that doesn't have a binding.
+        interface: &'static str,
+
+        /// The name of the binding that doesn't exist.
+        name: Option<String>,
+
+        /// The binding key of the binding that doesn't exist.
+        key: Option<String>,
+    },
+
+    /// More than one binding matches a dependency of a injectable.
+    #[error(
+        "'{dependent}' depends on '{interface}' {}{}but {matching} bindings match",
+        name.as_ref().map(|name| format!("with name '{name}' ")).unwrap_or_default(),
+        key.as_ref().map(|key| format!("with key '{key}' ")).unwrap_or_default()
+    )]
+    AmbiguousBinding
+    {
+        /// The injectable with the dependency.
+        dependent: &'static str,
+
+        /// The interface.
+        interface: &'static str,
+
+        /// The name of the bindings if they have one.
+        name: Option<String>,
+
+        /// The binding key of the bindings if they have one.
+        key: Option<String>,
+
+        /// The number of matching bindings.
+        matching: usize,
+    },
+
+    /// A injectable depends on itself, directly or through other injectables.
+    #[error("Detected circular dependencies. {}", chain.join(" -> "))]
+    CircularDependency
+    {
+        /// The injectables that depend on each other, starting and ending with the same
+        /// injectable.
+        chain: Vec<&'static str>,
+    },
+
+    /// The implementation of a binding can't be cast to the interface of the binding.
+    #[error(
+        "Implementation '{implementation}' can't be cast to interface '{interface}'"
+    )]
+    CastImpossible
+    {
+        /// The interface of the binding.
+        interface: &'static str,
+
+        /// The implementation of the binding.
+        implementation: &'static str,
+    },
+}
@@ -0,0 +1,496 @@
+//! Explanations of how DI containers would resolve interfaces.
+use std::fmt::{Display, Formatter};
+
+use crate::dependency::{DependencyInfo, DependencyKind};
+use crate::dependency_history::DependencyHistory;
+use crate::di_container::binding_lookup::BindingLookup;
+use crate::di_container::binding_storage::StoredBinding;
+use crate::di_container::BindingScope;
+use crate::provider::ProvidedInjectable;
+
+/// Explanation of how a DI container would resolve a interface and every transitive
+/// dependency of it. Nothing is constructed while explaining.
+///
+/// Displays as a indented text tree where failures are marked with `FAILED`.
+///
+/// # Examples
+/// ```
+/// # use syrette::ptr::TransientPtr;
+/// # use syrette::{injectable, DIContainer};
+/// #
+/// # trait IEngine {}
+/// #
+/// # struct Car
+/// # {
+/// #     engine: TransientPtr<dyn IEngine>,
+/// # }
+/// #
+/// # #[injectable]
+/// # impl Car
+/// # {
+/// #     fn new(engine: TransientPtr<dyn IEngine>) -> Self
+/// #     {
+/// #         Self { engine }
+/// #     }
+/// # }
+/// #
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut di_container = DIContainer::new();
+///
+/// di_container.bind::<Car>().to::<Car>()?;
+///
+/// let explanation = di_container.explain::<Car>();
+///
+/// assert!(explanation.fails());
+///
+/// // Prints something like:
+/// // rust_out::Car -> rust_out::Car (transient)
+/// // └── dyn rust_out::IEngine: FAILED, no binding exists
+/// println!("{explanation}");
+/// #
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation
+{
+    interface: &'static str,
+    name: Option<String>,
+    key: Option<String>,
+    kind: Option<DependencyKind>,
+    outcome: ExplanationOutcome,
+    dependencies: Vec<Explanation>,
+}
+
+impl Explanation
+{
+    /// Returns the type name of the interface.
+    #[must_use]
+    pub fn interface(&self) -> &'static str
+    {
+        self.interface
+    }
+
+    /// Returns the name of the binding that is requested.
+    #[must_use]
+    pub fn name(&self) -> Option<&str>
+    {
+        self.name.as_deref()
+    }
+
+    /// Returns the binding key of the binding that is requested, formatted with it's
+    /// [`Debug`] implementation.
+    #[must_use]
+    pub fn key(&self) -> Option<&str>
+    {
+        self.key.as_deref()
+    }
+
+    /// Returns how the interface is depended on. Is `None` for the interface that is
+    /// explained and for the bindings of collections.
+    #[must_use]
+    pub fn kind(&self) -> Option<DependencyKind>
+    {
+        self.kind
+    }
+
+    /// Returns what would happen when resolving the interface.
+    #[must_use]
+    pub fn outcome(&self) -> &ExplanationOutcome
+    {
+        &self.outcome
+    }
+
+    /// Returns the explanations of the dependencies of the implementation or, for
+    /// collections, of the bindings that the collection would contain.
+    #[must_use]
+    pub fn dependencies(&self) -> &[Explanation]
+    {
+        &self.dependencies
+    }
+
+    /// Returns whether or not resolving the interface itself would fail.
+    #[must_use]
+    pub fn is_failure(&self) -> bool
+    {
+        match self.outcome {
+            ExplanationOutcome::Binding { .. } | ExplanationOutcome::Collection => false,
+            ExplanationOutcome::MissingBinding => {
+                self.kind != Some(DependencyKind::Optional)
+            }
+            ExplanationOutcome::AmbiguousBinding { .. }
+            | ExplanationOutcome::CircularDependency { .. }
+            | ExplanationOutcome::CastImpossible { .. } => true,
+        }
+    }
+
+    /// Returns whether or not resolving the interface or any of it's transitive
+    /// dependencies would fail.
+    #[must_use]
+    pub fn fails(&self) -> bool
+    {
+        self.is_failure() || self.dependencies.iter().any(Self::fails)
+    }
+
+    fn fmt_line(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        write!(formatter, "{}", self.interface)?;
+
+        if let Some(name) = &self.name {
+            write!(formatter, " named \"{name}\"")?;
+        }
+
+        if let Some(key) = &self.key {
+            write!(formatter, " keyed {key}")?;
+        }
+
+        if let Some(kind) = self.kind.filter(|kind| *kind != DependencyKind::Single) {
+            write!(formatter, " [{kind}]")?;
+        }
+
+        match &self.outcome {
+            ExplanationOutcome::Binding {
+                implementation: Some(implementation),
+                scope,
+            } => write!(formatter, " -> {implementation} ({scope})"),
+            ExplanationOutcome::Binding {
+                implementation: None,
+                scope,
+            } => write!(formatter, " ({scope})"),
+            ExplanationOutcome::Collection => {
+                write!(formatter, " ({} bindings)", self.dependencies.len())
+            }
+            ExplanationOutcome::MissingBinding if !self.is_failure() => {
+                write!(formatter, ": no binding exists")
+            }
+            ExplanationOutcome::MissingBinding => {
+                write!(formatter, ": FAILED, no binding exists")
+            }
+            ExplanationOutcome::AmbiguousBinding { matching } => {
+                write!(formatter, ": FAILED, {matching} bindings match")
+            }
+            ExplanationOutcome::CircularDependency { implementation } => write!(
+                formatter,
+                " -> {implementation}: FAILED, circular dependency"
+            ),
+            ExplanationOutcome::CastImpossible { implementation } => write!(
+                formatter,
+                " -> {implementation}: FAILED, can't be cast to the interface"
+            ),
+        }
+    }
+
+    fn fmt_dependencies(
+        &self,
+        formatter: &mut Formatter<'_>,
+        prefix: &str,
+    ) -> std::fmt::Result
+    {
+        for (index, dependency) in self.dependencies.iter().enumerate() {
+            let is_last = index + 1 == self.dependencies.len();
+
+            write!(
+                formatter,
+                "\n{prefix}{}",
+                if is_last { "└── " } else { "├── " }
+            )?;
+
+            dependency.fmt_line(formatter)?;
+
+            dependency.fmt_dependencies(
+                formatter,
+                &format!("{prefix}{}", if is_last { "    " } else { "│   " }),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Explanation
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result
+    {
+        self.fmt_line(formatter)?;
+        self.fmt_dependencies(formatter, "")
+    }
+}
+
+/// What would happen when resolving a interface.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExplanationOutcome
+{
+    /// A binding would be used.
+    Binding
+    {
+        /// The type name of the implementation. Is `None` for bindings to factories &
+        /// dynamic values.
+        implementation: Option<&'static str>,
+
+        /// The scope of the binding.
+        scope: BindingScope,
+    },
+
+    /// A collection of all of the matching bindings would be created.
+    Collection,
+
+    /// No binding exists.
+    MissingBinding,
+
+    /// More than one binding matches.
+    AmbiguousBinding
+    {
+        /// The number of matching bindings.
+        matching: usize,
+    },
+
+    /// The implementation would depend on itself.
+    CircularDependency
+    {
+        /// The type name of the implementation.
+        implementation: &'static str,
+    },
+
+    /// The implementation can't be cast to the interface.
+    CastImpossible
+    {
+        /// The type name of the implementation.
+        implementation: &'static str,
+    },
+}
+
+/// Explains how the bindings found by a [`BindingLookup`] would be resolved.
+pub(crate) struct Explainer<'storage, Provider>
+where
+    Provider: 'static + ?Sized,
+{
+    lookup: BindingLookup<'storage, Provider>,
+    get_injectable: fn(&Provider) -> Option<ProvidedInjectable>,
+    get_scope: fn(&Provider) -> BindingScope,
+}
+
+impl<'storage, Provider> Explainer<'storage, Provider>
+where
+    Provider: 'static + ?Sized,
+{
+    pub(crate) fn new(
+        lookup: BindingLookup<'storage, Provider>,
+        get_injectable: fn(&Provider) -> Option<ProvidedInjectable>,
+        get_scope: fn(&Provider) -> BindingScope,
+    ) -> Self
+    {
+        Self {
+            lookup,
+            get_injectable,
+            get_scope,
+        }
+    }
+
+    pub(crate) fn explain(&self, requested: &DependencyInfo) -> Explanation
+    {
+        self.explain_dependency(requested, None, &DependencyHistory::new())
+    }
+
+    fn explain_dependency(
+        &self,
+        dependency: &DependencyInfo,
+        kind: Option<DependencyKind>,
+        dependency_history: &DependencyHistory,
+    ) -> Explanation
+    {
+        let mut explanation = Explanation {
+            interface: dependency.interface_name(),
+            name: dependency.name().map(ToString::to_string),
+            key: dependency
+                .binding_options()
+                .key
+                .as_ref()
+                .map(ToString::to_string),
+            kind,
+            outcome: ExplanationOutcome::MissingBinding,
+            dependencies: Vec::new(),
+        };
+
+        if matches!(kind, Some(DependencyKind::All | DependencyKind::AllNamed)) {
+            explanation.outcome = ExplanationOutcome::Collection;
+
+            explanation.dependencies = self
+                .lookup
+                .find_all(dependency, dependency_history)
+                .into_iter()
+                .map(|binding| {
+                    let (outcome, dependencies) =
+                        self.explain_binding(&binding, None, dependency_history);
+
+                    Explanation {
+                        interface: binding.interface.name,
+                        name: binding.options.name.as_deref().map(ToString::to_string),
+                        key: binding.options.key.as_ref().map(ToString::to_string),
+                        kind: None,
+                        outcome,
+                        dependencies,
+                    }
+                })
+                .collect();
+
+            return explanation;
+        }
+
+        let bindings = self.lookup.find(dependency, dependency_history);
+
+        match bindings.as_slice() {
+            [] => {}
+            [binding] => {
+                let (outcome, dependencies) =
+                    self.explain_binding(binding, kind, dependency_history);
+
+                explanation.outcome = outcome;
+                explanation.dependencies = dependencies;
+            }
+            _ => {
+                explanation.outcome = ExplanationOutcome::AmbiguousBinding {
+                    matching: bindings.len(),
+                };
+            }
+        }
+
+        explanation
+    }
+
+    fn explain_binding(
+        &self,
+        binding: &StoredBinding<'storage, Provider>,
+        kind: Option<DependencyKind>,
+        dependency_history: &DependencyHistory,
+    ) -> (ExplanationOutcome, Vec<Explanation>)
+    {
+        let scope = (self.get_scope)(binding.provider);
+
+        let injectable = match (self.get_injectable)(binding.provider) {
+            Some(injectable) => injectable,
+            None => {
+                return (
+                    ExplanationOutcome::Binding {
+                        implementation: None,
+                        scope,
+                    },
+                    Vec::new(),
+                );
+            }
+        };
+
+        if !(binding.interface.is_castable_from)(injectable.type_id) {
+            return (
+                ExplanationOutcome::CastImpossible {
+                    implementation: injectable.type_name,
+                },
+                Vec::new(),
+            );
+        }
+
+        if dependency_history
+            .as_slice()
+            .contains(&injectable.type_name)
+        {
+            return (
+                ExplanationOutcome::CircularDependency {
+                    implementation: injectable.type_name,
+                },
+                Vec::new(),
+            );
+        }
+
+        let outcome = ExplanationOutcome::Binding {
+            implementation: Some(injectable.type_name),
+            scope,
+        };
+
+        // Deferred dependencies & weak singleton pointers don't construct anything
+        // together with the injectable depending on them
+        if !kind.map_or(true, DependencyKind::is_eager) {
+            return (outcome, Vec::new());
+        }
+
+        let mut dependency_history = dependency_history.clone();
+
+        dependency_history.set_next_binding_name(binding.options.name.clone());
+        dependency_history.push_type_name(injectable.type_name);
+
+        let dependencies = injectable
+            .dependencies
+            .iter()
+            .map(|dependency| {
+                self.explain_dependency(
+                    dependency,
+                    Some(dependency.kind()),
+                    &dependency_history,
+                )
+            })
+            .collect();
+
+        (outcome, dependencies)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn can_display()
+    {
+        let explanation = Explanation {
+            interface: "dyn IWarrior",
+            name: None,
+            key: None,
+            kind: None,
+            outcome: ExplanationOutcome::Binding {
+                implementation: Some("Warrior"),
+                scope: BindingScope::Transient,
+            },
+            dependencies: vec![
+                Explanation {
+                    interface: "dyn IWeapon",
+                    name: Some("sharp".to_string()),
+                    key: None,
+                    kind: Some(DependencyKind::Single),
+                    outcome: ExplanationOutcome::Binding {
+                        implementation: Some("Sword"),
+                        scope: BindingScope::Singleton,
+                    },
+                    dependencies: vec![Explanation {
+                        interface: "dyn IMetal",
+                        name: None,
+                        key: Some("Steel".to_string()),
+                        kind: Some(DependencyKind::Single),
+                        outcome: ExplanationOutcome::AmbiguousBinding { matching: 2 },
+                        dependencies: vec![],
+                    }],
+                },
+                Explanation {
+                    interface: "dyn IShield",
+                    name: None,
+                    key: None,
+                    kind: Some(DependencyKind::Optional),
+                    outcome: ExplanationOutcome::MissingBinding,
+                    dependencies: vec![],
+                },
+            ],
+        };
+
+        assert_eq!(
+            explanation.to_string(),
+            concat!(
+                "dyn IWarrior -> Warrior (transient)\n",
+                "├── dyn IWeapon named \"sharp\" -> Sword (singleton)\n",
+                "│   └── dyn IMetal keyed Steel: FAILED, 2 bindings match\n",
+                "└── dyn IShield [optional]: no binding exists"
+            )
+        );
+
+        assert!(!explanation.is_failure());
+        assert!(explanation.fails());
+        assert!(!explanation.dependencies()[1].fails());
+    }
+}
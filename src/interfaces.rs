@@ -1,7 +1,13 @@
 //! Various useful interfaces.
 
 pub mod injectable;
+pub mod lifecycle;
+pub mod threadsafe_injectable;
 
 #[cfg(feature = "async")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
 pub mod async_injectable;
+
+#[cfg(feature = "async")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
+pub mod async_lifecycle;
@@ -2,6 +2,7 @@
 use std::fmt::Debug;
 use std::future::ready;
 
+use crate::dependency::DependencyInfo;
 use crate::errors::injectable::InjectableError;
 use crate::future::BoxFuture;
 use crate::private::cast::CastFromArc;
@@ -24,6 +25,15 @@ pub trait AsyncInjectable<DIContainerT>: CastFromArc
     where
         Self: Sized + 'fut,
         'di_container: 'fut;
+
+    /// Returns the dependencies of the injectable, without resolving them.
+    #[must_use]
+    fn dependencies() -> Vec<DependencyInfo>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
 }
 
 impl<DIContainerT> Debug for dyn AsyncInjectable<DIContainerT>
@@ -0,0 +1,38 @@
+//! Interfaces for services that are started & stopped by a [`AsyncDIContainer`].
+//!
+//! [`AsyncDIContainer`]: crate::AsyncDIContainer
+use std::error::Error;
+
+use async_trait::async_trait;
+
+/// Interface for singletons that are started by [`AsyncDIContainer::start_all`].
+///
+/// The binding of the singleton has to be made startable using `startable` on the scope
+/// configurator of the binding.
+///
+/// [`AsyncDIContainer::start_all`]: crate::AsyncDIContainer::start_all
+#[async_trait]
+pub trait AsyncStartable: Send + Sync
+{
+    /// Starts the service.
+    ///
+    /// # Errors
+    /// Will return `Err` if the service fails to start.
+    async fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+/// Interface for singletons that are stopped by [`AsyncDIContainer::stop_all`].
+///
+/// The binding of the singleton has to be made stoppable using `stoppable` on the scope
+/// configurator of the binding.
+///
+/// [`AsyncDIContainer::stop_all`]: crate::AsyncDIContainer::stop_all
+#[async_trait]
+pub trait AsyncStoppable: Send + Sync
+{
+    /// Stops the service.
+    ///
+    /// # Errors
+    /// Will return `Err` if the service fails to stop.
+    async fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
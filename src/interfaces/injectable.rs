@@ -1,6 +1,7 @@
 //! Interface for structs that can be injected into or be injected to.
 use std::fmt::Debug;
 
+use crate::dependency::DependencyInfo;
 use crate::errors::injectable::InjectableError;
 use crate::private::cast::CastFrom;
 use crate::ptr::TransientPtr;
@@ -21,6 +22,15 @@ pub trait Injectable<DIContainerT>: CastFrom
     ) -> Result<TransientPtr<Self>, InjectableError>
     where
         Self: Sized;
+
+    /// Returns the dependencies of the injectable, without resolving them.
+    #[must_use]
+    fn dependencies() -> Vec<DependencyInfo>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
 }
 
 impl<DIContainerT> Debug for dyn Injectable<DIContainerT>
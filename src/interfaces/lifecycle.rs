@@ -0,0 +1,34 @@
+//! Interfaces for services that are started & stopped by a [`DIContainer`].
+//!
+//! [`DIContainer`]: crate::DIContainer
+use std::error::Error;
+
+/// Interface for singletons that are started by [`DIContainer::start_all`].
+///
+/// The binding of the singleton has to be made startable using `startable` on the scope
+/// configurator of the binding.
+///
+/// [`DIContainer::start_all`]: crate::DIContainer::start_all
+pub trait Startable
+{
+    /// Starts the service.
+    ///
+    /// # Errors
+    /// Will return `Err` if the service fails to start.
+    fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+/// Interface for singletons that are stopped by [`DIContainer::stop_all`].
+///
+/// The binding of the singleton has to be made stoppable using `stoppable` on the scope
+/// configurator of the binding.
+///
+/// [`DIContainer::stop_all`]: crate::DIContainer::stop_all
+pub trait Stoppable
+{
+    /// Stops the service.
+    ///
+    /// # Errors
+    /// Will return `Err` if the service fails to stop.
+    fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
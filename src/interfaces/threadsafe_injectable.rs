@@ -0,0 +1,57 @@
+//! Interface for structs that can be injected into or be injected to in a thread-safe
+//! manner.
+use std::fmt::Debug;
+
+use crate::dependency::DependencyInfo;
+use crate::errors::injectable::InjectableError;
+use crate::private::cast::CastFromArc;
+use crate::ptr::TransientPtr;
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+/// Interface for structs that can be injected into or be injected to in a thread-safe
+/// manner.
+pub trait ThreadsafeInjectable<DIContainerT>: CastFromArc
+{
+    /// Resolves the dependencies of the injectable.
+    ///
+    /// # Errors
+    /// Will return `Err` if resolving the dependencies fails.
+    fn resolve(
+        di_container: &DIContainerT,
+        dependency_history: DependencyHistory,
+    ) -> Result<TransientPtr<Self>, InjectableError>
+    where
+        Self: Sized;
+
+    /// Returns the dependencies of the injectable, without resolving them.
+    #[must_use]
+    fn dependencies() -> Vec<DependencyInfo>
+    where
+        Self: Sized,
+    {
+        Vec::new()
+    }
+}
+
+impl<DIContainerT> Debug for dyn ThreadsafeInjectable<DIContainerT>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_str("{}")
+    }
+}
+
+impl<T, DIContainerT> ThreadsafeInjectable<DIContainerT> for T
+where
+    T: Default + 'static + Send + Sync,
+{
+    fn resolve(
+        _: &DIContainerT,
+        _: DependencyHistory,
+    ) -> Result<TransientPtr<Self>, InjectableError>
+    {
+        Ok(TransientPtr::new(Self::default()))
+    }
+}
@@ -88,9 +88,13 @@
 //! }
 //! ```
 
+pub mod deferred;
+pub mod dependency;
+pub mod dependency_graph;
 pub mod dependency_history;
 pub mod di_container;
 pub mod errors;
+pub mod explanation;
 pub mod interfaces;
 pub mod ptr;
 
@@ -102,7 +106,8 @@ pub mod future;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "async")))]
 pub use di_container::asynchronous::AsyncDIContainer;
 pub use di_container::blocking::DIContainer;
-pub use syrette_macros::{declare_interface, injectable, named};
+pub use di_container::threadsafe::ThreadsafeDIContainer;
+pub use syrette_macros::{declare_interface, injectable, keyed, named, tagged};
 
 #[doc(hidden)]
 pub mod private;
@@ -122,6 +122,14 @@ fn get_caster<Dest: ?Sized + 'static>(
         .ok_or(GetCasterError::DowncastFailed)
 }
 
+/// Returns whether or not a concrete type with the id `type_id` can be cast to a type or
+/// trait `Dest`.
+#[must_use]
+pub fn can_cast<Dest: ?Sized + 'static>(type_id: TypeId) -> bool
+{
+    get_caster::<Dest>(type_id).is_ok()
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum GetCasterError
 {
@@ -1,4 +1,83 @@
+use std::any::{type_name, TypeId};
+use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::dependency::DependencyInfo;
+
 pub mod blocking;
+pub mod threadsafe;
 
 #[cfg(feature = "async")]
 pub mod r#async;
+
+/// Static information about the injectable that a provider provides.
+#[derive(Debug, Clone)]
+pub struct ProvidedInjectable
+{
+    pub type_id: TypeId,
+    pub type_name: &'static str,
+    pub dependencies: Vec<DependencyInfo>,
+}
+
+impl ProvidedInjectable
+{
+    pub fn new<InjectableT>(dependencies: Vec<DependencyInfo>) -> Self
+    where
+        InjectableT: 'static,
+    {
+        Self {
+            type_id: TypeId::of::<InjectableT>(),
+            type_name: type_name::<InjectableT>(),
+            dependencies,
+        }
+    }
+}
+
+/// The result of starting or stopping a singleton.
+pub type LifecycleResult = Result<(), Box<dyn Error + Send + Sync>>;
+
+/// A singleton held by a provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeldSingleton
+{
+    pub type_name: &'static str,
+
+    /// The position of the singleton in the order that all singletons were
+    /// constructed in.
+    pub construction_order: usize,
+
+    /// The number of strong references to the singleton, including the one of the
+    /// provider.
+    pub strong_count: usize,
+
+    /// The address of the singleton. Identifies a singleton that is held by more than
+    /// one provider.
+    pub address: usize,
+}
+
+impl HeldSingleton
+{
+    pub fn new<SingletonT>(
+        construction_order: usize,
+        strong_count: usize,
+        singleton: *const SingletonT,
+    ) -> Self
+    where
+        SingletonT: 'static,
+    {
+        Self {
+            type_name: type_name::<SingletonT>(),
+            construction_order,
+            strong_count,
+            address: singleton.cast::<()>() as usize,
+        }
+    }
+}
+
+/// Returns the next position in the order that singletons are constructed in.
+pub fn next_construction_order() -> usize
+{
+    static NEXT_CONSTRUCTION_ORDER: AtomicUsize = AtomicUsize::new(0);
+
+    NEXT_CONSTRUCTION_ORDER.fetch_add(1, Ordering::Relaxed)
+}
@@ -2,10 +2,20 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use tokio::sync::OnceCell;
 
 use crate::castable_function::threadsafe::AnyThreadsafeCastableFunction;
+use crate::di_container::BindingScope;
 use crate::errors::injectable::InjectableError;
+use crate::future::BoxFuture;
 use crate::interfaces::async_injectable::AsyncInjectable;
+use crate::interfaces::async_lifecycle::{AsyncStartable, AsyncStoppable};
+use crate::provider::{
+    next_construction_order,
+    HeldSingleton,
+    LifecycleResult,
+    ProvidedInjectable,
+};
 use crate::ptr::{ThreadsafeSingletonPtr, TransientPtr};
 use crate::util::use_double;
 
@@ -27,6 +37,8 @@ pub enum ProvidableFunctionKind
 {
     #[cfg(feature = "factory")]
     UserCalled,
+    #[cfg(feature = "factory")]
+    AsyncUserCalled,
     Instant,
     AsyncInstant,
 }
@@ -44,6 +56,111 @@ where
     ) -> Result<AsyncProvidable<DIContainerT>, InjectableError>;
 
     fn do_clone(&self) -> Box<dyn IAsyncProvider<DIContainerT>>;
+
+    /// Returns the injectable that is provided, if the provider provides one.
+    fn injectable(&self) -> Option<ProvidedInjectable>;
+
+    /// Returns how the provided instances are created.
+    fn scope(&self) -> BindingScope;
+
+    /// Returns whether or not the provider holds a singleton that has been built.
+    fn is_singleton_built(&self) -> bool;
+
+    /// Returns the singleton that the provider holds, if it holds a built one.
+    fn held_singleton(&self) -> Option<HeldSingleton>;
+
+    /// Returns whether or not the provider provides a singleton that is started by
+    /// `start_all`.
+    fn is_startable(&self) -> bool;
+
+    /// Starts the singleton that the provider holds, if it holds a built one that is
+    /// startable.
+    async fn start(&self) -> Option<LifecycleResult>;
+
+    /// Stops the singleton that the provider holds, if it holds a built one that is
+    /// stoppable.
+    async fn stop(&self) -> Option<LifecycleResult>;
+}
+
+pub type AsyncLifecycleHook<InjectableT> =
+    for<'singleton> fn(&'singleton InjectableT) -> BoxFuture<'singleton, LifecycleResult>;
+
+/// Hooks that start & stop the singleton of a binding.
+pub struct AsyncLifecycleHooks<InjectableT>
+{
+    pub on_start: Option<AsyncLifecycleHook<InjectableT>>,
+    pub on_stop: Option<AsyncLifecycleHook<InjectableT>>,
+}
+
+impl<InjectableT> AsyncLifecycleHooks<InjectableT>
+{
+    pub fn set_startable(&mut self)
+    where
+        InjectableT: AsyncStartable,
+    {
+        fn start<InjectableT>(singleton: &InjectableT) -> BoxFuture<'_, LifecycleResult>
+        where
+            InjectableT: AsyncStartable,
+        {
+            singleton.start()
+        }
+
+        self.on_start = Some(start::<InjectableT>);
+    }
+
+    pub fn set_stoppable(&mut self)
+    where
+        InjectableT: AsyncStoppable,
+    {
+        fn stop<InjectableT>(singleton: &InjectableT) -> BoxFuture<'_, LifecycleResult>
+        where
+            InjectableT: AsyncStoppable,
+        {
+            singleton.stop()
+        }
+
+        self.on_stop = Some(stop::<InjectableT>);
+    }
+
+    /// Starts a singleton, if it's startable.
+    pub async fn start(&self, singleton: &InjectableT) -> Option<LifecycleResult>
+    {
+        match self.on_start {
+            Some(on_start) => Some(on_start(singleton).await),
+            None => None,
+        }
+    }
+
+    /// Stops a singleton, if it's stoppable.
+    pub async fn stop(&self, singleton: &InjectableT) -> Option<LifecycleResult>
+    {
+        match self.on_stop {
+            Some(on_stop) => Some(on_stop(singleton).await),
+            None => None,
+        }
+    }
+}
+
+impl<InjectableT> Default for AsyncLifecycleHooks<InjectableT>
+{
+    fn default() -> Self
+    {
+        Self {
+            on_start: None,
+            on_stop: None,
+        }
+    }
+}
+
+impl<InjectableT> Clone for AsyncLifecycleHooks<InjectableT>
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            on_start: self.on_start,
+            on_stop: self.on_stop,
+        }
+    }
 }
 
 impl<DIContainerT> Clone for Box<dyn IAsyncProvider<DIContainerT>>
@@ -99,6 +216,43 @@ where
     {
         Box::new(self.clone())
     }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableT>(
+            InjectableT::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::Transient
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        false
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        None
+    }
+
+    fn is_startable(&self) -> bool
+    {
+        false
+    }
+
+    async fn start(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
+
+    async fn stop(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
 }
 
 impl<InjectableT, DIContainerT> Clone
@@ -120,6 +274,8 @@ where
     InjectableT: AsyncInjectable<DIContainerT>,
 {
     singleton: ThreadsafeSingletonPtr<InjectableT>,
+    construction_order: usize,
+    hooks: AsyncLifecycleHooks<InjectableT>,
 
     di_container_phantom: PhantomData<DIContainerT>,
 }
@@ -128,10 +284,15 @@ impl<InjectableT, DIContainerT> AsyncSingletonProvider<InjectableT, DIContainerT
 where
     InjectableT: AsyncInjectable<DIContainerT>,
 {
-    pub fn new(singleton: ThreadsafeSingletonPtr<InjectableT>) -> Self
+    pub fn new(
+        singleton: ThreadsafeSingletonPtr<InjectableT>,
+        hooks: AsyncLifecycleHooks<InjectableT>,
+    ) -> Self
     {
         Self {
             singleton,
+            construction_order: next_construction_order(),
+            hooks,
             di_container_phantom: PhantomData,
         }
     }
@@ -157,6 +318,47 @@ where
     {
         Box::new(self.clone())
     }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableT>(
+            InjectableT::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::Singleton
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        true
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        Some(HeldSingleton::new::<InjectableT>(
+            self.construction_order,
+            ThreadsafeSingletonPtr::strong_count(&self.singleton),
+            ThreadsafeSingletonPtr::as_ptr(&self.singleton),
+        ))
+    }
+
+    fn is_startable(&self) -> bool
+    {
+        self.hooks.on_start.is_some()
+    }
+
+    async fn start(&self) -> Option<LifecycleResult>
+    {
+        self.hooks.start(&self.singleton).await
+    }
+
+    async fn stop(&self) -> Option<LifecycleResult>
+    {
+        self.hooks.stop(&self.singleton).await
+    }
 }
 
 impl<InjectableT, DIContainerT> Clone
@@ -168,6 +370,141 @@ where
     {
         Self {
             singleton: self.singleton.clone(),
+            construction_order: self.construction_order,
+            hooks: self.hooks.clone(),
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+pub struct AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    /// The singleton together with it's position in the construction order.
+    singleton: Arc<OnceCell<(ThreadsafeSingletonPtr<InjectableT>, usize)>>,
+    hooks: AsyncLifecycleHooks<InjectableT>,
+
+    di_container_phantom: PhantomData<DIContainerT>,
+}
+
+impl<InjectableT, DIContainerT> AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    pub fn new(hooks: AsyncLifecycleHooks<InjectableT>) -> Self
+    {
+        Self {
+            singleton: Arc::new(OnceCell::new()),
+            hooks,
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<InjectableT, DIContainerT> IAsyncProvider<DIContainerT>
+    for AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+    DIContainerT: Send + Sync + 'static,
+{
+    async fn provide(
+        &self,
+        di_container: &DIContainerT,
+        dependency_history: DependencyHistory,
+    ) -> Result<AsyncProvidable<DIContainerT>, InjectableError>
+    {
+        if let Some((singleton, _)) = self.singleton.get() {
+            return Ok(AsyncProvidable::Singleton(singleton.clone()));
+        }
+
+        // Waiting for the initialization of the singleton from inside of that very
+        // initialization would never finish
+        if dependency_history.contains::<InjectableT>() {
+            return Err(dependency_history.into_circular_error::<InjectableT>());
+        }
+
+        let (singleton, _) = self
+            .singleton
+            .get_or_try_init(|| async {
+                InjectableT::resolve(di_container, dependency_history)
+                    .await
+                    .map(|singleton| {
+                        (
+                            ThreadsafeSingletonPtr::from(singleton),
+                            next_construction_order(),
+                        )
+                    })
+            })
+            .await?;
+
+        Ok(AsyncProvidable::Singleton(singleton.clone()))
+    }
+
+    fn do_clone(&self) -> Box<dyn IAsyncProvider<DIContainerT>>
+    {
+        Box::new(self.clone())
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableT>(
+            InjectableT::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::LazySingleton
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        self.singleton.initialized()
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        self.singleton.get().map(|(singleton, construction_order)| {
+            HeldSingleton::new::<InjectableT>(
+                *construction_order,
+                ThreadsafeSingletonPtr::strong_count(singleton),
+                ThreadsafeSingletonPtr::as_ptr(singleton),
+            )
+        })
+    }
+
+    fn is_startable(&self) -> bool
+    {
+        self.hooks.on_start.is_some()
+    }
+
+    async fn start(&self) -> Option<LifecycleResult>
+    {
+        let (singleton, _) = self.singleton.get()?;
+
+        self.hooks.start(singleton).await
+    }
+
+    async fn stop(&self) -> Option<LifecycleResult>
+    {
+        let (singleton, _) = self.singleton.get()?;
+
+        self.hooks.stop(singleton).await
+    }
+}
+
+impl<InjectableT, DIContainerT> Clone
+    for AsyncLazySingletonProvider<InjectableT, DIContainerT>
+where
+    InjectableT: AsyncInjectable<DIContainerT>,
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            singleton: self.singleton.clone(),
+            hooks: self.hooks.clone(),
             di_container_phantom: PhantomData,
         }
     }
@@ -214,6 +551,48 @@ where
     {
         Box::new(self.clone())
     }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        None
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        match self.providable_func_kind {
+            #[cfg(feature = "factory")]
+            ProvidableFunctionKind::UserCalled => BindingScope::Factory,
+            #[cfg(feature = "factory")]
+            ProvidableFunctionKind::AsyncUserCalled => BindingScope::AsyncFactory,
+            ProvidableFunctionKind::Instant => BindingScope::DynamicValue,
+            ProvidableFunctionKind::AsyncInstant => BindingScope::AsyncDynamicValue,
+        }
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        false
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        None
+    }
+
+    fn is_startable(&self) -> bool
+    {
+        false
+    }
+
+    async fn start(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
+
+    async fn stop(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
 }
 
 impl Clone for AsyncFunctionProvider
@@ -263,9 +642,10 @@ mod tests
         let singleton_provider = AsyncSingletonProvider::<
             subjects_async::UserManager,
             MockAsyncDIContainer,
-        >::new(ThreadsafeSingletonPtr::new(
-            subjects_async::UserManager {},
-        ));
+        >::new(
+            ThreadsafeSingletonPtr::new(subjects_async::UserManager {}),
+            AsyncLifecycleHooks::default(),
+        );
 
         let di_container = MockAsyncDIContainer::new();
 
@@ -281,6 +661,71 @@ mod tests
         );
     }
 
+    #[tokio::test]
+    async fn async_lazy_singleton_provider_works()
+    {
+        let lazy_singleton_provider = AsyncLazySingletonProvider::<
+            subjects_async::UserManager,
+            MockAsyncDIContainer,
+        >::new(AsyncLifecycleHooks::default());
+
+        let di_container = MockAsyncDIContainer::new();
+
+        let mut dependency_history_mock = MockDependencyHistory::new();
+
+        dependency_history_mock
+            .expect_contains::<subjects_async::UserManager>()
+            .return_const(false)
+            .once();
+
+        let first_providable = lazy_singleton_provider
+            .provide(&di_container, dependency_history_mock)
+            .await
+            .unwrap();
+
+        let second_providable = lazy_singleton_provider
+            .do_clone()
+            .provide(&di_container, MockDependencyHistory::new())
+            .await
+            .unwrap();
+
+        assert!(
+            matches!(
+                (first_providable, second_providable),
+                (AsyncProvidable::Singleton(first), AsyncProvidable::Singleton(second))
+                    if Arc::ptr_eq(&first, &second)
+            ),
+            "The provided types are not the same singleton"
+        );
+    }
+
+    #[tokio::test]
+    async fn async_lazy_singleton_provider_detects_circular()
+    {
+        let lazy_singleton_provider = AsyncLazySingletonProvider::<
+            subjects_async::UserManager,
+            MockAsyncDIContainer,
+        >::new(AsyncLifecycleHooks::default());
+
+        let di_container = MockAsyncDIContainer::new();
+
+        let mut dependency_history_mock = MockDependencyHistory::new();
+
+        dependency_history_mock
+            .expect_contains::<subjects_async::UserManager>()
+            .return_const(true)
+            .once();
+
+        assert!(matches!(
+            lazy_singleton_provider
+                .provide(&di_container, dependency_history_mock)
+                .await,
+            Err(InjectableError::DetectedCircular { .. })
+        ));
+
+        assert!(!lazy_singleton_provider.is_singleton_built());
+    }
+
     #[tokio::test]
     async fn function_provider_works()
     {
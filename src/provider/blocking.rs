@@ -1,13 +1,24 @@
-use std::marker::PhantomData;
+use std::any::type_name;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use once_cell::unsync::OnceCell;
 
 use crate::castable_function::AnyCastableFunction;
+use crate::di_container::BindingScope;
 use crate::errors::injectable::InjectableError;
 use crate::interfaces::injectable::Injectable;
+use crate::provider::{
+    next_construction_order,
+    HeldSingleton,
+    LifecycleResult,
+    ProvidedInjectable,
+};
 use crate::ptr::{SingletonPtr, TransientPtr};
 use crate::util::use_double;
 
 use_double!(crate::dependency_history::DependencyHistory);
+use_double!(crate::di_container::blocking::DIContainer);
 
 #[derive(strum_macros::Display, Debug)]
 pub enum Providable<DIContainerType>
@@ -33,14 +44,125 @@ pub trait IProvider<DIContainerType>
         di_container: &DIContainerType,
         dependency_history: DependencyHistory,
     ) -> Result<Providable<DIContainerType>, InjectableError>;
+
+    /// Returns the injectable that is provided, if the provider provides one.
+    fn injectable(&self) -> Option<ProvidedInjectable>;
+
+    /// Returns how the provided instances are created.
+    fn scope(&self) -> BindingScope;
+
+    /// Returns whether or not the provider holds a singleton that has been built.
+    fn is_singleton_built(&self) -> bool;
+
+    /// Returns the singleton that the provider holds, if it holds a built one.
+    fn held_singleton(&self) -> Option<HeldSingleton>;
+
+    /// Runs the deactivation hook on the singleton that the provider holds, if it
+    /// holds a built one.
+    fn deactivate(&self);
+
+    /// Returns whether or not the provider provides a singleton that is started by
+    /// `start_all`.
+    fn is_startable(&self) -> bool;
+
+    /// Starts the singleton that the provider holds, if it holds a built one that is
+    /// startable.
+    fn start(&self) -> Option<LifecycleResult>;
+
+    /// Stops the singleton that the provider holds, if it holds a built one that is
+    /// stoppable.
+    fn stop(&self) -> Option<LifecycleResult>;
+}
+
+pub type ActivationHook<InjectableType, DIContainerType> = Rc<
+    dyn Fn(
+        TransientPtr<InjectableType>,
+        &DIContainerType,
+    ) -> TransientPtr<InjectableType>,
+>;
+
+pub type DeactivationHook<InjectableType> = Rc<dyn Fn(&InjectableType)>;
+
+/// Hooks that are run on the instances of a binding.
+#[allow(clippy::struct_field_names)]
+pub struct InstanceHooks<InjectableType, DIContainerType>
+{
+    pub on_activation: Option<ActivationHook<InjectableType, DIContainerType>>,
+    pub on_deactivation: Option<DeactivationHook<InjectableType>>,
+    pub on_start: Option<fn(&InjectableType) -> LifecycleResult>,
+    pub on_stop: Option<fn(&InjectableType) -> LifecycleResult>,
+}
+
+impl<InjectableType, DIContainerType> InstanceHooks<InjectableType, DIContainerType>
+{
+    /// Runs the activation hook on a newly resolved instance.
+    #[allow(clippy::unnecessary_box_returns)] // The hook may replace the instance
+    pub fn activate(
+        &self,
+        instance: TransientPtr<InjectableType>,
+        di_container: &DIContainerType,
+    ) -> TransientPtr<InjectableType>
+    {
+        match &self.on_activation {
+            Some(on_activation) => on_activation(instance, di_container),
+            None => instance,
+        }
+    }
+
+    /// Runs the deactivation hook on a instance that is being disposed.
+    pub fn deactivate(&self, instance: &InjectableType)
+    {
+        if let Some(on_deactivation) = &self.on_deactivation {
+            on_deactivation(instance);
+        }
+    }
+
+    /// Starts a singleton, if it's startable.
+    pub fn start(&self, singleton: &InjectableType) -> Option<LifecycleResult>
+    {
+        self.on_start.map(|on_start| on_start(singleton))
+    }
+
+    /// Stops a singleton, if it's stoppable.
+    pub fn stop(&self, singleton: &InjectableType) -> Option<LifecycleResult>
+    {
+        self.on_stop.map(|on_stop| on_stop(singleton))
+    }
+}
+
+impl<InjectableType, DIContainerType> Default
+    for InstanceHooks<InjectableType, DIContainerType>
+{
+    fn default() -> Self
+    {
+        Self {
+            on_activation: None,
+            on_deactivation: None,
+            on_start: None,
+            on_stop: None,
+        }
+    }
+}
+
+impl<InjectableType, DIContainerType> Clone
+    for InstanceHooks<InjectableType, DIContainerType>
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            on_activation: self.on_activation.clone(),
+            on_deactivation: self.on_deactivation.clone(),
+            on_start: self.on_start,
+            on_stop: self.on_stop,
+        }
+    }
 }
 
 pub struct TransientTypeProvider<InjectableType, DIContainerType>
 where
     InjectableType: Injectable<DIContainerType>,
 {
-    injectable_phantom: PhantomData<InjectableType>,
-    di_container_phantom: PhantomData<DIContainerType>,
+    hooks: InstanceHooks<InjectableType, DIContainerType>,
 }
 
 impl<InjectableType, DIContainerType>
@@ -48,12 +170,9 @@ impl<InjectableType, DIContainerType>
 where
     InjectableType: Injectable<DIContainerType>,
 {
-    pub fn new() -> Self
+    pub fn new(hooks: InstanceHooks<InjectableType, DIContainerType>) -> Self
     {
-        Self {
-            injectable_phantom: PhantomData,
-            di_container_phantom: PhantomData,
-        }
+        Self { hooks }
     }
 }
 
@@ -68,10 +187,50 @@ where
         dependency_history: DependencyHistory,
     ) -> Result<Providable<DIContainerType>, InjectableError>
     {
-        Ok(Providable::Transient(InjectableType::resolve(
-            di_container,
-            dependency_history,
-        )?))
+        let transient = InjectableType::resolve(di_container, dependency_history)?;
+
+        Ok(Providable::Transient(
+            self.hooks.activate(transient, di_container),
+        ))
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableType>(
+            InjectableType::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::Transient
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        false
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        None
+    }
+
+    fn deactivate(&self) {}
+
+    fn is_startable(&self) -> bool
+    {
+        false
+    }
+
+    fn start(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
+
+    fn stop(&self) -> Option<LifecycleResult>
+    {
+        None
     }
 }
 
@@ -80,19 +239,25 @@ where
     InjectableType: Injectable<DIContainerType>,
 {
     singleton: SingletonPtr<InjectableType>,
-
-    di_container_phantom: PhantomData<DIContainerType>,
+    construction_order: usize,
+    hooks: InstanceHooks<InjectableType, DIContainerType>,
 }
 
 impl<InjectableType, DIContainerType> SingletonProvider<InjectableType, DIContainerType>
 where
     InjectableType: Injectable<DIContainerType>,
 {
-    pub fn new(singleton: SingletonPtr<InjectableType>) -> Self
+    /// The activation hook is not run by the provider, as the singleton is already
+    /// constructed.
+    pub fn new(
+        singleton: SingletonPtr<InjectableType>,
+        hooks: InstanceHooks<InjectableType, DIContainerType>,
+    ) -> Self
     {
         Self {
             singleton,
-            di_container_phantom: PhantomData,
+            construction_order: next_construction_order(),
+            hooks,
         }
     }
 }
@@ -110,6 +275,266 @@ where
     {
         Ok(Providable::Singleton(self.singleton.clone()))
     }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableType>(
+            InjectableType::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::Singleton
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        true
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        Some(HeldSingleton::new::<InjectableType>(
+            self.construction_order,
+            SingletonPtr::strong_count(&self.singleton),
+            SingletonPtr::as_ptr(&self.singleton),
+        ))
+    }
+
+    fn deactivate(&self)
+    {
+        self.hooks.deactivate(&self.singleton);
+    }
+
+    fn is_startable(&self) -> bool
+    {
+        self.hooks.on_start.is_some()
+    }
+
+    fn start(&self) -> Option<LifecycleResult>
+    {
+        self.hooks.start(&self.singleton)
+    }
+
+    fn stop(&self) -> Option<LifecycleResult>
+    {
+        self.hooks.stop(&self.singleton)
+    }
+}
+
+pub struct LazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: Injectable<DIContainerType>,
+{
+    /// The singleton together with it's position in the construction order.
+    singleton: OnceCell<(SingletonPtr<InjectableType>, usize)>,
+
+    hooks: InstanceHooks<InjectableType, DIContainerType>,
+}
+
+impl<InjectableType, DIContainerType>
+    LazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: Injectable<DIContainerType>,
+{
+    pub fn new(hooks: InstanceHooks<InjectableType, DIContainerType>) -> Self
+    {
+        Self {
+            singleton: OnceCell::new(),
+            hooks,
+        }
+    }
+}
+
+impl<InjectableType, DIContainerType> IProvider<DIContainerType>
+    for LazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: Injectable<DIContainerType>,
+{
+    fn provide(
+        &self,
+        di_container: &DIContainerType,
+        dependency_history: DependencyHistory,
+    ) -> Result<Providable<DIContainerType>, InjectableError>
+    {
+        let (singleton, _) = self.singleton.get_or_try_init(|| {
+            InjectableType::resolve(di_container, dependency_history).map(|singleton| {
+                (
+                    SingletonPtr::from(self.hooks.activate(singleton, di_container)),
+                    next_construction_order(),
+                )
+            })
+        })?;
+
+        Ok(Providable::Singleton(singleton.clone()))
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableType>(
+            InjectableType::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::LazySingleton
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        self.singleton.get().is_some()
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        self.singleton.get().map(|(singleton, construction_order)| {
+            HeldSingleton::new::<InjectableType>(
+                *construction_order,
+                SingletonPtr::strong_count(singleton),
+                SingletonPtr::as_ptr(singleton),
+            )
+        })
+    }
+
+    fn deactivate(&self)
+    {
+        if let Some((singleton, _)) = self.singleton.get() {
+            self.hooks.deactivate(singleton);
+        }
+    }
+
+    fn is_startable(&self) -> bool
+    {
+        self.hooks.on_start.is_some()
+    }
+
+    fn start(&self) -> Option<LifecycleResult>
+    {
+        let (singleton, _) = self.singleton.get()?;
+
+        self.hooks.start(singleton)
+    }
+
+    fn stop(&self) -> Option<LifecycleResult>
+    {
+        let (singleton, _) = self.singleton.get()?;
+
+        self.hooks.stop(singleton)
+    }
+}
+
+pub struct ScopedProvider<InjectableType>
+where
+    InjectableType: Injectable<DIContainer>,
+{
+    scoped_binding_id: usize,
+
+    hooks: InstanceHooks<InjectableType, DIContainer>,
+}
+
+impl<InjectableType> ScopedProvider<InjectableType>
+where
+    InjectableType: Injectable<DIContainer>,
+{
+    pub fn new(hooks: InstanceHooks<InjectableType, DIContainer>) -> Self
+    {
+        static NEXT_SCOPED_BINDING_ID: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            scoped_binding_id: NEXT_SCOPED_BINDING_ID.fetch_add(1, Ordering::Relaxed),
+            hooks,
+        }
+    }
+}
+
+impl<InjectableType> IProvider<DIContainer> for ScopedProvider<InjectableType>
+where
+    InjectableType: Injectable<DIContainer>,
+{
+    fn provide(
+        &self,
+        di_container: &DIContainer,
+        dependency_history: DependencyHistory,
+    ) -> Result<Providable<DIContainer>, InjectableError>
+    {
+        if !di_container.is_scope() {
+            return Err(InjectableError::NotInScope {
+                affected: type_name::<InjectableType>(),
+            });
+        }
+
+        if let Some(scoped_instance) =
+            di_container.get_scoped_instance(self.scoped_binding_id)
+        {
+            return Ok(Providable::Singleton(scoped_instance));
+        }
+
+        let scoped_instance: SingletonPtr<InjectableType> =
+            SingletonPtr::from(self.hooks.activate(
+                InjectableType::resolve(di_container, dependency_history)?,
+                di_container,
+            ));
+
+        let on_deactivation = self.hooks.on_deactivation.clone().map(|on_deactivation| {
+            let weak_scoped_instance = SingletonPtr::downgrade(&scoped_instance);
+
+            Box::new(move || {
+                if let Some(scoped_instance) = weak_scoped_instance.upgrade() {
+                    on_deactivation(&scoped_instance);
+                }
+            }) as Box<dyn FnOnce()>
+        });
+
+        di_container.set_scoped_instance(
+            self.scoped_binding_id,
+            type_name::<InjectableType>(),
+            scoped_instance.clone(),
+            on_deactivation,
+        );
+
+        Ok(Providable::Singleton(scoped_instance))
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableType>(
+            InjectableType::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::Scoped
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        false
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        None
+    }
+
+    fn deactivate(&self) {}
+
+    fn is_startable(&self) -> bool
+    {
+        false
+    }
+
+    fn start(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
+
+    fn stop(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
 }
 
 pub struct FunctionProvider
@@ -145,11 +570,54 @@ impl<DIContainerType> IProvider<DIContainerType> for FunctionProvider
             self.providable_func_kind,
         ))
     }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        None
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        match self.providable_func_kind {
+            #[cfg(feature = "factory")]
+            ProvidableFunctionKind::UserCalled => BindingScope::Factory,
+            ProvidableFunctionKind::Instant => BindingScope::DynamicValue,
+        }
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        false
+    }
+
+    fn held_singleton(&self) -> Option<HeldSingleton>
+    {
+        None
+    }
+
+    fn deactivate(&self) {}
+
+    fn is_startable(&self) -> bool
+    {
+        false
+    }
+
+    fn start(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
+
+    fn stop(&self) -> Option<LifecycleResult>
+    {
+        None
+    }
 }
 
 #[cfg(test)]
 mod tests
 {
+    use std::cell::Cell;
+
     use super::*;
     use crate::dependency_history::MockDependencyHistory;
     use crate::di_container::blocking::MockDIContainer;
@@ -158,8 +626,10 @@ mod tests
     #[test]
     fn transient_type_provider_works()
     {
-        let transient_type_provider =
-            TransientTypeProvider::<subjects::UserManager, MockDIContainer>::new();
+        let transient_type_provider = TransientTypeProvider::<
+            subjects::UserManager,
+            MockDIContainer,
+        >::new(InstanceHooks::default());
 
         let di_container = MockDIContainer::new();
 
@@ -174,12 +644,43 @@ mod tests
         );
     }
 
+    #[test]
+    fn transient_type_provider_runs_activation_hook()
+    {
+        let activation_cnt = Rc::new(Cell::new(0));
+
+        let transient_type_provider = TransientTypeProvider::<
+            subjects::UserManager,
+            MockDIContainer,
+        >::new(InstanceHooks {
+            on_activation: Some(Rc::new({
+                let activation_cnt = activation_cnt.clone();
+
+                move |instance, _| {
+                    activation_cnt.set(activation_cnt.get() + 1);
+
+                    instance
+                }
+            })),
+            ..InstanceHooks::default()
+        });
+
+        let di_container = MockDIContainer::new();
+
+        transient_type_provider
+            .provide(&di_container, MockDependencyHistory::new())
+            .unwrap();
+
+        assert_eq!(activation_cnt.get(), 1);
+    }
+
     #[test]
     fn singleton_provider_works()
     {
         let singleton_provider =
             SingletonProvider::<subjects::UserManager, MockDIContainer>::new(
                 SingletonPtr::new(subjects::UserManager {}),
+                InstanceHooks::default(),
             );
 
         let di_container = MockDIContainer::new();
@@ -195,6 +696,110 @@ mod tests
         );
     }
 
+    #[test]
+    fn lazy_singleton_provider_works()
+    {
+        let lazy_singleton_provider = LazySingletonProvider::<
+            subjects::UserManager,
+            MockDIContainer,
+        >::new(InstanceHooks::default());
+
+        let di_container = MockDIContainer::new();
+
+        let first_providable = lazy_singleton_provider
+            .provide(&di_container, MockDependencyHistory::new())
+            .unwrap();
+
+        let second_providable = lazy_singleton_provider
+            .provide(&di_container, MockDependencyHistory::new())
+            .unwrap();
+
+        assert!(
+            matches!(
+                (first_providable, second_providable),
+                (Providable::Singleton(first), Providable::Singleton(second))
+                    if Rc::ptr_eq(&first, &second)
+            ),
+            "The provided types are not the same singleton"
+        );
+    }
+
+    #[test]
+    fn lazy_singleton_provider_runs_deactivation_hook()
+    {
+        let deactivation_cnt = Rc::new(Cell::new(0));
+
+        let lazy_singleton_provider = LazySingletonProvider::<
+            subjects::UserManager,
+            MockDIContainer,
+        >::new(InstanceHooks {
+            on_deactivation: Some(Rc::new({
+                let deactivation_cnt = deactivation_cnt.clone();
+
+                move |_| deactivation_cnt.set(deactivation_cnt.get() + 1)
+            })),
+            ..InstanceHooks::default()
+        });
+
+        // Nothing to deactivate as the singleton is not built
+        lazy_singleton_provider.deactivate();
+
+        assert_eq!(deactivation_cnt.get(), 0);
+
+        lazy_singleton_provider
+            .provide(&MockDIContainer::new(), MockDependencyHistory::new())
+            .unwrap();
+
+        lazy_singleton_provider.deactivate();
+
+        assert_eq!(deactivation_cnt.get(), 1);
+    }
+
+    #[test]
+    fn scoped_provider_works()
+    {
+        let scoped_provider =
+            ScopedProvider::<subjects::UserManager>::new(InstanceHooks::default());
+
+        let mut di_container = MockDIContainer::new();
+
+        di_container.expect_is_scope().return_const(true);
+
+        di_container
+            .expect_get_scoped_instance()
+            .return_once(|_| None)
+            .once();
+
+        di_container
+            .expect_set_scoped_instance()
+            .return_once(|_, _, _, _| ())
+            .once();
+
+        assert!(
+            matches!(
+                scoped_provider.provide(&di_container, MockDependencyHistory::new()),
+                Ok(Providable::Singleton(_))
+            ),
+            "The provided type is not a singleton"
+        );
+    }
+
+    #[test]
+    fn scoped_provider_fails_outside_of_scope()
+    {
+        let scoped_provider =
+            ScopedProvider::<subjects::UserManager>::new(InstanceHooks::default());
+
+        let mut di_container = MockDIContainer::new();
+
+        di_container.expect_is_scope().return_const(false);
+
+        assert!(matches!(
+            scoped_provider.provide(&di_container, MockDependencyHistory::new()),
+            Err(InjectableError::NotInScope { .. })
+        ));
+    }
+
     #[test]
     fn function_provider_works()
     {
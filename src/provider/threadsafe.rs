@@ -0,0 +1,346 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use once_cell::sync::OnceCell;
+
+use crate::castable_function::threadsafe::AnyThreadsafeCastableFunction;
+use crate::di_container::BindingScope;
+use crate::errors::injectable::InjectableError;
+use crate::interfaces::threadsafe_injectable::ThreadsafeInjectable;
+use crate::provider::blocking::ProvidableFunctionKind;
+use crate::provider::ProvidedInjectable;
+use crate::ptr::{ThreadsafeSingletonPtr, TransientPtr};
+use crate::util::use_double;
+
+use_double!(crate::dependency_history::DependencyHistory);
+
+#[derive(strum_macros::Display, Debug)]
+pub enum ThreadsafeProvidable<DIContainerType>
+{
+    Transient(TransientPtr<dyn ThreadsafeInjectable<DIContainerType>>),
+    Singleton(ThreadsafeSingletonPtr<dyn ThreadsafeInjectable<DIContainerType>>),
+    Function(
+        Arc<dyn AnyThreadsafeCastableFunction>,
+        ProvidableFunctionKind,
+    ),
+}
+
+pub trait IThreadsafeProvider<DIContainerType>: Send + Sync
+{
+    fn provide(
+        &self,
+        di_container: &DIContainerType,
+        dependency_history: DependencyHistory,
+    ) -> Result<ThreadsafeProvidable<DIContainerType>, InjectableError>;
+
+    /// Returns the injectable that is provided, if the provider provides one.
+    fn injectable(&self) -> Option<ProvidedInjectable>;
+
+    /// Returns how the provided instances are created.
+    fn scope(&self) -> BindingScope;
+
+    /// Returns whether or not the provider holds a singleton that has been built.
+    fn is_singleton_built(&self) -> bool;
+}
+
+pub struct ThreadsafeTransientTypeProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    injectable_phantom: PhantomData<InjectableType>,
+    di_container_phantom: PhantomData<fn(&DIContainerType)>,
+}
+
+impl<InjectableType, DIContainerType>
+    ThreadsafeTransientTypeProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    pub fn new() -> Self
+    {
+        Self {
+            injectable_phantom: PhantomData,
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+impl<InjectableType, DIContainerType> IThreadsafeProvider<DIContainerType>
+    for ThreadsafeTransientTypeProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    fn provide(
+        &self,
+        di_container: &DIContainerType,
+        dependency_history: DependencyHistory,
+    ) -> Result<ThreadsafeProvidable<DIContainerType>, InjectableError>
+    {
+        Ok(ThreadsafeProvidable::Transient(InjectableType::resolve(
+            di_container,
+            dependency_history,
+        )?))
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableType>(
+            InjectableType::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::Transient
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        false
+    }
+}
+
+pub struct ThreadsafeSingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    singleton: ThreadsafeSingletonPtr<InjectableType>,
+
+    di_container_phantom: PhantomData<fn(&DIContainerType)>,
+}
+
+impl<InjectableType, DIContainerType>
+    ThreadsafeSingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    pub fn new(singleton: ThreadsafeSingletonPtr<InjectableType>) -> Self
+    {
+        Self {
+            singleton,
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+impl<InjectableType, DIContainerType> IThreadsafeProvider<DIContainerType>
+    for ThreadsafeSingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    fn provide(
+        &self,
+        _di_container: &DIContainerType,
+        _dependency_history: DependencyHistory,
+    ) -> Result<ThreadsafeProvidable<DIContainerType>, InjectableError>
+    {
+        Ok(ThreadsafeProvidable::Singleton(self.singleton.clone()))
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableType>(
+            InjectableType::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::Singleton
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        true
+    }
+}
+
+pub struct ThreadsafeLazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    singleton: OnceCell<ThreadsafeSingletonPtr<InjectableType>>,
+
+    di_container_phantom: PhantomData<fn(&DIContainerType)>,
+}
+
+impl<InjectableType, DIContainerType>
+    ThreadsafeLazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    pub fn new() -> Self
+    {
+        Self {
+            singleton: OnceCell::new(),
+            di_container_phantom: PhantomData,
+        }
+    }
+}
+
+impl<InjectableType, DIContainerType> IThreadsafeProvider<DIContainerType>
+    for ThreadsafeLazySingletonProvider<InjectableType, DIContainerType>
+where
+    InjectableType: ThreadsafeInjectable<DIContainerType>,
+{
+    fn provide(
+        &self,
+        di_container: &DIContainerType,
+        dependency_history: DependencyHistory,
+    ) -> Result<ThreadsafeProvidable<DIContainerType>, InjectableError>
+    {
+        if let Some(singleton) = self.singleton.get() {
+            return Ok(ThreadsafeProvidable::Singleton(singleton.clone()));
+        }
+
+        // The singleton is resolved without holding the cell so that a singleton that
+        // depends on itself is reported as circular instead of deadlocking. Should
+        // several threads resolve it at once, the first one to finish wins
+        let singleton = ThreadsafeSingletonPtr::from(InjectableType::resolve(
+            di_container,
+            dependency_history,
+        )?);
+
+        let singleton = self.singleton.get_or_init(|| singleton);
+
+        Ok(ThreadsafeProvidable::Singleton(singleton.clone()))
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        Some(ProvidedInjectable::new::<InjectableType>(
+            InjectableType::dependencies(),
+        ))
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        BindingScope::LazySingleton
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        self.singleton.get().is_some()
+    }
+}
+
+pub struct ThreadsafeFunctionProvider
+{
+    function: Arc<dyn AnyThreadsafeCastableFunction>,
+    providable_func_kind: ProvidableFunctionKind,
+}
+
+impl ThreadsafeFunctionProvider
+{
+    pub fn new(
+        function: Arc<dyn AnyThreadsafeCastableFunction>,
+        providable_func_kind: ProvidableFunctionKind,
+    ) -> Self
+    {
+        Self {
+            function,
+            providable_func_kind,
+        }
+    }
+}
+
+impl<DIContainerType> IThreadsafeProvider<DIContainerType> for ThreadsafeFunctionProvider
+{
+    fn provide(
+        &self,
+        _di_container: &DIContainerType,
+        _dependency_history: DependencyHistory,
+    ) -> Result<ThreadsafeProvidable<DIContainerType>, InjectableError>
+    {
+        Ok(ThreadsafeProvidable::Function(
+            self.function.clone(),
+            self.providable_func_kind,
+        ))
+    }
+
+    fn injectable(&self) -> Option<ProvidedInjectable>
+    {
+        None
+    }
+
+    fn scope(&self) -> BindingScope
+    {
+        match self.providable_func_kind {
+            #[cfg(feature = "factory")]
+            ProvidableFunctionKind::UserCalled => BindingScope::Factory,
+            ProvidableFunctionKind::Instant => BindingScope::DynamicValue,
+        }
+    }
+
+    fn is_singleton_built(&self) -> bool
+    {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::dependency_history::MockDependencyHistory;
+
+    #[derive(Default)]
+    struct Counter {}
+
+    #[test]
+    fn transient_type_provider_works()
+    {
+        let transient_type_provider =
+            ThreadsafeTransientTypeProvider::<Counter, ()>::new();
+
+        assert!(
+            matches!(
+                transient_type_provider.provide(&(), MockDependencyHistory::new()),
+                Ok(ThreadsafeProvidable::Transient(_))
+            ),
+            "The provided type is not transient"
+        );
+    }
+
+    #[test]
+    fn singleton_provider_works()
+    {
+        let singleton_provider = ThreadsafeSingletonProvider::<Counter, ()>::new(
+            ThreadsafeSingletonPtr::new(Counter {}),
+        );
+
+        assert!(
+            matches!(
+                singleton_provider.provide(&(), MockDependencyHistory::new()),
+                Ok(ThreadsafeProvidable::Singleton(_))
+            ),
+            "The provided type is not a singleton"
+        );
+    }
+
+    #[test]
+    fn lazy_singleton_provider_works()
+    {
+        let lazy_singleton_provider =
+            ThreadsafeLazySingletonProvider::<Counter, ()>::new();
+
+        let first_providable = lazy_singleton_provider
+            .provide(&(), MockDependencyHistory::new())
+            .unwrap();
+
+        let second_providable = lazy_singleton_provider
+            .provide(&(), MockDependencyHistory::new())
+            .unwrap();
+
+        assert!(
+            matches!(
+                (first_providable, second_providable),
+                (
+                    ThreadsafeProvidable::Singleton(first),
+                    ThreadsafeProvidable::Singleton(second)
+                ) if Arc::ptr_eq(&first, &second)
+            ),
+            "The provided singletons are not the same"
+        );
+    }
+}
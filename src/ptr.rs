@@ -1,7 +1,9 @@
 //! Smart pointer type aliases.
-use std::rc::Rc;
+use std::fmt::Debug;
+use std::rc::{Rc, Weak};
 use std::sync::Arc;
 
+use once_cell::unsync::OnceCell;
 use paste::paste;
 
 use crate::errors::ptr::SomePtrError;
@@ -25,6 +27,83 @@ pub type FactoryPtr<FactoryInterface> = Rc<FactoryInterface>;
 #[cfg_attr(doc_cfg, doc(cfg(feature = "factory")))]
 pub type ThreadsafeFactoryPtr<FactoryInterface> = Arc<FactoryInterface>;
 
+/// A weak smart pointer to a interface in the singleton scope.
+///
+/// Used as a back-reference to a singleton that depends on the injectable, directly
+/// or indirectly. When injected while the singleton is being resolved, it is filled
+/// once the singleton has been created.
+pub struct WeakSingletonPtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    inner: Rc<OnceCell<Weak<Interface>>>,
+}
+
+impl<Interface> WeakSingletonPtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    pub(crate) fn new() -> Self
+    {
+        Self {
+            inner: Rc::new(OnceCell::new()),
+        }
+    }
+
+    pub(crate) fn fill(&self, singleton: &SingletonPtr<Interface>)
+    {
+        // Already being filled is not a problem since it's the same singleton
+        let _ = self.inner.set(Rc::downgrade(singleton));
+    }
+
+    /// Returns the singleton if it has been created and not yet been dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<SingletonPtr<Interface>>
+    {
+        self.inner.get()?.upgrade()
+    }
+}
+
+impl<Interface> From<&SingletonPtr<Interface>> for WeakSingletonPtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    fn from(singleton: &SingletonPtr<Interface>) -> Self
+    {
+        let weak_singleton = Self::new();
+
+        weak_singleton.fill(singleton);
+
+        weak_singleton
+    }
+}
+
+impl<Interface> Clone for WeakSingletonPtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<Interface> Debug for WeakSingletonPtr<Interface>
+where
+    Interface: 'static + ?Sized,
+{
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        formatter
+            .debug_struct("WeakSingletonPtr")
+            .field("is_filled", &self.inner.get().is_some())
+            .finish()
+    }
+}
+
 macro_rules! create_as_variant_fn {
     ($enum: ident, $variant: ident, $err: ident) => {
         create_as_variant_fn!($enum, $variant, $err,);
@@ -273,8 +273,10 @@ pub mod mocks
         use async_trait::async_trait;
         use mockall::mock;
 
+        use crate::di_container::BindingScope;
         use crate::errors::injectable::InjectableError;
         use crate::provider::r#async::{AsyncProvidable, IAsyncProvider};
+        use crate::provider::{HeldSingleton, LifecycleResult, ProvidedInjectable};
         use crate::util::use_double;
 
         use_double!(crate::dependency_history::DependencyHistory);
@@ -296,6 +298,20 @@ pub mod mocks
 
                 fn do_clone(&self) ->
                     Box<dyn IAsyncProvider<DIContainerT>>;
+
+                fn injectable(&self) -> Option<ProvidedInjectable>;
+
+                fn scope(&self) -> BindingScope;
+
+                fn is_singleton_built(&self) -> bool;
+
+                fn held_singleton(&self) -> Option<HeldSingleton>;
+
+                fn is_startable(&self) -> bool;
+
+                async fn start(&self) -> Option<LifecycleResult>;
+
+                async fn stop(&self) -> Option<LifecycleResult>;
             }
         }
     }
@@ -0,0 +1,255 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+struct Counter
+{
+    count: Cell<usize>,
+}
+
+#[injectable]
+impl Counter
+{
+    fn new() -> Self
+    {
+        Self {
+            count: Cell::new(0),
+        }
+    }
+}
+
+#[derive(Default)]
+struct MetricsRegistry
+{
+    registered: RefCell<Vec<&'static str>>,
+}
+
+#[test]
+fn runs_activation_hook_on_every_transient() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_activation(|counter, _| {
+            counter.count.set(10);
+
+            counter
+        });
+
+    assert_eq!(di_container.get::<Counter>()?.transient()?.count.get(), 10);
+    assert_eq!(di_container.get::<Counter>()?.transient()?.count.get(), 10);
+
+    Ok(())
+}
+
+#[test]
+fn activation_hook_can_replace_instance() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_activation(|_, _| {
+            TransientPtr::new(Counter {
+                count: Cell::new(3),
+            })
+        })
+        .in_lazy_singleton_scope();
+
+    let counter = di_container.get::<Counter>()?.singleton()?;
+
+    assert_eq!(counter.count.get(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn runs_activation_hook_once_for_singleton() -> Result<(), Box<dyn std::error::Error>>
+{
+    let metrics_registry = Rc::new(MetricsRegistry::default());
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_activation({
+            let metrics_registry = metrics_registry.clone();
+
+            move |counter, _| {
+                metrics_registry.registered.borrow_mut().push("Counter");
+
+                counter
+            }
+        })
+        .in_lazy_singleton_scope();
+
+    assert!(metrics_registry.registered.borrow().is_empty());
+
+    di_container.get::<Counter>()?.singleton()?;
+    di_container.get::<Counter>()?.singleton()?;
+
+    assert_eq!(*metrics_registry.registered.borrow(), vec!["Counter"]);
+
+    Ok(())
+}
+
+#[test]
+fn runs_deactivation_hook_on_dispose() -> Result<(), Box<dyn std::error::Error>>
+{
+    let deactivated_counts = Rc::new(RefCell::new(Vec::new()));
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_deactivation({
+            let deactivated_counts = deactivated_counts.clone();
+
+            move |counter| deactivated_counts.borrow_mut().push(counter.count.get())
+        })
+        .in_singleton_scope()?;
+
+    di_container.get::<Counter>()?.singleton()?.count.set(7);
+
+    assert!(deactivated_counts.borrow().is_empty());
+
+    drop(di_container);
+
+    assert_eq!(*deactivated_counts.borrow(), vec![7]);
+
+    Ok(())
+}
+
+#[test]
+fn runs_deactivation_hook_on_unbind() -> Result<(), Box<dyn std::error::Error>>
+{
+    let deactivated_counts = Rc::new(RefCell::new(Vec::new()));
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_deactivation({
+            let deactivated_counts = deactivated_counts.clone();
+
+            move |counter| deactivated_counts.borrow_mut().push(counter.count.get())
+        })
+        .in_singleton_scope()?;
+
+    let counter = di_container.get::<Counter>()?.singleton()?;
+
+    counter.count.set(3);
+
+    let referenced_instances = di_container.unbind::<Counter>()?;
+
+    assert_eq!(*deactivated_counts.borrow(), vec![3]);
+
+    assert_eq!(referenced_instances.len(), 1);
+    assert_eq!(referenced_instances[0].reference_count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn runs_deactivation_hook_of_replaced_binding_on_rebind(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let deactivated_counts = Rc::new(RefCell::new(Vec::new()));
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_deactivation({
+            let deactivated_counts = deactivated_counts.clone();
+
+            move |counter| deactivated_counts.borrow_mut().push(counter.count.get())
+        })
+        .in_singleton_scope()?;
+
+    di_container.get::<Counter>()?.singleton()?.count.set(5);
+
+    di_container
+        .rebind::<Counter>()
+        .to::<Counter>()?
+        .in_singleton_scope()?;
+
+    assert_eq!(*deactivated_counts.borrow(), vec![5]);
+
+    assert_eq!(di_container.get::<Counter>()?.singleton()?.count.get(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn does_not_run_deactivation_hook_for_unbuilt_lazy_singleton(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let is_deactivated = Rc::new(Cell::new(false));
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_deactivation({
+            let is_deactivated = is_deactivated.clone();
+
+            move |_| is_deactivated.set(true)
+        })
+        .in_lazy_singleton_scope();
+
+    di_container.dispose();
+
+    assert!(!is_deactivated.get());
+
+    Ok(())
+}
+
+#[test]
+fn runs_deactivation_hook_when_scope_ends() -> Result<(), Box<dyn std::error::Error>>
+{
+    let deactivation_cnt = Rc::new(Cell::new(0));
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Counter>()
+        .to::<Counter>()?
+        .on_deactivation({
+            let deactivation_cnt = deactivation_cnt.clone();
+
+            move |_| deactivation_cnt.set(deactivation_cnt.get() + 1)
+        })
+        .in_scoped_scope();
+
+    let di_container = Rc::new(di_container);
+
+    let first_scope = di_container.begin_scope();
+    let second_scope = di_container.begin_scope();
+
+    first_scope.get::<Counter>()?.singleton()?;
+    first_scope.get::<Counter>()?.singleton()?;
+
+    drop(first_scope);
+
+    assert_eq!(deactivation_cnt.get(), 1);
+
+    // Nothing was resolved in the second scope
+    drop(second_scope);
+
+    assert_eq!(deactivation_cnt.get(), 1);
+
+    Ok(())
+}
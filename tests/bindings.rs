@@ -0,0 +1,156 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::type_name;
+
+use syrette::di_container::BindingScope;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IWeapon {}
+
+struct Katana {}
+
+#[injectable(IWeapon)]
+impl Katana
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IWeapon for Katana {}
+
+struct Ninja {}
+
+#[injectable]
+impl Ninja
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+trait IShadow {}
+
+struct Shadow {}
+
+impl IShadow for Shadow {}
+
+#[test]
+fn can_get_bindings() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_transient_scope()
+        .when_named("sharp")?;
+
+    di_container
+        .bind::<Ninja>()
+        .to::<Ninja>()?
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<dyn IShadow>()
+        .to_dynamic_value(&|_| Box::new(|| TransientPtr::new(Shadow {})))?;
+
+    let bindings = di_container.bindings().collect::<Vec<_>>();
+
+    assert_eq!(bindings.len(), 3);
+
+    assert_eq!(bindings[0].interface(), type_name::<dyn IWeapon>());
+    assert_eq!(bindings[0].name(), Some("sharp"));
+    assert_eq!(bindings[0].implementation(), Some(type_name::<Katana>()));
+    assert_eq!(bindings[0].scope(), BindingScope::Transient);
+    assert!(!bindings[0].is_singleton_built());
+
+    assert_eq!(bindings[1].interface(), type_name::<Ninja>());
+    assert_eq!(bindings[1].name(), None);
+    assert_eq!(bindings[1].scope(), BindingScope::LazySingleton);
+    assert!(!bindings[1].is_singleton_built());
+
+    assert_eq!(bindings[2].interface(), type_name::<dyn IShadow>());
+    assert_eq!(bindings[2].implementation(), None);
+    assert_eq!(bindings[2].scope(), BindingScope::DynamicValue);
+
+    di_container.get::<Ninja>()?.singleton()?;
+
+    assert!(di_container
+        .bindings()
+        .find(|binding| binding.interface() == type_name::<Ninja>())
+        .ok_or("Missing binding")?
+        .is_singleton_built());
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::any::type_name;
+
+    use syrette::di_container::BindingScope;
+    use syrette::{injectable, AsyncDIContainer};
+
+    struct Ninja {}
+
+    #[injectable(async = true)]
+    impl Ninja
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    #[tokio::test]
+    async fn can_get_bindings() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<Ninja>()
+            .to::<Ninja>()?
+            .in_singleton_scope()
+            .await?;
+
+        let bindings = di_container.bindings().collect::<Vec<_>>();
+
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(bindings[0].interface(), type_name::<Ninja>());
+        assert_eq!(bindings[0].implementation(), Some(type_name::<Ninja>()));
+        assert_eq!(bindings[0].scope(), BindingScope::Singleton);
+        assert!(bindings[0].is_singleton_built());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "factory")]
+    #[tokio::test]
+    async fn can_get_async_factory_binding() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use syrette::future::BoxFuture;
+        use syrette::ptr::TransientPtr;
+
+        type NinjaFactory =
+            dyn Fn() -> BoxFuture<'static, TransientPtr<Ninja>> + Send + Sync;
+
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container.bind::<NinjaFactory>().to_async_factory(&|_| {
+            Box::new(|| Box::pin(async { TransientPtr::new(Ninja {}) }))
+        })?;
+
+        let bindings = di_container.bindings().collect::<Vec<_>>();
+
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(bindings[0].implementation(), None);
+        assert_eq!(bindings[0].scope(), BindingScope::AsyncFactory);
+
+        Ok(())
+    }
+}
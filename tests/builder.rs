@@ -0,0 +1,275 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use syrette::errors::build::BuildError;
+use syrette::ptr::SingletonPtr;
+use syrette::{injectable, DIContainer};
+
+static CONFIG_BUILD_CNT: AtomicUsize = AtomicUsize::new(0);
+
+struct Config {}
+
+#[injectable]
+impl Config
+{
+    fn new() -> Self
+    {
+        CONFIG_BUILD_CNT.fetch_add(1, Ordering::SeqCst);
+
+        Self {}
+    }
+}
+
+struct Server
+{
+    config: SingletonPtr<Config>,
+}
+
+#[injectable]
+impl Server
+{
+    fn new(config: SingletonPtr<Config>) -> Self
+    {
+        Self { config }
+    }
+}
+
+#[test]
+fn build_resolves_lazy_singletons() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container_builder = DIContainer::builder();
+
+    di_container_builder
+        .bind::<Config>()
+        .to::<Config>()?
+        .in_lazy_singleton_scope();
+
+    di_container_builder.bind::<Server>().to::<Server>()?;
+
+    assert_eq!(CONFIG_BUILD_CNT.load(Ordering::SeqCst), 0);
+
+    let di_container = di_container_builder.build()?;
+
+    assert_eq!(CONFIG_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+    let other_di_container = di_container.clone();
+
+    let server = di_container.get::<Server>()?.transient()?;
+    let config = other_di_container.get::<Config>()?.singleton()?;
+
+    assert!(Rc::ptr_eq(&server.config, &config));
+    assert_eq!(CONFIG_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+#[test]
+fn build_fails_with_invalid_bindings() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container_builder = DIContainer::builder();
+
+    // The config that the server depends on is never bound
+    di_container_builder.bind::<Server>().to::<Server>()?;
+
+    assert!(matches!(
+        di_container_builder.build(),
+        Err(BuildError::InvalidBindings(_))
+    ));
+
+    Ok(())
+}
+
+mod threadsafe
+{
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+
+    use syrette::errors::build::BuildError;
+    use syrette::ptr::ThreadsafeSingletonPtr;
+    use syrette::{injectable, ThreadsafeDIContainer};
+
+    static CACHE_BUILD_CNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Cache {}
+
+    #[injectable(threadsafe = true)]
+    impl Cache
+    {
+        fn new() -> Self
+        {
+            CACHE_BUILD_CNT.fetch_add(1, Ordering::SeqCst);
+
+            Self {}
+        }
+    }
+
+    struct Worker
+    {
+        cache: ThreadsafeSingletonPtr<Cache>,
+    }
+
+    #[injectable(threadsafe = true)]
+    impl Worker
+    {
+        fn new(cache: ThreadsafeSingletonPtr<Cache>) -> Self
+        {
+            Self { cache }
+        }
+    }
+
+    #[test]
+    fn build_resolves_lazy_singletons() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container_builder = ThreadsafeDIContainer::builder();
+
+        di_container_builder
+            .bind::<Cache>()
+            .to::<Cache>()?
+            .in_lazy_singleton_scope();
+
+        di_container_builder.bind::<Worker>().to::<Worker>()?;
+
+        assert_eq!(CACHE_BUILD_CNT.load(Ordering::SeqCst), 0);
+
+        let di_container = di_container_builder.build()?;
+
+        assert_eq!(CACHE_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+        let worker = thread::spawn({
+            let di_container = di_container.clone();
+
+            move || di_container.get::<Worker>().unwrap().transient().unwrap()
+        })
+        .join()
+        .unwrap();
+
+        let cache = di_container.get::<Cache>()?.threadsafe_singleton()?;
+
+        assert!(Arc::ptr_eq(&worker.cache, &cache));
+        assert_eq!(CACHE_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn build_fails_with_invalid_bindings() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container_builder = ThreadsafeDIContainer::builder();
+
+        // The cache that the worker depends on is never bound
+        di_container_builder.bind::<Worker>().to::<Worker>()?;
+
+        assert!(matches!(
+            di_container_builder.build(),
+            Err(BuildError::InvalidBindings(_))
+        ));
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use syrette::errors::build::BuildError;
+    use syrette::ptr::ThreadsafeSingletonPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    static DATABASE_BUILD_CNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Database {}
+
+    #[injectable(async = true)]
+    impl Database
+    {
+        fn new() -> Self
+        {
+            DATABASE_BUILD_CNT.fetch_add(1, Ordering::SeqCst);
+
+            Self {}
+        }
+    }
+
+    struct Repository
+    {
+        database: ThreadsafeSingletonPtr<Database>,
+    }
+
+    #[injectable(async = true)]
+    impl Repository
+    {
+        fn new(database: ThreadsafeSingletonPtr<Database>) -> Self
+        {
+            Self { database }
+        }
+    }
+
+    #[tokio::test]
+    async fn build_resolves_lazy_singletons() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container_builder = AsyncDIContainer::builder();
+
+        di_container_builder
+            .bind::<Database>()
+            .to::<Database>()?
+            .in_lazy_singleton_scope();
+
+        di_container_builder
+            .bind::<Repository>()
+            .to::<Repository>()?;
+
+        assert_eq!(DATABASE_BUILD_CNT.load(Ordering::SeqCst), 0);
+
+        let di_container = di_container_builder.build().await?;
+
+        assert_eq!(DATABASE_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+        let repository = tokio::spawn({
+            let di_container = di_container.clone();
+
+            async move {
+                di_container
+                    .get::<Repository>()
+                    .await
+                    .unwrap()
+                    .transient()
+                    .unwrap()
+            }
+        })
+        .await?;
+
+        let database = di_container
+            .get::<Database>()
+            .await?
+            .threadsafe_singleton()?;
+
+        assert!(Arc::ptr_eq(&repository.database, &database));
+        assert_eq!(DATABASE_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn build_fails_with_invalid_bindings() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container_builder = AsyncDIContainer::builder();
+
+        // The database that the repository depends on is never bound
+        di_container_builder
+            .bind::<Repository>()
+            .to::<Repository>()?;
+
+        assert!(matches!(
+            di_container_builder.build().await,
+            Err(BuildError::InvalidBindings(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,387 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::type_name;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::errors::injectable::InjectableError;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IWeapon
+{
+    fn name(&self) -> &'static str;
+}
+
+macro_rules! weapon {
+    ($weapon: ident) => {
+        struct $weapon {}
+
+        #[injectable(IWeapon)]
+        impl $weapon
+        {
+            fn new() -> Self
+            {
+                Self {}
+            }
+        }
+
+        impl IWeapon for $weapon
+        {
+            fn name(&self) -> &'static str
+            {
+                stringify!($weapon)
+            }
+        }
+    };
+}
+
+weapon!(Katana);
+weapon!(Shuriken);
+weapon!(Sword);
+
+struct Ninja
+{
+    weapon: TransientPtr<dyn IWeapon>,
+}
+
+#[injectable]
+impl Ninja
+{
+    fn new(weapon: TransientPtr<dyn IWeapon>) -> Self
+    {
+        Self { weapon }
+    }
+}
+
+struct Samurai
+{
+    weapon: TransientPtr<dyn IWeapon>,
+}
+
+#[injectable]
+impl Samurai
+{
+    fn new(weapon: TransientPtr<dyn IWeapon>) -> Self
+    {
+        Self { weapon }
+    }
+}
+
+struct Dojo
+{
+    ninja: TransientPtr<Ninja>,
+}
+
+#[injectable]
+impl Dojo
+{
+    fn new(ninja: TransientPtr<Ninja>) -> Self
+    {
+        Self { ninja }
+    }
+}
+
+#[test]
+fn can_bind_when_injected_into() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Shuriken>()?
+        .in_transient_scope()
+        .when_injected_into::<Ninja>()?;
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_transient_scope()
+        .when_injected_into::<Samurai>()?;
+
+    di_container.bind::<dyn IWeapon>().to::<Sword>()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+    di_container.bind::<Samurai>().to::<Samurai>()?;
+
+    assert_eq!(
+        di_container.get::<Ninja>()?.transient()?.weapon.name(),
+        "Shuriken"
+    );
+    assert_eq!(
+        di_container.get::<Samurai>()?.transient()?.weapon.name(),
+        "Katana"
+    );
+    assert_eq!(di_container.get::<dyn IWeapon>()?.transient()?.name(), "Sword");
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_when_any_ancestor_is() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Shuriken>()?
+        .in_transient_scope()
+        .when_any_ancestor_is::<Dojo>()?;
+
+    di_container.bind::<dyn IWeapon>().to::<Sword>()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+    di_container.bind::<Dojo>().to::<Dojo>()?;
+
+    assert_eq!(
+        di_container.get::<Dojo>()?.transient()?.ninja.weapon.name(),
+        "Shuriken"
+    );
+    assert_eq!(
+        di_container.get::<Ninja>()?.transient()?.weapon.name(),
+        "Sword"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_when_parent_named() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Shuriken>()?
+        .in_transient_scope()
+        .when_parent_named("shadow")?;
+
+    di_container.bind::<dyn IWeapon>().to::<Sword>()?;
+
+    di_container
+        .bind::<Ninja>()
+        .to::<Ninja>()?
+        .in_transient_scope()
+        .when_named("shadow")?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    assert_eq!(
+        di_container
+            .get_named::<Ninja>("shadow")?
+            .transient()?
+            .weapon
+            .name(),
+        "Shuriken"
+    );
+    assert_eq!(
+        di_container.get::<Ninja>()?.transient()?.weapon.name(),
+        "Sword"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_when_predicate_passes() -> Result<(), Box<dyn std::error::Error>>
+{
+    static USE_KATANA: AtomicBool = AtomicBool::new(false);
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_transient_scope()
+        .when(|_context| USE_KATANA.load(Ordering::Relaxed))?;
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Shuriken>()?
+        .in_transient_scope()
+        .when(|context| {
+            context.type_chain() == [type_name::<Dojo>(), type_name::<Ninja>()]
+        })?;
+
+    di_container.bind::<dyn IWeapon>().to::<Sword>()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+    di_container.bind::<Dojo>().to::<Dojo>()?;
+
+    assert_eq!(
+        di_container.get::<Ninja>()?.transient()?.weapon.name(),
+        "Sword"
+    );
+    assert_eq!(
+        di_container.get::<Dojo>()?.transient()?.ninja.weapon.name(),
+        "Shuriken"
+    );
+
+    USE_KATANA.store(true, Ordering::Relaxed);
+
+    assert_eq!(
+        di_container.get::<Ninja>()?.transient()?.weapon.name(),
+        "Katana"
+    );
+    assert!(matches!(
+        di_container.get::<Dojo>(),
+        Err(DIContainerError::BindingResolveFailed { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_resolve_when_conditions_are_ambiguous()
+    -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Shuriken>()?
+        .in_transient_scope()
+        .when_injected_into::<Ninja>()?;
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_transient_scope()
+        .when_any_ancestor_is::<Ninja>()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    assert!(matches!(
+        di_container.get::<Ninja>(),
+        Err(DIContainerError::BindingResolveFailed {
+            reason: InjectableError::ResolveFailed { reason, .. },
+            ..
+        }) if matches!(*reason, DIContainerError::AmbiguousBinding { matching: 2, .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_resolve_without_satisfied_condition() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_transient_scope()
+        .when_injected_into::<Samurai>()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    assert!(matches!(
+        di_container.get::<Ninja>(),
+        Err(DIContainerError::BindingResolveFailed {
+            reason: InjectableError::ResolveFailed { reason, .. },
+            ..
+        }) if matches!(*reason, DIContainerError::BindingNotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IWeapon: Send + Sync
+    {
+        fn name(&self) -> &'static str;
+    }
+
+    struct Katana {}
+
+    #[injectable(IWeapon, async = true)]
+    impl Katana
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IWeapon for Katana
+    {
+        fn name(&self) -> &'static str
+        {
+            "Katana"
+        }
+    }
+
+    struct Sword {}
+
+    #[injectable(IWeapon, async = true)]
+    impl Sword
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IWeapon for Sword
+    {
+        fn name(&self) -> &'static str
+        {
+            "Sword"
+        }
+    }
+
+    struct Samurai
+    {
+        weapon: TransientPtr<dyn IWeapon>,
+    }
+
+    #[injectable(async = true)]
+    impl Samurai
+    {
+        fn new(weapon: TransientPtr<dyn IWeapon>) -> Self
+        {
+            Self { weapon }
+        }
+    }
+
+    #[tokio::test]
+    async fn can_bind_when_injected_into() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IWeapon>()
+            .to::<Katana>()?
+            .in_transient_scope()
+            .when_injected_into::<Samurai>()?;
+
+        di_container.bind::<dyn IWeapon>().to::<Sword>()?;
+
+        di_container.bind::<Samurai>().to::<Samurai>()?;
+
+        assert_eq!(
+            di_container
+                .get::<Samurai>()
+                .await?
+                .transient()?
+                .weapon
+                .name(),
+            "Katana"
+        );
+        assert_eq!(
+            di_container
+                .get::<dyn IWeapon>()
+                .await?
+                .transient()?
+                .name(),
+            "Sword"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use syrette::deferred::{Lazy, Provider};
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+static HEAVY_SERVICE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+trait IHeavyService
+{
+    fn id(&self) -> usize;
+}
+
+struct HeavyService
+{
+    id: usize,
+}
+
+#[injectable(IHeavyService)]
+impl HeavyService
+{
+    fn new() -> Self
+    {
+        Self {
+            id: HEAVY_SERVICE_COUNT.fetch_add(1, Ordering::SeqCst) + 1,
+        }
+    }
+}
+
+impl IHeavyService for HeavyService
+{
+    fn id(&self) -> usize
+    {
+        self.id
+    }
+}
+
+struct LazyConsumer
+{
+    heavy_service: Lazy<dyn IHeavyService>,
+}
+
+#[injectable]
+impl LazyConsumer
+{
+    fn new(heavy_service: Lazy<dyn IHeavyService>) -> Self
+    {
+        Self { heavy_service }
+    }
+}
+
+struct ProviderConsumer
+{
+    heavy_service_provider: Provider<dyn IHeavyService>,
+}
+
+#[injectable]
+impl ProviderConsumer
+{
+    fn new(heavy_service_provider: Provider<dyn IHeavyService>) -> Self
+    {
+        Self {
+            heavy_service_provider,
+        }
+    }
+}
+
+struct Parent
+{
+    child: Lazy<Child>,
+}
+
+#[injectable]
+impl Parent
+{
+    fn new(child: Lazy<Child>) -> Self
+    {
+        Self { child }
+    }
+}
+
+struct Child
+{
+    _parent: TransientPtr<Parent>,
+}
+
+#[injectable]
+impl Child
+{
+    fn new(parent: TransientPtr<Parent>) -> Self
+    {
+        Self { _parent: parent }
+    }
+}
+
+#[test]
+fn lazy_resolves_on_first_use() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHeavyService>()
+        .to::<HeavyService>()?;
+
+    di_container.bind::<LazyConsumer>().to::<LazyConsumer>()?;
+
+    let di_container = di_container.into_shared();
+
+    let count_before = HEAVY_SERVICE_COUNT.load(Ordering::SeqCst);
+
+    let consumer = di_container.get::<LazyConsumer>()?.transient()?;
+
+    assert_eq!(HEAVY_SERVICE_COUNT.load(Ordering::SeqCst), count_before);
+
+    let first_id = consumer.heavy_service.get()?.id();
+
+    assert_eq!(consumer.heavy_service.get()?.id(), first_id);
+
+    Ok(())
+}
+
+#[test]
+fn provider_resolves_fresh_instances() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHeavyService>()
+        .to::<HeavyService>()?;
+
+    di_container
+        .bind::<ProviderConsumer>()
+        .to::<ProviderConsumer>()?
+        .in_lazy_singleton_scope();
+
+    let di_container = di_container.into_shared();
+
+    let consumer = di_container.get::<ProviderConsumer>()?.singleton()?;
+
+    let first = consumer.heavy_service_provider.get()?.transient()?;
+    let second = consumer.heavy_service_provider.get()?.transient()?;
+
+    assert_ne!(first.id(), second.id());
+
+    let same_consumer = di_container.get::<ProviderConsumer>()?.singleton()?;
+
+    assert!(Rc::ptr_eq(&consumer, &same_consumer));
+
+    Ok(())
+}
+
+#[test]
+fn lazy_breaks_circular_dependencies() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<Parent>().to::<Parent>()?;
+    di_container.bind::<Child>().to::<Child>()?;
+
+    let di_container = di_container.into_shared();
+
+    let parent = di_container.get::<Parent>()?.transient()?;
+
+    assert!(parent.child.get().is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn deferred_resolve_sees_injection_target() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHeavyService>()
+        .to::<HeavyService>()?
+        .in_transient_scope()
+        .when_injected_into::<LazyConsumer>()?;
+
+    di_container.bind::<LazyConsumer>().to::<LazyConsumer>()?;
+
+    di_container
+        .bind::<ProviderConsumer>()
+        .to::<ProviderConsumer>()?;
+
+    let di_container = di_container.into_shared();
+
+    let lazy_consumer = di_container.get::<LazyConsumer>()?.transient()?;
+
+    assert!(lazy_consumer.heavy_service.get().is_ok());
+
+    let provider_consumer = di_container.get::<ProviderConsumer>()?.transient()?;
+
+    assert!(matches!(
+        provider_consumer.heavy_service_provider.get(),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_resolve_deferred_before_sharing() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHeavyService>()
+        .to::<HeavyService>()?;
+
+    di_container.bind::<LazyConsumer>().to::<LazyConsumer>()?;
+
+    let consumer = di_container.get::<LazyConsumer>()?.transient()?;
+
+    assert!(matches!(
+        consumer.heavy_service.get(),
+        Err(DIContainerError::NotShared { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn singleton_resolves_deferred_after_sharing() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHeavyService>()
+        .to::<HeavyService>()?;
+
+    di_container
+        .bind::<ProviderConsumer>()
+        .to::<ProviderConsumer>()?
+        .in_singleton_scope()?;
+
+    let di_container = di_container.into_shared();
+
+    let consumer = di_container.get::<ProviderConsumer>()?.singleton()?;
+
+    assert!(consumer.heavy_service_provider.get()?.transient().is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn child_resolves_deferred_once_shared() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHeavyService>()
+        .to::<HeavyService>()?;
+
+    di_container
+        .bind::<ProviderConsumer>()
+        .to::<ProviderConsumer>()?
+        .in_lazy_singleton_scope();
+
+    let di_container = Rc::new(di_container);
+
+    let mut child_di_container = di_container.create_child();
+
+    child_di_container
+        .bind::<LazyConsumer>()
+        .to::<LazyConsumer>()?
+        .in_singleton_scope()?;
+
+    let child_di_container = child_di_container.into_shared();
+
+    let consumer = child_di_container.get::<LazyConsumer>()?.singleton()?;
+
+    assert!(consumer.heavy_service.get().is_ok());
+
+    // Resolved by the parent, which is shared when the child is created
+    let provider_consumer = child_di_container.get::<ProviderConsumer>()?.singleton()?;
+
+    assert!(provider_consumer.heavy_service_provider.get().is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn cannot_resolve_after_container_dropped() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHeavyService>()
+        .to::<HeavyService>()?;
+
+    di_container
+        .bind::<ProviderConsumer>()
+        .to::<ProviderConsumer>()?;
+
+    let di_container = di_container.into_shared();
+
+    let consumer = di_container.get::<ProviderConsumer>()?.transient()?;
+
+    drop(di_container);
+
+    assert!(matches!(
+        consumer.heavy_service_provider.get(),
+        Err(DIContainerError::ContainerDropped { .. })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::type_name;
+
+use syrette::deferred::Lazy;
+use syrette::dependency::DependencyKind;
+use syrette::di_container::BindingScope;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IWeapon {}
+
+struct Katana {}
+
+#[injectable(IWeapon)]
+impl Katana
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IWeapon for Katana {}
+
+struct Shuriken {}
+
+#[injectable(IWeapon)]
+impl Shuriken
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IWeapon for Shuriken {}
+
+struct Ninja
+{
+    _weapon: TransientPtr<dyn IWeapon>,
+    _backup_weapon: Lazy<dyn IWeapon>,
+}
+
+#[injectable]
+impl Ninja
+{
+    fn new(
+        weapon: TransientPtr<dyn IWeapon>,
+        #[syrette::named("backup")] backup_weapon: Lazy<dyn IWeapon>,
+    ) -> Self
+    {
+        Self {
+            _weapon: weapon,
+            _backup_weapon: backup_weapon,
+        }
+    }
+}
+
+fn create_di_container() -> Result<DIContainer, Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Shuriken>()?
+        .in_transient_scope()
+        .when_named("backup")?;
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_singleton_scope()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    Ok(di_container)
+}
+
+#[test]
+fn can_get_dependency_graph() -> Result<(), Box<dyn std::error::Error>>
+{
+    let dependency_graph = create_di_container()?.dependency_graph();
+
+    let nodes = dependency_graph.nodes();
+
+    assert_eq!(nodes.len(), 3);
+
+    assert_eq!(nodes[0].interface(), type_name::<dyn IWeapon>());
+    assert_eq!(nodes[0].implementation(), Some(type_name::<Shuriken>()));
+    assert_eq!(nodes[0].name(), Some("backup"));
+    assert_eq!(nodes[0].scope(), BindingScope::Transient);
+
+    assert_eq!(nodes[1].implementation(), Some(type_name::<Katana>()));
+    assert_eq!(nodes[1].name(), None);
+    assert_eq!(nodes[1].scope(), BindingScope::Singleton);
+
+    assert_eq!(nodes[2].interface(), type_name::<Ninja>());
+
+    let edges = dependency_graph
+        .edges()
+        .iter()
+        .map(|edge| (edge.from(), edge.to(), edge.kind()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        edges,
+        vec![
+            (2, 1, DependencyKind::Single),
+            (2, 0, DependencyKind::Deferred)
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dependency_graph_output_is_stable() -> Result<(), Box<dyn std::error::Error>>
+{
+    let first_dependency_graph = create_di_container()?.dependency_graph();
+    let second_dependency_graph = create_di_container()?.dependency_graph();
+
+    assert_eq!(
+        first_dependency_graph.to_json(),
+        second_dependency_graph.to_json()
+    );
+
+    assert_eq!(
+        first_dependency_graph.to_dot(),
+        second_dependency_graph.to_dot()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use syrette::ptr::SingletonPtr;
+use syrette::{injectable, DIContainer};
+
+thread_local! {
+    static DROPPED: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn take_dropped() -> Vec<&'static str>
+{
+    DROPPED.with(RefCell::take)
+}
+
+trait IDatabase {}
+
+struct Database {}
+
+#[injectable]
+impl Database
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IDatabase for Database {}
+
+impl Drop for Database
+{
+    fn drop(&mut self)
+    {
+        DROPPED.with(|dropped| dropped.borrow_mut().push("Database"));
+    }
+}
+
+struct UserRepository
+{
+    _database: SingletonPtr<Database>,
+}
+
+#[injectable]
+impl UserRepository
+{
+    fn new(database: SingletonPtr<Database>) -> Self
+    {
+        Self {
+            _database: database,
+        }
+    }
+}
+
+impl Drop for UserRepository
+{
+    fn drop(&mut self)
+    {
+        DROPPED.with(|dropped| dropped.borrow_mut().push("UserRepository"));
+    }
+}
+
+fn create_di_container() -> Result<DIContainer, Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    // The repository is bound before the database it depends on
+    di_container
+        .bind::<UserRepository>()
+        .to::<UserRepository>()?
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<Database>()
+        .to::<Database>()?
+        .in_lazy_singleton_scope();
+
+    Ok(di_container)
+}
+
+#[test]
+fn disposes_in_reverse_construction_order() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = create_di_container()?;
+
+    drop(di_container.get::<UserRepository>()?.singleton()?);
+
+    assert!(take_dropped().is_empty());
+
+    assert!(di_container.dispose().is_empty());
+
+    assert_eq!(take_dropped(), vec!["UserRepository", "Database"]);
+
+    Ok(())
+}
+
+#[test]
+fn disposes_on_drop() -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?;
+
+    drop(di_container.get::<UserRepository>()?.singleton()?);
+
+    drop(di_container);
+
+    assert_eq!(take_dropped(), vec!["UserRepository", "Database"]);
+
+    Ok(())
+}
+
+#[test]
+fn reports_referenced_instances() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = create_di_container()?;
+
+    let database = di_container.get::<Database>()?.singleton()?;
+
+    drop(di_container.get::<UserRepository>()?.singleton()?);
+
+    let referenced_instances = di_container.dispose();
+
+    assert_eq!(referenced_instances.len(), 1);
+    assert!(referenced_instances[0].type_name().ends_with("Database"));
+    assert_eq!(referenced_instances[0].reference_count(), 1);
+
+    assert_eq!(take_dropped(), vec!["UserRepository"]);
+
+    drop(database);
+
+    assert_eq!(take_dropped(), vec!["Database"]);
+
+    Ok(())
+}
+
+#[test]
+fn reports_instances_bound_from_existing_once() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Database>()
+        .to::<Database>()?
+        .in_singleton_scope()?;
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<Database>()?
+        .in_singleton_scope_from_existing()?;
+
+    assert!(di_container.dispose().is_empty());
+
+    assert_eq!(take_dropped(), vec!["Database"]);
+
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Database>()
+        .to::<Database>()?
+        .in_singleton_scope()?;
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<Database>()?
+        .in_singleton_scope_from_existing()?;
+
+    let database = di_container.get::<Database>()?.singleton()?;
+
+    let referenced_instances = di_container.dispose();
+
+    assert_eq!(referenced_instances.len(), 1);
+    assert_eq!(referenced_instances[0].reference_count(), 1);
+
+    drop(database);
+
+    assert_eq!(take_dropped(), vec!["Database"]);
+
+    Ok(())
+}
+
+#[test]
+fn disposes_scoped_instances_with_scope() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<Database>()
+        .to::<Database>()?
+        .in_scoped_scope();
+
+    di_container
+        .bind::<UserRepository>()
+        .to::<UserRepository>()?
+        .in_scoped_scope();
+
+    let di_container = Rc::new(di_container);
+
+    let scope = di_container.begin_scope();
+
+    drop(scope.get::<UserRepository>()?.singleton()?);
+
+    assert!(take_dropped().is_empty());
+
+    drop(scope);
+
+    assert_eq!(take_dropped(), vec!["UserRepository", "Database"]);
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::type_name;
+
+use syrette::dependency::DependencyKind;
+use syrette::di_container::BindingScope;
+use syrette::explanation::ExplanationOutcome;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IWeapon {}
+
+struct Katana {}
+
+#[injectable(IWeapon)]
+impl Katana
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IWeapon for Katana {}
+
+trait IArmor {}
+
+struct Ninja
+{
+    _weapon: TransientPtr<dyn IWeapon>,
+    _armor: TransientPtr<dyn IArmor>,
+}
+
+#[injectable]
+impl Ninja
+{
+    fn new(weapon: TransientPtr<dyn IWeapon>, armor: TransientPtr<dyn IArmor>) -> Self
+    {
+        Self {
+            _weapon: weapon,
+            _armor: armor,
+        }
+    }
+}
+
+#[test]
+fn can_explain() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_singleton_scope()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    let explanation = di_container.explain::<Ninja>();
+
+    assert_eq!(explanation.interface(), type_name::<Ninja>());
+    assert_eq!(explanation.kind(), None);
+    assert_eq!(
+        explanation.outcome(),
+        &ExplanationOutcome::Binding {
+            implementation: Some(type_name::<Ninja>()),
+            scope: BindingScope::Transient
+        }
+    );
+
+    assert!(explanation.fails());
+    assert!(!explanation.is_failure());
+
+    assert_eq!(explanation.dependencies().len(), 2);
+
+    let weapon = &explanation.dependencies()[0];
+    let armor = &explanation.dependencies()[1];
+
+    assert_eq!(weapon.kind(), Some(DependencyKind::Single));
+    assert_eq!(
+        weapon.outcome(),
+        &ExplanationOutcome::Binding {
+            implementation: Some(type_name::<Katana>()),
+            scope: BindingScope::Singleton
+        }
+    );
+    assert!(!weapon.fails());
+
+    assert_eq!(armor.interface(), type_name::<dyn IArmor>());
+    assert_eq!(armor.outcome(), &ExplanationOutcome::MissingBinding);
+    assert!(armor.is_failure());
+
+    assert_eq!(
+        explanation.to_string(),
+        format!(
+            concat!(
+                "{} -> {} (transient)\n",
+                "├── {} -> {} (singleton)\n",
+                "└── {}: FAILED, no binding exists"
+            ),
+            type_name::<Ninja>(),
+            type_name::<Ninja>(),
+            type_name::<dyn IWeapon>(),
+            type_name::<Katana>(),
+            type_name::<dyn IArmor>()
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn explaining_missing_binding_fails()
+{
+    let di_container = DIContainer::new();
+
+    let explanation = di_container.explain_named::<dyn IWeapon>("sharp");
+
+    assert_eq!(explanation.name(), Some("sharp"));
+    assert_eq!(explanation.outcome(), &ExplanationOutcome::MissingBinding);
+    assert!(explanation.fails());
+}
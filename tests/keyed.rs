@@ -0,0 +1,253 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::di_container::BindingKey;
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer, ThreadsafeDIContainer};
+
+struct Primary;
+
+impl BindingKey for Primary {}
+
+struct Replica;
+
+impl BindingKey for Replica {}
+
+struct Archive;
+
+impl BindingKey for Archive {}
+
+trait IDatabase
+{
+    fn host(&self) -> &'static str;
+}
+
+struct PrimaryDatabase {}
+
+#[injectable(IDatabase)]
+impl PrimaryDatabase
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IDatabase for PrimaryDatabase
+{
+    fn host(&self) -> &'static str
+    {
+        "primary.db"
+    }
+}
+
+struct ReplicaDatabase {}
+
+#[injectable(IDatabase)]
+impl ReplicaDatabase
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IDatabase for ReplicaDatabase
+{
+    fn host(&self) -> &'static str
+    {
+        "replica.db"
+    }
+}
+
+struct ReportService
+{
+    primary: TransientPtr<dyn IDatabase>,
+    replica: TransientPtr<dyn IDatabase>,
+}
+
+#[injectable]
+impl ReportService
+{
+    fn new(
+        #[syrette::keyed(Primary)] primary: TransientPtr<dyn IDatabase>,
+        #[syrette::keyed(Replica)] replica: TransientPtr<dyn IDatabase>,
+    ) -> Self
+    {
+        Self { primary, replica }
+    }
+}
+
+fn bind_databases(
+    di_container: &mut DIContainer,
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<PrimaryDatabase>()?
+        .in_transient_scope()
+        .when_keyed::<Primary>()?;
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<ReplicaDatabase>()?
+        .in_transient_scope()
+        .when_keyed::<Replica>()?;
+
+    Ok(())
+}
+
+#[test]
+fn can_bind_and_get_keyed() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_databases(&mut di_container)?;
+
+    let replica = di_container
+        .get_keyed::<dyn IDatabase, Replica>()?
+        .transient()?;
+
+    assert_eq!(replica.host(), "replica.db");
+
+    assert!(matches!(
+        di_container.get_keyed::<dyn IDatabase, Archive>(),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn keys_do_not_collide_with_names() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<PrimaryDatabase>()?
+        .in_transient_scope()
+        .when_keyed::<Primary>()?;
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<ReplicaDatabase>()?
+        .in_transient_scope()
+        .when_named(std::any::type_name::<Replica>())?;
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<ReplicaDatabase>()?
+        .in_transient_scope()
+        .when_named("Primary")?;
+
+    let primary = di_container
+        .get_keyed::<dyn IDatabase, Primary>()?
+        .transient()?;
+
+    assert_eq!(primary.host(), "primary.db");
+
+    assert!(matches!(
+        di_container.get_keyed::<dyn IDatabase, Replica>(),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_keyed() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_databases(&mut di_container)?;
+
+    di_container.bind::<ReportService>().to::<ReportService>()?;
+
+    let report_service = di_container.get::<ReportService>()?.transient()?;
+
+    assert_eq!(report_service.primary.host(), "primary.db");
+    assert_eq!(report_service.replica.host(), "replica.db");
+
+    Ok(())
+}
+
+struct Config {}
+
+#[injectable(threadsafe = true)]
+impl Config
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+#[test]
+fn threadsafe_can_bind_and_get_keyed() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<Config>()
+        .to::<Config>()?
+        .in_transient_scope()
+        .when_keyed::<Archive>()?;
+
+    di_container.get_keyed::<Config, Archive>()?.transient()?;
+
+    assert!(di_container.get::<Config>().is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::di_container::BindingKey;
+    use syrette::errors::async_di_container::AsyncDIContainerError;
+    use syrette::{injectable, AsyncDIContainer};
+
+    struct Emails;
+
+    impl BindingKey for Emails {}
+
+    struct Sms;
+
+    impl BindingKey for Sms {}
+
+    struct Queue {}
+
+    #[injectable(async = true)]
+    impl Queue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    #[tokio::test]
+    async fn can_bind_and_get_keyed() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<Queue>()
+            .to::<Queue>()?
+            .in_transient_scope()
+            .when_keyed::<Emails>()?;
+
+        di_container
+            .get_keyed::<Queue, Emails>()
+            .await?
+            .transient()?;
+
+        assert!(matches!(
+            di_container.get_keyed::<Queue, Sms>().await,
+            Err(AsyncDIContainerError::BindingNotFound { .. })
+        ));
+
+        Ok(())
+    }
+}
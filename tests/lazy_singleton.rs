@@ -0,0 +1,307 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use syrette::ptr::{SingletonPtr, TransientPtr};
+use syrette::{injectable, DIContainer};
+
+static CONFIG_BUILD_CNT: AtomicUsize = AtomicUsize::new(0);
+
+struct Config {}
+
+#[injectable]
+impl Config
+{
+    fn new() -> Self
+    {
+        CONFIG_BUILD_CNT.fetch_add(1, Ordering::SeqCst);
+
+        Self {}
+    }
+}
+
+struct Server
+{
+    config: SingletonPtr<Config>,
+}
+
+#[injectable]
+impl Server
+{
+    fn new(config: SingletonPtr<Config>) -> Self
+    {
+        Self { config }
+    }
+}
+
+#[test]
+fn builds_on_first_get() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    // The dependency of the server is bound after the server itself
+    di_container
+        .bind::<Server>()
+        .to::<Server>()?
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<Config>()
+        .to::<Config>()?
+        .in_lazy_singleton_scope();
+
+    assert_eq!(CONFIG_BUILD_CNT.load(Ordering::SeqCst), 0);
+
+    let server = di_container.get::<Server>()?.singleton()?;
+    let config = di_container.get::<Config>()?.singleton()?;
+
+    assert!(Rc::ptr_eq(&server.config, &config));
+    assert_eq!(CONFIG_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+trait IStorage
+{
+    fn kind(&self) -> &'static str;
+}
+
+struct DiskStorage {}
+
+#[injectable(IStorage)]
+impl DiskStorage
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IStorage for DiskStorage
+{
+    fn kind(&self) -> &'static str
+    {
+        "disk"
+    }
+}
+
+struct MemoryStorage {}
+
+#[injectable(IStorage)]
+impl MemoryStorage
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IStorage for MemoryStorage
+{
+    fn kind(&self) -> &'static str
+    {
+        "memory"
+    }
+}
+
+struct Cache
+{
+    storage: TransientPtr<dyn IStorage>,
+}
+
+#[injectable]
+impl Cache
+{
+    fn new(storage: TransientPtr<dyn IStorage>) -> Self
+    {
+        Self { storage }
+    }
+}
+
+#[test]
+fn resolves_dependencies_of_parent_binding_in_parent(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut parent = DIContainer::new();
+
+    parent
+        .bind::<Cache>()
+        .to::<Cache>()?
+        .in_lazy_singleton_scope();
+
+    parent.bind::<dyn IStorage>().to::<DiskStorage>()?;
+
+    let parent = Rc::new(parent);
+
+    let mut child = parent.create_child();
+
+    child.bind::<dyn IStorage>().to::<MemoryStorage>()?;
+
+    let cache = child.get::<Cache>()?.singleton()?;
+
+    assert_eq!(cache.storage.kind(), "disk");
+
+    assert!(Rc::ptr_eq(&parent.get::<Cache>()?.singleton()?, &cache));
+
+    let scope = parent.begin_scope();
+
+    assert!(Rc::ptr_eq(&scope.get::<Cache>()?.singleton()?, &cache));
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    static DATABASE_BUILD_CNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Database {}
+
+    #[injectable(async = true)]
+    impl Database
+    {
+        fn new() -> Self
+        {
+            DATABASE_BUILD_CNT.fetch_add(1, Ordering::SeqCst);
+
+            // Give the other resolutions a chance to run at the same time
+            std::thread::sleep(Duration::from_millis(50));
+
+            Self {}
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn builds_once_when_resolved_concurrently(
+    ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<Database>()
+            .to::<Database>()?
+            .in_lazy_singleton_scope();
+
+        let di_container = Arc::new(di_container);
+
+        let handles = (0..8)
+            .map(|_| {
+                let di_container = di_container.clone();
+
+                tokio::spawn(async move {
+                    di_container
+                        .get::<Database>()
+                        .await
+                        .unwrap()
+                        .threadsafe_singleton()
+                        .unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut databases = Vec::new();
+
+        for handle in handles {
+            databases.push(handle.await?);
+        }
+
+        assert_eq!(DATABASE_BUILD_CNT.load(Ordering::SeqCst), 1);
+
+        assert!(databases
+            .iter()
+            .all(|database| Arc::ptr_eq(database, &databases[0])));
+
+        Ok(())
+    }
+
+    trait IQueue: Send + Sync
+    {
+        fn kind(&self) -> &'static str;
+    }
+
+    struct RedisQueue {}
+
+    #[injectable(IQueue, async = true)]
+    impl RedisQueue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IQueue for RedisQueue
+    {
+        fn kind(&self) -> &'static str
+        {
+            "redis"
+        }
+    }
+
+    struct MemoryQueue {}
+
+    #[injectable(IQueue, async = true)]
+    impl MemoryQueue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IQueue for MemoryQueue
+    {
+        fn kind(&self) -> &'static str
+        {
+            "memory"
+        }
+    }
+
+    struct Mailer
+    {
+        queue: TransientPtr<dyn IQueue>,
+    }
+
+    #[injectable(async = true)]
+    impl Mailer
+    {
+        fn new(queue: TransientPtr<dyn IQueue>) -> Self
+        {
+            Self { queue }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_dependencies_of_parent_binding_in_parent(
+    ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut parent = AsyncDIContainer::new();
+
+        parent
+            .bind::<Mailer>()
+            .to::<Mailer>()?
+            .in_lazy_singleton_scope();
+
+        parent.bind::<dyn IQueue>().to::<RedisQueue>()?;
+
+        let parent = Arc::new(parent);
+
+        let mut child = parent.create_child();
+
+        child.bind::<dyn IQueue>().to::<MemoryQueue>()?;
+
+        let mailer = child.get::<Mailer>().await?.threadsafe_singleton()?;
+
+        assert_eq!(mailer.queue.kind(), "redis");
+
+        Ok(())
+    }
+}
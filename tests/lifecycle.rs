@@ -0,0 +1,342 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::cell::RefCell;
+use std::error::Error;
+
+use syrette::errors::lifecycle::ServiceFailure;
+use syrette::interfaces::lifecycle::{Startable, Stoppable};
+use syrette::ptr::SingletonPtr;
+use syrette::{injectable, DIContainer};
+
+thread_local! {
+    static EVENTS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record_event(event: &'static str)
+{
+    EVENTS.with(|events| events.borrow_mut().push(event));
+}
+
+fn take_events() -> Vec<&'static str>
+{
+    EVENTS.with(RefCell::take)
+}
+
+struct Database {}
+
+#[injectable]
+impl Database
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl Startable for Database
+{
+    fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    {
+        record_event("start database");
+
+        Ok(())
+    }
+}
+
+impl Stoppable for Database
+{
+    fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    {
+        record_event("stop database");
+
+        Err("Connection was already closed".into())
+    }
+}
+
+struct WebServer
+{
+    _database: SingletonPtr<Database>,
+}
+
+#[injectable]
+impl WebServer
+{
+    fn new(database: SingletonPtr<Database>) -> Self
+    {
+        Self {
+            _database: database,
+        }
+    }
+}
+
+impl Startable for WebServer
+{
+    fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    {
+        record_event("start web server");
+
+        Ok(())
+    }
+}
+
+impl Stoppable for WebServer
+{
+    fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    {
+        record_event("stop web server");
+
+        Err("Requests are still being handled".into())
+    }
+}
+
+struct Mailer {}
+
+#[injectable]
+impl Mailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl Startable for Mailer
+{
+    fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+    {
+        Err("Mail server is unreachable".into())
+    }
+}
+
+fn create_di_container() -> Result<DIContainer, Box<dyn Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    // The web server is bound before the database it depends on
+    di_container
+        .bind::<WebServer>()
+        .to::<WebServer>()?
+        .startable()
+        .stoppable()
+        .in_lazy_singleton_scope();
+
+    di_container
+        .bind::<Database>()
+        .to::<Database>()?
+        .startable()
+        .stoppable()
+        .in_lazy_singleton_scope();
+
+    Ok(di_container)
+}
+
+#[test]
+fn starts_in_dependency_order() -> Result<(), Box<dyn Error>>
+{
+    let di_container = create_di_container()?;
+
+    di_container.start_all()?;
+
+    assert_eq!(take_events(), vec!["start database", "start web server"]);
+
+    Ok(())
+}
+
+#[test]
+fn stops_in_reverse_dependency_order() -> Result<(), Box<dyn Error>>
+{
+    let di_container = create_di_container()?;
+
+    di_container.start_all()?;
+
+    take_events();
+
+    let err = di_container.stop_all().unwrap_err();
+
+    assert_eq!(take_events(), vec!["stop web server", "stop database"]);
+
+    // Stopping continued past the failure of the web server
+    assert_eq!(
+        err.failures()
+            .iter()
+            .map(ServiceFailure::service)
+            .collect::<Vec<_>>(),
+        vec![
+            std::any::type_name::<WebServer>(),
+            std::any::type_name::<Database>()
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn does_not_stop_unbuilt_lazy_singletons() -> Result<(), Box<dyn Error>>
+{
+    let di_container = create_di_container()?;
+
+    di_container.stop_all()?;
+
+    assert!(take_events().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn reports_service_that_failed_to_start() -> Result<(), Box<dyn Error>>
+{
+    let mut di_container = create_di_container()?;
+
+    di_container
+        .bind::<Mailer>()
+        .to::<Mailer>()?
+        .startable()
+        .in_singleton_scope()?;
+
+    let err = di_container.start_all().unwrap_err();
+
+    assert_eq!(err.failures().len(), 1);
+
+    assert!(matches!(
+        &err.failures()[0],
+        ServiceFailure::StartFailed { service, .. }
+            if *service == std::any::type_name::<Mailer>()
+    ));
+
+    // The mailer was constructed first, so nothing was started after it failed
+    assert!(take_events().is_empty());
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::cell::RefCell;
+    use std::error::Error;
+
+    use async_trait::async_trait;
+    use syrette::interfaces::async_lifecycle::{AsyncStartable, AsyncStoppable};
+    use syrette::ptr::ThreadsafeSingletonPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    thread_local! {
+        static EVENTS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record_event(event: &'static str)
+    {
+        EVENTS.with(|events| events.borrow_mut().push(event));
+    }
+
+    fn take_events() -> Vec<&'static str>
+    {
+        EVENTS.with(RefCell::take)
+    }
+
+    struct MessageQueue {}
+
+    #[injectable(async = true)]
+    impl MessageQueue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    #[async_trait]
+    impl AsyncStartable for MessageQueue
+    {
+        async fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+        {
+            record_event("start message queue");
+
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl AsyncStoppable for MessageQueue
+    {
+        async fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+        {
+            record_event("stop message queue");
+
+            Ok(())
+        }
+    }
+
+    struct Worker
+    {
+        _message_queue: ThreadsafeSingletonPtr<MessageQueue>,
+    }
+
+    #[injectable(async = true)]
+    impl Worker
+    {
+        fn new(message_queue: ThreadsafeSingletonPtr<MessageQueue>) -> Self
+        {
+            Self {
+                _message_queue: message_queue,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl AsyncStartable for Worker
+    {
+        async fn start(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+        {
+            record_event("start worker");
+
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl AsyncStoppable for Worker
+    {
+        async fn stop(&self) -> Result<(), Box<dyn Error + Send + Sync>>
+        {
+            record_event("stop worker");
+
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn starts_and_stops_in_dependency_order() -> Result<(), Box<dyn Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<Worker>()
+            .to::<Worker>()?
+            .startable()
+            .stoppable()
+            .in_lazy_singleton_scope();
+
+        di_container
+            .bind::<MessageQueue>()
+            .to::<MessageQueue>()?
+            .startable()
+            .stoppable()
+            .in_lazy_singleton_scope();
+
+        di_container.start_all().await?;
+        di_container.stop_all().await?;
+
+        assert_eq!(
+            take_events(),
+            vec![
+                "start message queue",
+                "start worker",
+                "stop worker",
+                "stop message queue"
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,446 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use syrette::ptr::{SingletonPtr, TransientPtr};
+use syrette::{injectable, DIContainer};
+
+trait IPlugin
+{
+    fn name(&self) -> &'static str;
+}
+
+struct AudioPlugin {}
+
+#[injectable(IPlugin)]
+impl AudioPlugin
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IPlugin for AudioPlugin
+{
+    fn name(&self) -> &'static str
+    {
+        "audio"
+    }
+}
+
+struct VideoPlugin {}
+
+#[injectable(IPlugin)]
+impl VideoPlugin
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IPlugin for VideoPlugin
+{
+    fn name(&self) -> &'static str
+    {
+        "video"
+    }
+}
+
+struct PluginManager
+{
+    plugins: Vec<TransientPtr<dyn IPlugin>>,
+}
+
+#[injectable]
+impl PluginManager
+{
+    fn new(plugins: Vec<TransientPtr<dyn IPlugin>>) -> Self
+    {
+        Self { plugins }
+    }
+}
+
+struct SharedPluginManager
+{
+    plugins: Vec<SingletonPtr<dyn IPlugin>>,
+}
+
+#[injectable]
+impl SharedPluginManager
+{
+    fn new(plugins: Vec<SingletonPtr<dyn IPlugin>>) -> Self
+    {
+        Self { plugins }
+    }
+}
+
+struct PluginRegistry
+{
+    plugins: HashMap<String, TransientPtr<dyn IPlugin>>,
+}
+
+#[injectable]
+impl PluginRegistry
+{
+    fn new(plugins: HashMap<String, TransientPtr<dyn IPlugin>>) -> Self
+    {
+        Self { plugins }
+    }
+}
+
+#[test]
+fn can_get_all() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<AudioPlugin>()
+        .in_singleton_scope()?;
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<VideoPlugin>()
+        .in_transient_scope();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<VideoPlugin>()
+        .in_transient_scope()
+        .when_named("hidden")?;
+
+    let mut plugins = di_container.get_all::<dyn IPlugin>()?.into_iter();
+
+    let audio_plugin = plugins.next().ok_or("Missing audio plugin")?.singleton()?;
+    let video_plugin = plugins.next().ok_or("Missing video plugin")?.transient()?;
+
+    assert!(plugins.next().is_none());
+
+    assert_eq!(audio_plugin.name(), "audio");
+    assert_eq!(video_plugin.name(), "video");
+
+    let same_audio_plugin = di_container
+        .get_all::<dyn IPlugin>()?
+        .remove(0)
+        .singleton()?;
+
+    assert!(Rc::ptr_eq(&audio_plugin, &same_audio_plugin));
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_all() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<AudioPlugin>();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<VideoPlugin>();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<AudioPlugin>()
+        .in_transient_scope()
+        .when_named("hidden")?;
+
+    di_container.bind::<PluginManager>().to::<PluginManager>()?;
+
+    let plugin_manager = di_container.get::<PluginManager>()?.transient()?;
+
+    assert_eq!(
+        plugin_manager
+            .plugins
+            .iter()
+            .map(|plugin| plugin.name())
+            .collect::<Vec<_>>(),
+        vec!["audio", "video"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_all_singletons() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<AudioPlugin>()
+        .in_singleton_scope()?;
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<VideoPlugin>()
+        .in_singleton_scope()?;
+
+    di_container
+        .bind::<SharedPluginManager>()
+        .to::<SharedPluginManager>()?;
+
+    let first_manager = di_container.get::<SharedPluginManager>()?.transient()?;
+    let second_manager = di_container.get::<SharedPluginManager>()?.transient()?;
+
+    assert_eq!(first_manager.plugins.len(), 2);
+
+    assert!(first_manager
+        .plugins
+        .iter()
+        .zip(second_manager.plugins.iter())
+        .all(|(first, second)| Rc::ptr_eq(first, second)));
+
+    Ok(())
+}
+
+#[test]
+fn can_get_all_named() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to::<AudioPlugin>()?
+        .in_transient_scope()
+        .when_named("sound")?;
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to::<VideoPlugin>()?
+        .in_singleton_scope()?
+        .when_named("movie")?;
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to_additional::<VideoPlugin>();
+
+    let mut plugins = di_container.get_all_named::<dyn IPlugin>()?;
+
+    assert_eq!(plugins.len(), 2);
+
+    let sound_plugin = plugins
+        .remove("sound")
+        .ok_or("Missing sound plugin")?
+        .transient()?;
+
+    let movie_plugin = plugins
+        .remove("movie")
+        .ok_or("Missing movie plugin")?
+        .singleton()?;
+
+    assert_eq!(sound_plugin.name(), "audio");
+    assert_eq!(movie_plugin.name(), "video");
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_all_named() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to::<AudioPlugin>()?
+        .in_transient_scope()
+        .when_named("sound")?;
+
+    di_container
+        .bind::<dyn IPlugin>()
+        .to::<VideoPlugin>()?
+        .in_transient_scope()
+        .when_named("movie")?;
+
+    di_container.bind::<PluginRegistry>().to::<PluginRegistry>()?;
+
+    let plugin_registry = di_container.get::<PluginRegistry>()?.transient()?;
+
+    assert_eq!(plugin_registry.plugins.len(), 2);
+    assert_eq!(plugin_registry.plugins["sound"].name(), "audio");
+    assert_eq!(plugin_registry.plugins["movie"].name(), "video");
+
+    Ok(())
+}
+
+#[test]
+fn child_shadows_bindings_of_parent() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut parent = DIContainer::new();
+
+    parent
+        .bind::<dyn IPlugin>()
+        .to::<AudioPlugin>()?
+        .in_transient_scope()
+        .when_named("sound")?;
+
+    parent.bind::<dyn IPlugin>().to::<AudioPlugin>()?;
+
+    parent.bind::<dyn IPlugin>().to_additional::<AudioPlugin>();
+
+    let parent = Rc::new(parent);
+
+    let mut child = parent.create_child();
+
+    child
+        .bind::<dyn IPlugin>()
+        .to::<VideoPlugin>()?
+        .in_transient_scope()
+        .when_named("sound")?;
+
+    child.bind::<dyn IPlugin>().to::<VideoPlugin>()?;
+
+    let plugin_names = child
+        .get_all::<dyn IPlugin>()?
+        .into_iter()
+        .map(|plugin| Ok(plugin.transient()?.name()))
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+    assert_eq!(plugin_names, ["audio", "video"]);
+
+    let mut named_plugins = child.get_all_named::<dyn IPlugin>()?;
+
+    assert_eq!(named_plugins.len(), 1);
+
+    assert_eq!(
+        named_plugins
+            .remove("sound")
+            .ok_or("Missing sound plugin")?
+            .transient()?
+            .name(),
+        "video"
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IHandler: Send + Sync {}
+
+    struct LogHandler {}
+
+    #[injectable(IHandler, async = true)]
+    impl LogHandler
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IHandler for LogHandler {}
+
+    struct Dispatcher
+    {
+        handlers: Vec<TransientPtr<dyn IHandler>>,
+    }
+
+    #[injectable(async = true)]
+    impl Dispatcher
+    {
+        fn new(handlers: Vec<TransientPtr<dyn IHandler>>) -> Self
+        {
+            Self { handlers }
+        }
+    }
+
+    struct NamedDispatcher
+    {
+        handlers: HashMap<String, TransientPtr<dyn IHandler>>,
+    }
+
+    #[injectable(async = true)]
+    impl NamedDispatcher
+    {
+        fn new(handlers: HashMap<String, TransientPtr<dyn IHandler>>) -> Self
+        {
+            Self { handlers }
+        }
+    }
+
+    #[tokio::test]
+    async fn can_inject_all() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IHandler>()
+            .to_additional::<LogHandler>();
+
+        di_container
+            .bind::<dyn IHandler>()
+            .to_additional::<LogHandler>();
+
+        di_container.bind::<Dispatcher>().to::<Dispatcher>()?;
+
+        let dispatcher = di_container.get::<Dispatcher>().await?.transient()?;
+
+        assert_eq!(dispatcher.handlers.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn can_inject_all_named() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IHandler>()
+            .to::<LogHandler>()?
+            .in_transient_scope()
+            .when_named("log")?;
+
+        di_container
+            .bind::<dyn IHandler>()
+            .to::<LogHandler>()?
+            .in_transient_scope()
+            .when_named("audit")?;
+
+        di_container
+            .bind::<NamedDispatcher>()
+            .to::<NamedDispatcher>()?;
+
+        let dispatcher = di_container.get::<NamedDispatcher>().await?.transient()?;
+
+        assert_eq!(dispatcher.handlers.len(), 2);
+        assert!(dispatcher.handlers.contains_key("log"));
+        assert!(dispatcher.handlers.contains_key("audit"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn child_shadows_bindings_of_parent() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut parent = AsyncDIContainer::new();
+
+        parent.bind::<dyn IHandler>().to::<LogHandler>()?;
+
+        parent.bind::<dyn IHandler>().to_additional::<LogHandler>();
+
+        let parent = Arc::new(parent);
+
+        let mut child = parent.create_child();
+
+        child.bind::<dyn IHandler>().to::<LogHandler>()?;
+
+        assert_eq!(child.get_all::<dyn IHandler>().await?.len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer, ThreadsafeDIContainer};
+
+trait IPaymentGateway
+{
+    fn region(&self) -> &'static str;
+}
+
+struct EuPaymentGateway {}
+
+#[injectable(IPaymentGateway)]
+impl EuPaymentGateway
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IPaymentGateway for EuPaymentGateway
+{
+    fn region(&self) -> &'static str
+    {
+        "eu"
+    }
+}
+
+struct GenericPaymentGateway {}
+
+#[injectable(IPaymentGateway)]
+impl GenericPaymentGateway
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IPaymentGateway for GenericPaymentGateway
+{
+    fn region(&self) -> &'static str
+    {
+        "generic"
+    }
+}
+
+struct Checkout
+{
+    _payment_gateway: TransientPtr<dyn IPaymentGateway>,
+}
+
+#[injectable]
+impl Checkout
+{
+    fn new(#[named("us")] payment_gateway: TransientPtr<dyn IPaymentGateway>) -> Self
+    {
+        Self {
+            _payment_gateway: payment_gateway,
+        }
+    }
+}
+
+fn bind_payment_gateways(
+    di_container: &mut DIContainer,
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    di_container
+        .bind::<dyn IPaymentGateway>()
+        .to::<EuPaymentGateway>()?
+        .in_transient_scope()
+        .when_named("eu")?;
+
+    di_container
+        .bind::<dyn IPaymentGateway>()
+        .to::<GenericPaymentGateway>()?;
+
+    Ok(())
+}
+
+#[test]
+fn can_get_named_or_default() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_payment_gateways(&mut di_container)?;
+
+    let eu_payment_gateway = di_container
+        .get_named_or_default::<dyn IPaymentGateway>("eu")?
+        .transient()?;
+
+    let us_payment_gateway = di_container
+        .get_named_or_default::<dyn IPaymentGateway>("us")?
+        .transient()?;
+
+    assert_eq!(eu_payment_gateway.region(), "eu");
+    assert_eq!(us_payment_gateway.region(), "generic");
+
+    // Only get_named_or_default falls back to the binding without a name
+    assert!(matches!(
+        di_container.get_named::<dyn IPaymentGateway>("us"),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn named_dependencies_dont_fall_back() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_payment_gateways(&mut di_container)?;
+
+    di_container.bind::<Checkout>().to::<Checkout>()?;
+
+    assert!(di_container.validate().is_err());
+
+    assert!(matches!(
+        di_container.get::<Checkout>(),
+        Err(DIContainerError::BindingResolveFailed { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn child_falls_back_to_binding_of_parent() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut parent = DIContainer::new();
+
+    bind_payment_gateways(&mut parent)?;
+
+    let parent = Rc::new(parent);
+
+    let mut child = parent.create_child();
+
+    child
+        .bind::<dyn IPaymentGateway>()
+        .to::<EuPaymentGateway>()?
+        .in_transient_scope()
+        .when_named("se")?;
+
+    let se_payment_gateway = child
+        .get_named_or_default::<dyn IPaymentGateway>("se")?
+        .transient()?;
+
+    let us_payment_gateway = child
+        .get_named_or_default::<dyn IPaymentGateway>("us")?
+        .transient()?;
+
+    assert_eq!(se_payment_gateway.region(), "eu");
+    assert_eq!(us_payment_gateway.region(), "generic");
+
+    Ok(())
+}
+
+#[test]
+fn error_has_requested_name() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IPaymentGateway>()
+        .to::<EuPaymentGateway>()?
+        .in_transient_scope()
+        .when_named("eu")?;
+
+    assert!(matches!(
+        di_container.get_named_or_default::<dyn IPaymentGateway>("us"),
+        Err(DIContainerError::BindingNotFound { name: Some(name), .. }) if name == "us"
+    ));
+
+    Ok(())
+}
+
+struct Config {}
+
+#[injectable(threadsafe = true)]
+impl Config
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+#[test]
+fn threadsafe_can_get_named_or_default() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container.bind::<Config>().to::<Config>()?;
+
+    assert!(matches!(
+        di_container.get_named::<Config>("staging"),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    di_container
+        .get_named_or_default::<Config>("production")?
+        .transient()?;
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::errors::async_di_container::AsyncDIContainerError;
+    use syrette::{injectable, AsyncDIContainer};
+
+    struct Queue {}
+
+    #[injectable(async = true)]
+    impl Queue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    #[tokio::test]
+    async fn can_get_named_or_default() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<Queue>()
+            .to::<Queue>()?
+            .in_transient_scope();
+
+        assert!(matches!(
+            di_container.get_named::<Queue>("emails").await,
+            Err(AsyncDIContainerError::BindingNotFound { .. })
+        ));
+
+        di_container
+            .get_named_or_default::<Queue>("emails")
+            .await?
+            .transient()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::{SingletonPtr, TransientPtr};
+use syrette::{injectable, DIContainer};
+
+trait IMetricsSink
+{
+    fn name(&self) -> &'static str;
+}
+
+struct StatsdSink {}
+
+#[injectable(IMetricsSink)]
+impl StatsdSink
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IMetricsSink for StatsdSink
+{
+    fn name(&self) -> &'static str
+    {
+        "statsd"
+    }
+}
+
+trait ICache {}
+
+struct RedisCache
+{
+    _connection: TransientPtr<dyn IConnection>,
+}
+
+#[injectable(ICache)]
+impl RedisCache
+{
+    fn new(connection: TransientPtr<dyn IConnection>) -> Self
+    {
+        Self {
+            _connection: connection,
+        }
+    }
+}
+
+impl ICache for RedisCache {}
+
+trait IConnection {}
+
+struct Service
+{
+    metrics_sink: Option<TransientPtr<dyn IMetricsSink>>,
+    cache: Option<SingletonPtr<dyn ICache>>,
+}
+
+#[injectable]
+impl Service
+{
+    fn new(
+        metrics_sink: Option<TransientPtr<dyn IMetricsSink>>,
+        cache: Option<SingletonPtr<dyn ICache>>,
+    ) -> Self
+    {
+        Self {
+            metrics_sink,
+            cache,
+        }
+    }
+}
+
+struct NamedService
+{
+    metrics_sink: Option<TransientPtr<dyn IMetricsSink>>,
+}
+
+#[injectable]
+impl NamedService
+{
+    fn new(#[named("remote")] metrics_sink: Option<TransientPtr<dyn IMetricsSink>>)
+        -> Self
+    {
+        Self { metrics_sink }
+    }
+}
+
+#[test]
+fn resolves_missing_optional_dependencies_to_none()
+    -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<Service>().to::<Service>()?;
+
+    let service = di_container.get::<Service>()?.transient()?;
+
+    assert!(service.metrics_sink.is_none());
+    assert!(service.cache.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn resolves_bound_optional_dependencies() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IMetricsSink>()
+        .to::<StatsdSink>()?;
+
+    di_container.bind::<Service>().to::<Service>()?;
+
+    let service = di_container.get::<Service>()?.transient()?;
+
+    assert_eq!(
+        service
+            .metrics_sink
+            .as_ref()
+            .map(|metrics_sink| metrics_sink.name()),
+        Some("statsd")
+    );
+    assert!(service.cache.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn resolves_named_optional_dependencies() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<NamedService>().to::<NamedService>()?;
+
+    assert!(di_container
+        .get::<NamedService>()?
+        .transient()?
+        .metrics_sink
+        .is_none());
+
+    di_container
+        .bind::<dyn IMetricsSink>()
+        .to::<StatsdSink>()?
+        .in_transient_scope()
+        .when_named("remote")?;
+
+    assert!(di_container
+        .get::<NamedService>()?
+        .transient()?
+        .metrics_sink
+        .is_some());
+
+    Ok(())
+}
+
+#[test]
+fn propagates_other_errors_of_optional_dependencies()
+    -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    // The connection of the cache is not bound
+    di_container.bind::<dyn ICache>().to::<RedisCache>()?;
+
+    di_container.bind::<Service>().to::<Service>()?;
+
+    assert!(matches!(
+        di_container.get::<Service>(),
+        Err(DIContainerError::BindingResolveFailed { .. })
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IMetricsSink: Send + Sync {}
+
+    struct StatsdSink {}
+
+    #[injectable(IMetricsSink, async = true)]
+    impl StatsdSink
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IMetricsSink for StatsdSink {}
+
+    struct Service
+    {
+        metrics_sink: Option<TransientPtr<dyn IMetricsSink>>,
+    }
+
+    #[injectable(async = true)]
+    impl Service
+    {
+        fn new(metrics_sink: Option<TransientPtr<dyn IMetricsSink>>) -> Self
+        {
+            Self { metrics_sink }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_optional_dependencies() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container.bind::<Service>().to::<Service>()?;
+
+        assert!(di_container
+            .get::<Service>()
+            .await?
+            .transient()?
+            .metrics_sink
+            .is_none());
+
+        di_container
+            .bind::<dyn IMetricsSink>()
+            .to::<StatsdSink>()?;
+
+        assert!(di_container
+            .get::<Service>()
+            .await?
+            .transient()?
+            .metrics_sink
+            .is_some());
+
+        Ok(())
+    }
+}
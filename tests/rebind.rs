@@ -0,0 +1,304 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IMailer
+{
+    fn is_fake(&self) -> bool;
+}
+
+struct SmtpMailer {}
+
+#[injectable(IMailer)]
+impl SmtpMailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IMailer for SmtpMailer
+{
+    fn is_fake(&self) -> bool
+    {
+        false
+    }
+}
+
+struct FakeMailer {}
+
+#[injectable(IMailer)]
+impl FakeMailer
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IMailer for FakeMailer
+{
+    fn is_fake(&self) -> bool
+    {
+        true
+    }
+}
+
+trait IMailQueue {}
+
+struct QueuedMailer
+{
+    _queue: TransientPtr<dyn IMailQueue>,
+}
+
+#[injectable(IMailer)]
+impl QueuedMailer
+{
+    fn new(queue: TransientPtr<dyn IMailQueue>) -> Self
+    {
+        Self { _queue: queue }
+    }
+}
+
+impl IMailer for QueuedMailer
+{
+    fn is_fake(&self) -> bool
+    {
+        false
+    }
+}
+
+#[test]
+fn can_rebind() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IMailer>().to::<SmtpMailer>()?;
+
+    assert!(di_container.bind::<dyn IMailer>().to::<FakeMailer>().is_err());
+
+    di_container.rebind::<dyn IMailer>().to::<FakeMailer>()?;
+
+    assert!(di_container.get::<dyn IMailer>()?.transient()?.is_fake());
+
+    Ok(())
+}
+
+#[test]
+fn failed_rebind_keeps_replaced_binding() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IMailer>()
+        .to::<FakeMailer>()?
+        .in_singleton_scope()?;
+
+    // No binding for the mail queue exists, so the mailer can't be constructed
+    assert!(di_container
+        .rebind::<dyn IMailer>()
+        .to::<QueuedMailer>()?
+        .in_singleton_scope()
+        .is_err());
+
+    assert!(di_container.get::<dyn IMailer>()?.singleton()?.is_fake());
+
+    Ok(())
+}
+
+#[test]
+fn rebinding_with_name_keeps_binding_without_name(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IMailer>()
+        .to::<SmtpMailer>()?
+        .in_transient_scope()
+        .when_named("test")?;
+
+    di_container
+        .bind::<dyn IMailer>()
+        .to::<SmtpMailer>()?
+        .in_transient_scope();
+
+    di_container
+        .rebind::<dyn IMailer>()
+        .to::<FakeMailer>()?
+        .in_transient_scope()
+        .when_named("test")?;
+
+    assert!(di_container
+        .get_named::<dyn IMailer>("test")?
+        .transient()?
+        .is_fake());
+
+    assert!(!di_container.get::<dyn IMailer>()?.transient()?.is_fake());
+
+    Ok(())
+}
+
+#[test]
+fn can_unbind() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IMailer>().to::<SmtpMailer>()?;
+
+    di_container.unbind::<dyn IMailer>()?;
+
+    assert!(matches!(
+        di_container.get::<dyn IMailer>(),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    di_container.bind::<dyn IMailer>().to::<FakeMailer>()?;
+
+    assert!(di_container.get::<dyn IMailer>()?.transient()?.is_fake());
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IQueue: Send + Sync
+    {
+        fn is_fake(&self) -> bool;
+    }
+
+    struct RedisQueue {}
+
+    #[injectable(IQueue, async = true)]
+    impl RedisQueue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IQueue for RedisQueue
+    {
+        fn is_fake(&self) -> bool
+        {
+            false
+        }
+    }
+
+    struct FakeQueue {}
+
+    #[injectable(IQueue, async = true)]
+    impl FakeQueue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IQueue for FakeQueue
+    {
+        fn is_fake(&self) -> bool
+        {
+            true
+        }
+    }
+
+    trait IBroker: Send + Sync {}
+
+    struct BrokeredQueue
+    {
+        _broker: TransientPtr<dyn IBroker>,
+    }
+
+    #[injectable(IQueue, async = true)]
+    impl BrokeredQueue
+    {
+        fn new(broker: TransientPtr<dyn IBroker>) -> Self
+        {
+            Self { _broker: broker }
+        }
+    }
+
+    impl IQueue for BrokeredQueue
+    {
+        fn is_fake(&self) -> bool
+        {
+            false
+        }
+    }
+
+    #[tokio::test]
+    async fn rebinding_with_name_keeps_binding_without_name(
+    ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container.bind::<dyn IQueue>().to::<RedisQueue>()?;
+
+        di_container
+            .rebind::<dyn IQueue>()
+            .to::<FakeQueue>()?
+            .in_transient_scope()
+            .when_named("test")?;
+
+        assert!(di_container
+            .get_named::<dyn IQueue>("test")
+            .await?
+            .transient()?
+            .is_fake());
+
+        assert!(!di_container
+            .get::<dyn IQueue>()
+            .await?
+            .transient()?
+            .is_fake());
+
+        di_container.rebind::<dyn IQueue>().to::<FakeQueue>()?;
+
+        assert!(di_container
+            .get::<dyn IQueue>()
+            .await?
+            .transient()?
+            .is_fake());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_rebind_keeps_replaced_binding(
+    ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IQueue>()
+            .to::<FakeQueue>()?
+            .in_singleton_scope()
+            .await?;
+
+        // No binding for the broker exists, so the queue can't be constructed
+        assert!(di_container
+            .rebind::<dyn IQueue>()
+            .to::<BrokeredQueue>()?
+            .in_singleton_scope()
+            .await
+            .is_err());
+
+        assert!(di_container
+            .get::<dyn IQueue>()
+            .await?
+            .threadsafe_singleton()?
+            .is_fake());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::{injectable, DIContainer, ThreadsafeDIContainer};
+
+trait IHandler
+{
+    fn handle(&self) -> &'static str;
+}
+
+struct CreateHandler {}
+
+#[injectable(IHandler)]
+impl CreateHandler
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IHandler for CreateHandler
+{
+    fn handle(&self) -> &'static str
+    {
+        "created"
+    }
+}
+
+struct DeleteHandler {}
+
+#[injectable(IHandler)]
+impl DeleteHandler
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+impl IHandler for DeleteHandler
+{
+    fn handle(&self) -> &'static str
+    {
+        "deleted"
+    }
+}
+
+struct Config {}
+
+#[injectable(threadsafe = true)]
+impl Config
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+fn handler_names() -> Vec<String>
+{
+    // Stands in for names read from a config file
+    vec![String::from("create"), String::from("delete")]
+}
+
+#[test]
+fn can_bind_and_get_with_runtime_names() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    let handler_names = handler_names();
+
+    di_container
+        .bind::<dyn IHandler>()
+        .to::<CreateHandler>()?
+        .in_transient_scope()
+        .when_named(handler_names[0].clone())?;
+
+    di_container
+        .bind::<dyn IHandler>()
+        .to::<DeleteHandler>()?
+        .in_transient_scope()
+        .when_named(handler_names[1].clone())?;
+
+    let requested_name = format!("{}e", "delet");
+
+    let handler = di_container
+        .get_named::<dyn IHandler>(&requested_name)?
+        .transient()?;
+
+    assert_eq!(handler.handle(), "deleted");
+
+    assert!(matches!(
+        di_container.get_named::<dyn IHandler>(&String::from("update")),
+        Err(DIContainerError::BindingNotFound { name: Some(name), .. })
+            if name == "update"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn can_unbind_with_runtime_name() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IHandler>()
+        .to::<CreateHandler>()?
+        .in_transient_scope()
+        .when_named(String::from("create"))?;
+
+    di_container.unbind_named::<dyn IHandler>(&String::from("create"))?;
+
+    assert!(di_container.get_named::<dyn IHandler>("create").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn threadsafe_can_get_with_runtime_name() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<Config>()
+        .to::<Config>()?
+        .in_transient_scope()
+        .when_named(String::from("primary"))?;
+
+    di_container
+        .get_named::<Config>(&String::from("primary"))?
+        .transient()?;
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::errors::async_di_container::AsyncDIContainerError;
+    use syrette::{injectable, AsyncDIContainer};
+
+    struct Queue {}
+
+    #[injectable(async = true)]
+    impl Queue
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    #[tokio::test]
+    async fn can_bind_and_get_with_runtime_names(
+    ) -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<Queue>()
+            .to::<Queue>()?
+            .in_transient_scope()
+            .when_named(String::from("emails"))?;
+
+        let requested_name = String::from("emails");
+
+        di_container
+            .get_named::<Queue>(&requested_name)
+            .await?
+            .transient()?;
+
+        assert!(matches!(
+            di_container.get_named::<Queue>(&String::from("sms")).await,
+            Err(AsyncDIContainerError::BindingNotFound { name: Some(name), .. })
+                if name == "sms"
+        ));
+
+        Ok(())
+    }
+}
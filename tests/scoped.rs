@@ -0,0 +1,115 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::rc::Rc;
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::errors::injectable::InjectableError;
+use syrette::ptr::SingletonPtr;
+use syrette::{injectable, DIContainer};
+
+struct RequestContext {}
+
+#[injectable]
+impl RequestContext
+{
+    fn new() -> Self
+    {
+        Self {}
+    }
+}
+
+struct RequestHandler
+{
+    context: SingletonPtr<RequestContext>,
+}
+
+#[injectable]
+impl RequestHandler
+{
+    fn new(context: SingletonPtr<RequestContext>) -> Self
+    {
+        Self { context }
+    }
+}
+
+fn create_di_container() -> Result<Rc<DIContainer>, Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<RequestContext>()
+        .to::<RequestContext>()?
+        .in_scoped_scope();
+
+    di_container
+        .bind::<RequestHandler>()
+        .to::<RequestHandler>()?
+        .in_transient_scope();
+
+    Ok(Rc::new(di_container))
+}
+
+#[test]
+fn shares_instance_within_scope() -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?;
+
+    let scope = di_container.begin_scope();
+
+    let context = scope.get::<RequestContext>()?.singleton()?;
+    let handler = scope.get::<RequestHandler>()?.transient()?;
+
+    assert!(Rc::ptr_eq(&context, &handler.context));
+
+    Ok(())
+}
+
+#[test]
+fn does_not_share_instance_between_scopes() -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?;
+
+    let first_scope = di_container.begin_scope();
+    let second_scope = di_container.begin_scope();
+
+    let first_context = first_scope.get::<RequestContext>()?.singleton()?;
+    let second_context = second_scope.get::<RequestContext>()?.singleton()?;
+
+    assert!(!Rc::ptr_eq(&first_context, &second_context));
+
+    Ok(())
+}
+
+#[test]
+fn drops_instances_with_scope() -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?;
+
+    let scope = di_container.begin_scope();
+
+    let context = scope.get::<RequestContext>()?.singleton()?;
+
+    assert_eq!(Rc::strong_count(&context), 2);
+
+    drop(scope);
+
+    assert_eq!(Rc::strong_count(&context), 1);
+
+    Ok(())
+}
+
+#[test]
+fn fails_outside_of_scope() -> Result<(), Box<dyn std::error::Error>>
+{
+    let di_container = create_di_container()?;
+
+    assert!(matches!(
+        di_container.get::<RequestContext>(),
+        Err(DIContainerError::BindingResolveFailed {
+            reason: InjectableError::NotInScope { .. },
+            ..
+        })
+    ));
+
+    Ok(())
+}
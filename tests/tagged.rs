@@ -0,0 +1,262 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IDatabase
+{
+    fn name(&self) -> &'static str;
+}
+
+macro_rules! database {
+    ($database: ident) => {
+        struct $database {}
+
+        #[injectable(IDatabase)]
+        impl $database
+        {
+            fn new() -> Self
+            {
+                Self {}
+            }
+        }
+
+        impl IDatabase for $database
+        {
+            fn name(&self) -> &'static str
+            {
+                stringify!($database)
+            }
+        }
+    };
+}
+
+database!(EuPrimary);
+database!(EuReplica);
+database!(UsPrimary);
+database!(Local);
+
+struct ReportService
+{
+    database: TransientPtr<dyn IDatabase>,
+}
+
+#[injectable]
+impl ReportService
+{
+    fn new(
+        #[tagged(key = "region", value = "eu")]
+        #[syrette::tagged(key = "access", value = "read_only")]
+        database: TransientPtr<dyn IDatabase>,
+    ) -> Self
+    {
+        Self { database }
+    }
+}
+
+fn bind_databases(di_container: &mut DIContainer)
+    -> Result<(), Box<dyn std::error::Error>>
+{
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<EuPrimary>()?
+        .in_transient_scope()
+        .when_tagged("region", "eu")?
+        .when_tagged("access", "read_write")?;
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<EuReplica>()?
+        .in_transient_scope()
+        .when_tagged("access", "read_only")?
+        .when_tagged("region", "eu")?;
+
+    di_container
+        .bind::<dyn IDatabase>()
+        .to::<UsPrimary>()?
+        .in_transient_scope()
+        .when_tagged("region", "us")?;
+
+    di_container.bind::<dyn IDatabase>().to::<Local>()?;
+
+    Ok(())
+}
+
+#[test]
+fn can_get_tagged() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_databases(&mut di_container)?;
+
+    assert_eq!(
+        di_container
+            .get_tagged::<dyn IDatabase>(&[("region", "eu"), ("access", "read_only")])?
+            .transient()?
+            .name(),
+        "EuReplica"
+    );
+    assert_eq!(
+        di_container
+            .get_tagged::<dyn IDatabase>(&[("access", "read_write")])?
+            .transient()?
+            .name(),
+        "EuPrimary"
+    );
+    assert_eq!(
+        di_container
+            .get_tagged::<dyn IDatabase>(&[("region", "us")])?
+            .transient()?
+            .name(),
+        "UsPrimary"
+    );
+    assert_eq!(
+        di_container.get::<dyn IDatabase>()?.transient()?.name(),
+        "Local"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_get_tagged_when_ambiguous() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_databases(&mut di_container)?;
+
+    assert!(matches!(
+        di_container.get_tagged::<dyn IDatabase>(&[("region", "eu")]),
+        Err(DIContainerError::AmbiguousBinding { matching: 2, .. })
+    ));
+
+    assert!(matches!(
+        di_container.get_tagged::<dyn IDatabase>(&[("region", "asia")]),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn can_inject_tagged() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_databases(&mut di_container)?;
+
+    di_container.bind::<ReportService>().to::<ReportService>()?;
+
+    assert_eq!(
+        di_container
+            .get::<ReportService>()?
+            .transient()?
+            .database
+            .name(),
+        "EuReplica"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn tagged_bindings_are_not_in_collections() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    bind_databases(&mut di_container)?;
+
+    let databases = di_container.get_all::<dyn IDatabase>()?;
+
+    assert_eq!(databases.len(), 1);
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IDatabase: Send + Sync
+    {
+        fn name(&self) -> &'static str;
+    }
+
+    struct EuReplica {}
+
+    #[injectable(IDatabase, async = true)]
+    impl EuReplica
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IDatabase for EuReplica
+    {
+        fn name(&self) -> &'static str
+        {
+            "EuReplica"
+        }
+    }
+
+    struct ReportService
+    {
+        database: TransientPtr<dyn IDatabase>,
+    }
+
+    #[injectable(async = true)]
+    impl ReportService
+    {
+        fn new(
+            #[tagged(key = "region", value = "eu")] database: TransientPtr<
+                dyn IDatabase,
+            >,
+        ) -> Self
+        {
+            Self { database }
+        }
+    }
+
+    #[tokio::test]
+    async fn can_inject_tagged() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container
+            .bind::<dyn IDatabase>()
+            .to::<EuReplica>()?
+            .in_transient_scope()
+            .when_tagged("region", "eu")?
+            .when_tagged("access", "read_only")?;
+
+        di_container
+            .bind::<ReportService>()
+            .to::<ReportService>()?;
+
+        assert_eq!(
+            di_container
+                .get::<ReportService>()
+                .await?
+                .transient()?
+                .database
+                .name(),
+            "EuReplica"
+        );
+
+        assert_eq!(
+            di_container
+                .get_tagged::<dyn IDatabase>(&[("access", "read_only")])
+                .await?
+                .transient()?
+                .name(),
+            "EuReplica"
+        );
+
+        Ok(())
+    }
+}
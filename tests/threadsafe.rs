@@ -0,0 +1,290 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::{ThreadsafeSingletonPtr, TransientPtr};
+use syrette::{injectable, ThreadsafeDIContainer};
+
+trait ICounter: Send + Sync
+{
+    fn increment(&self) -> usize;
+}
+
+struct Counter
+{
+    count: AtomicUsize,
+}
+
+#[injectable(ICounter, threadsafe = true)]
+impl Counter
+{
+    fn new() -> Self
+    {
+        Self {
+            count: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl ICounter for Counter
+{
+    fn increment(&self) -> usize
+    {
+        self.count.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+struct Greeter
+{
+    counter: ThreadsafeSingletonPtr<dyn ICounter>,
+    name: TransientPtr<Name>,
+}
+
+#[injectable(threadsafe = true)]
+impl Greeter
+{
+    fn new(
+        counter: ThreadsafeSingletonPtr<dyn ICounter>,
+        name: TransientPtr<Name>,
+    ) -> Self
+    {
+        Self { counter, name }
+    }
+
+    fn greet(&self) -> String
+    {
+        format!("Hello {} #{}", self.name.0, self.counter.increment())
+    }
+}
+
+#[derive(Default)]
+struct Name(&'static str);
+
+#[test]
+fn can_share_singleton_between_threads()
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<dyn ICounter>()
+        .to::<Counter>()
+        .unwrap()
+        .in_singleton_scope()
+        .unwrap();
+
+    let di_container = Arc::new(di_container);
+
+    let handles = (0..4)
+        .map(|_| {
+            let di_container = di_container.clone();
+
+            thread::spawn(move || {
+                di_container
+                    .get::<dyn ICounter>()
+                    .unwrap()
+                    .threadsafe_singleton()
+                    .unwrap()
+                    .increment();
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let counter = di_container
+        .get::<dyn ICounter>()
+        .unwrap()
+        .threadsafe_singleton()
+        .unwrap();
+
+    assert_eq!(counter.increment(), 5);
+}
+
+#[test]
+fn lazy_singleton_is_shared_between_threads()
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<dyn ICounter>()
+        .to::<Counter>()
+        .unwrap()
+        .in_lazy_singleton_scope();
+
+    let di_container = Arc::new(di_container);
+
+    let first = di_container
+        .get::<dyn ICounter>()
+        .unwrap()
+        .threadsafe_singleton()
+        .unwrap();
+
+    let second = thread::spawn({
+        let di_container = di_container.clone();
+
+        move || {
+            di_container
+                .get::<dyn ICounter>()
+                .unwrap()
+                .threadsafe_singleton()
+                .unwrap()
+        }
+    })
+    .join()
+    .unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn can_inject_dependencies()
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<dyn ICounter>()
+        .to::<Counter>()
+        .unwrap()
+        .in_singleton_scope()
+        .unwrap();
+
+    di_container
+        .bind::<Name>()
+        .to_dynamic_value(&|_| Box::new(|| TransientPtr::new(Name("Alice"))))
+        .unwrap();
+
+    di_container.bind::<Greeter>().to::<Greeter>().unwrap();
+
+    let greeter = di_container.get::<Greeter>().unwrap().transient().unwrap();
+
+    assert_eq!(greeter.greet(), "Hello Alice #1");
+    assert_eq!(greeter.greet(), "Hello Alice #2");
+}
+
+#[test]
+fn can_get_named()
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<dyn ICounter>()
+        .to::<Counter>()
+        .unwrap()
+        .in_transient_scope()
+        .when_named("requests")
+        .unwrap();
+
+    assert!(matches!(
+        di_container.get::<dyn ICounter>(),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    di_container
+        .get_named::<dyn ICounter>("requests")
+        .unwrap()
+        .transient()
+        .unwrap();
+}
+
+#[test]
+fn can_get_tagged_and_all_named()
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<dyn ICounter>()
+        .to::<Counter>()
+        .unwrap()
+        .in_transient_scope()
+        .when_tagged("kind", "requests")
+        .unwrap();
+
+    di_container
+        .bind::<dyn ICounter>()
+        .to::<Counter>()
+        .unwrap()
+        .in_transient_scope()
+        .when_named("errors")
+        .unwrap();
+
+    di_container
+        .get_tagged::<dyn ICounter>(&[("kind", "requests")])
+        .unwrap()
+        .transient()
+        .unwrap();
+
+    let counters = di_container.get_all_named::<dyn ICounter>().unwrap();
+
+    assert_eq!(counters.len(), 1);
+    assert!(counters.contains_key("errors"));
+}
+
+#[test]
+fn can_unbind()
+{
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<dyn ICounter>()
+        .to::<Counter>()
+        .unwrap()
+        .in_transient_scope()
+        .when_named("requests")
+        .unwrap();
+
+    di_container.bind::<dyn ICounter>().to::<Counter>().unwrap();
+
+    di_container.unbind::<dyn ICounter>().unwrap();
+
+    assert!(matches!(
+        di_container.get::<dyn ICounter>(),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    assert!(matches!(
+        di_container.unbind::<dyn ICounter>(),
+        Err(DIContainerError::BindingNotFound { .. })
+    ));
+
+    // The interface can be bound again once it's unbound
+    di_container.bind::<dyn ICounter>().to::<Counter>().unwrap();
+
+    di_container
+        .unbind_named::<dyn ICounter>("requests")
+        .unwrap();
+
+    assert!(di_container.get_named::<dyn ICounter>("requests").is_err());
+
+    di_container.unbind_all();
+
+    assert!(di_container.get::<dyn ICounter>().is_err());
+}
+
+#[test]
+#[cfg(feature = "factory")]
+fn can_use_factory_from_other_thread()
+{
+    type NameFactory = dyn Fn(&'static str) -> TransientPtr<Name> + Send + Sync;
+
+    let mut di_container = ThreadsafeDIContainer::new();
+
+    di_container
+        .bind::<NameFactory>()
+        .to_factory(&|_| Box::new(|name| TransientPtr::new(Name(name))))
+        .unwrap();
+
+    let name_factory = di_container
+        .get::<NameFactory>()
+        .unwrap()
+        .threadsafe_factory()
+        .unwrap();
+
+    let name = thread::spawn(move || name_factory("Bob").0).join().unwrap();
+
+    assert_eq!(name, "Bob");
+}
@@ -0,0 +1,260 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::any::type_name;
+use std::rc::Rc;
+
+use syrette::errors::validation::ValidationProblem;
+use syrette::ptr::TransientPtr;
+use syrette::{injectable, DIContainer};
+
+trait IWeapon
+{
+    fn name(&self) -> &'static str;
+}
+
+macro_rules! weapon {
+    ($weapon: ident) => {
+        struct $weapon {}
+
+        #[injectable(IWeapon)]
+        impl $weapon
+        {
+            fn new() -> Self
+            {
+                Self {}
+            }
+        }
+
+        impl IWeapon for $weapon
+        {
+            fn name(&self) -> &'static str
+            {
+                stringify!($weapon)
+            }
+        }
+    };
+}
+
+weapon!(Katana);
+weapon!(Shuriken);
+
+struct Ninja
+{
+    weapon: TransientPtr<dyn IWeapon>,
+}
+
+#[injectable]
+impl Ninja
+{
+    fn new(weapon: TransientPtr<dyn IWeapon>) -> Self
+    {
+        Self { weapon }
+    }
+}
+
+struct Chicken
+{
+    _egg: TransientPtr<Egg>,
+}
+
+#[injectable]
+impl Chicken
+{
+    fn new(egg: TransientPtr<Egg>) -> Self
+    {
+        Self { _egg: egg }
+    }
+}
+
+struct Egg
+{
+    _chicken: TransientPtr<Chicken>,
+}
+
+#[injectable]
+impl Egg
+{
+    fn new(chicken: TransientPtr<Chicken>) -> Self
+    {
+        Self { _chicken: chicken }
+    }
+}
+
+#[test]
+fn can_validate_complete_bindings() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IWeapon>().to::<Katana>()?;
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    di_container.validate()?;
+
+    assert_eq!(
+        di_container.get::<Ninja>()?.transient()?.weapon.name(),
+        "Katana"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn detects_missing_binding() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    let err = di_container.validate().unwrap_err();
+
+    assert_eq!(
+        err.problems(),
+        [ValidationProblem::MissingBinding {
+            dependent: type_name::<Ninja>(),
+            interface: type_name::<dyn IWeapon>(),
+            name: None,
+            key: None
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn detects_ambiguous_binding() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Katana>()?
+        .in_transient_scope()
+        .when_injected_into::<Ninja>()?;
+
+    di_container
+        .bind::<dyn IWeapon>()
+        .to::<Shuriken>()?
+        .in_transient_scope()
+        .when_injected_into::<Ninja>()?;
+
+    di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    let err = di_container.validate().unwrap_err();
+
+    assert_eq!(
+        err.problems(),
+        [ValidationProblem::AmbiguousBinding {
+            dependent: type_name::<Ninja>(),
+            interface: type_name::<dyn IWeapon>(),
+            name: None,
+            key: None,
+            matching: 2
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn detects_circular_dependency() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<Chicken>().to::<Chicken>()?;
+    di_container.bind::<Egg>().to::<Egg>()?;
+
+    let err = di_container.validate().unwrap_err();
+
+    assert!(err
+        .problems()
+        .contains(&ValidationProblem::CircularDependency {
+            chain: vec![
+                type_name::<Chicken>(),
+                type_name::<Egg>(),
+                type_name::<Chicken>()
+            ]
+        }));
+
+    Ok(())
+}
+
+#[test]
+fn validates_bindings_of_parent() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn IWeapon>().to::<Shuriken>()?;
+
+    let mut child_di_container = Rc::new(di_container).create_child();
+
+    child_di_container.bind::<Ninja>().to::<Ninja>()?;
+
+    child_di_container.validate()?;
+
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+mod r#async
+{
+    use std::any::type_name;
+
+    use syrette::errors::validation::ValidationProblem;
+    use syrette::ptr::TransientPtr;
+    use syrette::{injectable, AsyncDIContainer};
+
+    trait IWeapon: Send + Sync {}
+
+    struct Katana {}
+
+    #[injectable(IWeapon, async = true)]
+    impl Katana
+    {
+        fn new() -> Self
+        {
+            Self {}
+        }
+    }
+
+    impl IWeapon for Katana {}
+
+    struct Samurai
+    {
+        _weapon: TransientPtr<dyn IWeapon>,
+    }
+
+    #[injectable(async = true)]
+    impl Samurai
+    {
+        fn new(weapon: TransientPtr<dyn IWeapon>) -> Self
+        {
+            Self { _weapon: weapon }
+        }
+    }
+
+    #[test]
+    fn can_validate() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let mut di_container = AsyncDIContainer::new();
+
+        di_container.bind::<Samurai>().to::<Samurai>()?;
+
+        let err = di_container.validate().unwrap_err();
+
+        assert_eq!(
+            err.problems(),
+            [ValidationProblem::MissingBinding {
+                dependent: type_name::<Samurai>(),
+                interface: type_name::<dyn IWeapon>(),
+                name: None,
+                key: None
+            }]
+        );
+
+        di_container.bind::<dyn IWeapon>().to::<Katana>()?;
+
+        di_container.validate()?;
+
+        Ok(())
+    }
+}
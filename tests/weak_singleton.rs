@@ -0,0 +1,192 @@
+#![deny(clippy::all, clippy::pedantic)]
+
+use std::error::Error;
+use std::iter::successors;
+use std::rc::Rc;
+
+use syrette::di_container::BindingScope;
+use syrette::errors::di_container::DIContainerError;
+use syrette::ptr::{TransientPtr, WeakSingletonPtr};
+use syrette::{injectable, DIContainer};
+
+trait INodeManager
+{
+    fn nodes(&self) -> &[TransientPtr<dyn INode>];
+}
+
+trait INode
+{
+    fn manager(&self) -> Option<Rc<dyn INodeManager>>;
+}
+
+struct NodeManager
+{
+    nodes: Vec<TransientPtr<dyn INode>>,
+}
+
+#[injectable(INodeManager)]
+impl NodeManager
+{
+    fn new(nodes: Vec<TransientPtr<dyn INode>>) -> Self
+    {
+        Self { nodes }
+    }
+}
+
+impl INodeManager for NodeManager
+{
+    fn nodes(&self) -> &[TransientPtr<dyn INode>]
+    {
+        &self.nodes
+    }
+}
+
+struct Node
+{
+    manager: WeakSingletonPtr<dyn INodeManager>,
+}
+
+#[injectable(INode)]
+impl Node
+{
+    fn new(manager: WeakSingletonPtr<dyn INodeManager>) -> Self
+    {
+        Self { manager }
+    }
+}
+
+impl INode for Node
+{
+    fn manager(&self) -> Option<Rc<dyn INodeManager>>
+    {
+        self.manager.upgrade()
+    }
+}
+
+fn assert_nodes_refer_to_manager(manager: &Rc<dyn INodeManager>)
+{
+    assert_eq!(manager.nodes().len(), 2);
+
+    for node in manager.nodes() {
+        let node_manager = node.manager().expect("Expected manager to be filled in");
+
+        assert!(Rc::ptr_eq(&node_manager, manager));
+    }
+}
+
+#[test]
+fn can_have_back_reference_to_singleton() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container.bind::<dyn INode>().to_additional::<Node>();
+    di_container.bind::<dyn INode>().to_additional::<Node>();
+
+    di_container
+        .bind::<dyn INodeManager>()
+        .to::<NodeManager>()?
+        .in_singleton_scope()?;
+
+    let manager = di_container.get::<dyn INodeManager>()?.singleton()?;
+
+    assert_nodes_refer_to_manager(&manager);
+
+    Ok(())
+}
+
+#[test]
+fn can_have_back_reference_to_lazy_singleton() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn INodeManager>()
+        .to::<NodeManager>()?
+        .in_lazy_singleton_scope();
+
+    di_container.bind::<dyn INode>().to_additional::<Node>();
+    di_container.bind::<dyn INode>().to_additional::<Node>();
+
+    let manager = di_container.get::<dyn INodeManager>()?.singleton()?;
+
+    assert_nodes_refer_to_manager(&manager);
+
+    Ok(())
+}
+
+#[test]
+fn resolves_singleton_when_not_in_cycle() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn INodeManager>()
+        .to::<NodeManager>()?
+        .in_lazy_singleton_scope();
+
+    di_container.bind::<dyn INode>().to_additional::<Node>();
+    di_container.bind::<dyn INode>().to::<Node>()?;
+
+    let node = di_container.get::<dyn INode>()?.transient()?;
+
+    let manager = node.manager().expect("Expected manager to be filled in");
+
+    assert!(Rc::ptr_eq(
+        &manager,
+        &di_container.get::<dyn INodeManager>()?.singleton()?
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_have_back_reference_to_transient() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn INodeManager>()
+        .to::<NodeManager>()?;
+
+    di_container.bind::<dyn INode>().to::<Node>()?;
+
+    assert!(matches!(
+        di_container.get::<dyn INode>(),
+        Err(DIContainerError::BindingResolveFailed { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_have_back_reference_to_transient_in_cycle(
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut di_container = DIContainer::new();
+
+    di_container
+        .bind::<dyn INodeManager>()
+        .to::<NodeManager>()?;
+
+    di_container.bind::<dyn INode>().to_additional::<Node>();
+
+    let err = di_container
+        .get::<dyn INodeManager>()
+        .err()
+        .expect("Expected getting manager to fail");
+
+    assert!(
+        successors(Some(&err as &dyn Error), |err| (*err).source()).any(|err| {
+            matches!(
+                err.downcast_ref::<Box<DIContainerError>>()
+                    .map(AsRef::as_ref),
+                Some(DIContainerError::NotSingleton {
+                    scope: BindingScope::Transient,
+                    ..
+                })
+            )
+        })
+    );
+
+    Ok(())
+}